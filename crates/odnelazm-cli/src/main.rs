@@ -1,12 +1,33 @@
+mod sqlite;
+mod validate;
+
+use std::fs::File;
+use std::io::{BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::process;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use chrono::NaiveDate;
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use log::LevelFilter;
-use odnelazm::{HansardScraper, House, SittingListOptions};
+use odnelazm::{
+    AllHousesMembers, CURRENT_PARLIAMENT, DataSource, FetchProgress, HansardScraper, House,
+    ListingSort, MarkdownOptions, Member, MemberMatch, MemberProfile, MemberSort, Parliament,
+    ScraperError, ScraperErrorKind, SittingListOptions, best_member_match, rank_members,
+};
 use polars::prelude::*;
 
+// Exit codes, documented in the README so scripts can branch on `$?`. Parse errors share the
+// generic failure code.
+const EXIT_FAILURE: i32 = 1;
+const EXIT_NETWORK: i32 = 2;
+const EXIT_NOT_FOUND: i32 = 3;
+const EXIT_INVALID_ARGS: i32 = 4;
+
+/// Candidates listed by `profile-by-name` when a name is ambiguous.
+const NAME_CANDIDATES: usize = 10;
+
 #[derive(Parser)]
 #[command(name = "odnelazm")]
 #[command(about = "Kenya Hansard scraper — automatically routes to archive or current source based on date", long_about = None)]
@@ -21,6 +42,14 @@ struct Cli {
     )]
     log_level: LogLevel,
 
+    #[arg(
+        long = "out-file",
+        value_name = "PATH",
+        global = true,
+        help = "Write output to a file instead of stdout (parent directories are created, existing files truncated)"
+    )]
+    out_file: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -51,8 +80,12 @@ impl From<LogLevel> for LevelFilter {
 #[derive(Debug, Clone, ValueEnum)]
 enum OutputFormat {
     Json,
+    /// One compact JSON object per line
+    Ndjson,
     Csv,
     Parquet,
+    /// Readable transcript (`sitting` only)
+    Markdown,
 }
 
 #[derive(Subcommand)]
@@ -60,9 +93,10 @@ enum Commands {
     /// List parliamentary sittings with automatic source routing.
     ///
     /// Routing rules (cutoff = 2013-03-28):
-    ///   No dates              → current source, paged via --page / --all
+    ///   No dates              → current source, paged via --page / --from-page..--to-page / --all
     ///   --end-date < cutoff   → archive only
-    ///   --start-date ≥ cutoff → current only, paged via --page / --all
+    ///   --start-date ≥ cutoff → current only, paging back only until sittings predate
+    ///                           --start-date; --page and --all are ignored
     ///   Range spans cutoff    → BOTH sources fetched in parallel and merged by date;
     ///                           --page and --all are ignored, use --limit / --offset instead
     ///
@@ -70,11 +104,17 @@ enum Commands {
     ///   # Recent sittings (current, page 1)
     ///   odnelazm sittings
     ///
+    ///   # Senate sittings since March 2026; stops paging once past the start date
+    ///   odnelazm sittings --start-date 2026-03-01 --house senate
+    ///
     ///   # All archive sittings in 2010
     ///   odnelazm sittings --start-date 2010-01-01 --end-date 2010-12-31
     ///
     ///   # Cross-era range: archive + current merged
     ///   odnelazm sittings --start-date 2012-01-01 --end-date 2014-12-31
+    ///
+    ///   # Pages 3 through 10 of the current listing, fetched concurrently
+    ///   odnelazm sittings --from-page 3 --to-page 10
     Sittings {
         #[arg(
             long,
@@ -101,7 +141,7 @@ enum Commands {
 
         #[arg(
             long,
-            help = "Page to fetch from the current source (ignored when --all is set or when a cross-era range triggers a merged query)",
+            help = "Page to fetch from the current source (ignored when --all or --start-date is set, or when a cross-era range triggers a merged query)",
             default_value = "1",
             value_parser = clap::value_parser!(u32).range(1..)
         )]
@@ -109,11 +149,30 @@ enum Commands {
 
         #[arg(
             long,
-            help = "Fetch all pages from the current source at once (ignored for cross-era merged queries; conflicts with --page)",
+            help = "Fetch all pages from the current source at once (ignored when --start-date is set, since paging stops past it, and for cross-era merged queries; conflicts with --page)",
             conflicts_with = "page"
         )]
         all: bool,
 
+        #[arg(
+            long,
+            value_name = "N",
+            help = "First page of a range fetched concurrently from the current source (default: 1; requires --to-page)",
+            requires = "to_page",
+            conflicts_with_all = ["page", "all"],
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        from_page: Option<u32>,
+
+        #[arg(
+            long,
+            value_name = "N",
+            help = "Last page of the range, inclusive; must not be past the last page",
+            conflicts_with_all = ["page", "all"],
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        to_page: Option<u32>,
+
         #[arg(
             long,
             help = "Maximum results to return, applied after merging and sorting",
@@ -128,6 +187,24 @@ enum Commands {
         )]
         offset: Option<usize>,
 
+        #[arg(
+            long,
+            value_name = "FIELD",
+            value_parser = |s: &str| ListingSort::from_str(s).map_err(|e| e.to_string()),
+            help = "Sort by field (date, house, title) before --limit / --offset are applied; site order by default"
+        )]
+        sort: Option<ListingSort>,
+
+        #[arg(long, help = "Reverse the sort order")]
+        reverse: bool,
+
+        #[arg(
+            long,
+            help = "Merge both houses into one list, newest first (same as --sort date --reverse)",
+            conflicts_with_all = ["sort", "reverse"]
+        )]
+        interleave: bool,
+
         #[arg(
             short = 'o',
             long = "output",
@@ -136,16 +213,51 @@ enum Commands {
             help = "Output format"
         )]
         format: OutputFormat,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Write results into the `listings` table of a SQLite database instead of printing",
+            conflicts_with = "format"
+        )]
+        sqlite: Option<PathBuf>,
     },
 
-    /// Fetch the full transcript of a sitting. Source is detected automatically from the URL.
+    /// Fetch the full transcript of a sitting. Source is detected automatically from the URL or slug.
     ///
     /// Archive URLs: https://info.mzalendo.com/hansard/sitting/...
+    /// Archive slugs: senate/2020-12-29-14-30-00 (or just the date, tried under both houses)
     /// Current URLs: https://mzalendo.com/democracy-tools/hansard/...
+    /// Current slugs: thursday-12th-february-2026-afternoon-sitting-2438
+    ///
+    /// Slugs matching neither shape are tried against the current source, then the archive.
     Sitting {
         #[arg(help = "URL or slug of the sitting to fetch")]
         url_or_slug: String,
 
+        #[arg(
+            long = "download-pdf",
+            value_name = "DIR",
+            help = "Also download the sitting's official PDF into DIR (current source only)"
+        )]
+        download_pdf: Option<PathBuf>,
+
+        #[arg(
+            long,
+            value_name = "N",
+            default_value = "0",
+            help = "Show at most N contributions per section and subsection in markdown output (0 = all)"
+        )]
+        max_contributions: usize,
+
+        #[arg(
+            long,
+            value_name = "CHARS",
+            default_value = "0",
+            help = "Cut each contribution to CHARS characters in markdown output (0 = full text)"
+        )]
+        content_width: usize,
+
         #[arg(
             short = 'o',
             long = "output",
@@ -164,8 +276,11 @@ enum Commands {
         )]
         house: House,
 
-        #[arg(help = "Parliament session (e.g. 13th-parliament, 12th-parliament)")]
-        parliament: String,
+        #[arg(
+            help = "Parliament session (e.g. 13th-parliament, 12th-parliament)",
+            value_parser = |s: &str| Parliament::from_str(s).map_err(|e| e.to_string()),
+        )]
+        parliament: Parliament,
 
         #[arg(
             long,
@@ -178,6 +293,17 @@ enum Commands {
         #[arg(long, help = "Fetch all pages at once", conflicts_with = "page")]
         all: bool,
 
+        #[arg(
+            long,
+            value_name = "FIELD",
+            value_parser = |s: &str| MemberSort::from_str(s).map_err(|e| e.to_string()),
+            help = "Sort by field (name, constituency); site order by default"
+        )]
+        sort: Option<MemberSort>,
+
+        #[arg(long, help = "Reverse the sort order")]
+        reverse: bool,
+
         #[arg(
             short = 'o',
             long = "output",
@@ -186,15 +312,35 @@ enum Commands {
             help = "Output format"
         )]
         format: OutputFormat,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Write results into the `members` table of a SQLite database instead of printing",
+            conflicts_with = "format"
+        )]
+        sqlite: Option<PathBuf>,
     },
 
     /// List all members from both houses in parallel (current source only)
     AllMembers {
         #[arg(
             help = "Parliament session (e.g. 13th-parliament, 12th-parliament)",
-            default_value = "13th-parliament"
+            default_value = CURRENT_PARLIAMENT,
+            value_parser = |s: &str| Parliament::from_str(s).map_err(|e| e.to_string()),
+        )]
+        parliament: Parliament,
+
+        #[arg(
+            long,
+            value_name = "FIELD",
+            value_parser = |s: &str| MemberSort::from_str(s).map_err(|e| e.to_string()),
+            help = "Sort by field (name, constituency); site order by default"
         )]
-        parliament: String,
+        sort: Option<MemberSort>,
+
+        #[arg(long, help = "Reverse the sort order")]
+        reverse: bool,
 
         #[arg(
             short = 'o',
@@ -204,6 +350,14 @@ enum Commands {
             help = "Output format"
         )]
         format: OutputFormat,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Write results into the `members` table of a SQLite database instead of printing",
+            conflicts_with = "format"
+        )]
+        sqlite: Option<PathBuf>,
     },
 
     /// Fetch a member's full profile including speeches, bills, and voting record (current source only)
@@ -225,12 +379,248 @@ enum Commands {
             help = "Output format"
         )]
         format: OutputFormat,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Write results into the `profiles` table of a SQLite database instead of printing",
+            conflicts_with = "format"
+        )]
+        sqlite: Option<PathBuf>,
     },
+
+    /// Fetch a member's profile by name instead of slug (current source only)
+    ///
+    /// Lists the house's members and fuzzy-matches the name as `search` does. A single strong
+    /// match has its profile fetched and printed; otherwise the candidates are printed and the
+    /// command exits non-zero.
+    ///
+    /// Examples:
+    ///   odnelazm profile-by-name na 13th-parliament "Gladys Jepkosgei"
+    ///   odnelazm profile-by-name senate 13th-parliament "Aaron Cheruiyot" --all-activity
+    ProfileByName {
+        #[arg(
+            help = "House the member sits in (senate, national_assembly, na)",
+            value_parser = |s: &str| House::from_str(s).map_err(|e| e.to_string()),
+        )]
+        house: House,
+
+        #[arg(
+            help = "Parliament session (e.g. 13th-parliament, 12th-parliament)",
+            value_parser = |s: &str| Parliament::from_str(s).map_err(|e| e.to_string()),
+        )]
+        parliament: Parliament,
+
+        #[arg(help = "Member's name; misspellings and partial names are tolerated")]
+        name: String,
+
+        #[arg(long, help = "Fetch all pages of parliamentary activity")]
+        all_activity: bool,
+
+        #[arg(long, help = "Fetch all pages of sponsored bills")]
+        all_bills: bool,
+
+        #[arg(
+            short = 'o',
+            long = "output",
+            value_enum,
+            default_value = "json",
+            help = "Output format"
+        )]
+        format: OutputFormat,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Write results into the `profiles` table of a SQLite database instead of printing",
+            conflicts_with = "format"
+        )]
+        sqlite: Option<PathBuf>,
+    },
+
+    /// Fuzzy search members by name, constituency, or county (current source only)
+    ///
+    /// Prints ranked matches with their profile URLs, best first.
+    ///
+    /// Examples:
+    ///   odnelazm search "gladys jepkosgei"
+    ///   odnelazm search mwala --house na --limit 3
+    Search {
+        #[arg(help = "Name or constituency to search for")]
+        query: String,
+
+        #[arg(
+            long,
+            help = "Only search one house (senate, national_assembly, na); both by default",
+            value_parser = |s: &str| House::from_str(s).map_err(|e| e.to_string()),
+        )]
+        house: Option<House>,
+
+        #[arg(
+            long,
+            help = "Parliament session (e.g. 13th-parliament, 12th-parliament)",
+            default_value = CURRENT_PARLIAMENT,
+            value_parser = |s: &str| Parliament::from_str(s).map_err(|e| e.to_string()),
+        )]
+        parliament: Parliament,
+
+        #[arg(
+            long,
+            help = "Maximum number of matches to print",
+            default_value = "10",
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        limit: u32,
+
+        #[arg(
+            short = 'o',
+            long = "output",
+            value_enum,
+            help = "Output format (one ranked match per line when omitted)"
+        )]
+        format: Option<OutputFormat>,
+    },
+
+    /// Check that a saved JSON file still deserializes into the current schema
+    ///
+    /// Examples:
+    ///   odnelazm validate sitting.json --as sitting
+    ///   odnelazm validate members.json --as members
+    Validate {
+        #[arg(help = "Path to the JSON file to check")]
+        file: PathBuf,
+
+        #[arg(
+            long = "as",
+            value_enum,
+            help = "Type the file should deserialize into"
+        )]
+        schema: validate::Schema,
+    },
+
+    /// Save the raw HTML of a page to a file, e.g. to capture a parser fixture
+    ///
+    /// The page is fetched with the same client and headers as every other command, and
+    /// written exactly as received, without parsing. Sitting URLs and slugs are resolved as
+    /// for `sitting`; pass --source to resolve other site paths against one site.
+    ///
+    /// Examples:
+    ///   odnelazm snapshot thursday-12th-february-2026-afternoon-sitting-2438 fixtures/current/sitting
+    ///   odnelazm snapshot senate/2020-12-29-14-30-00 fixtures/archive/sitting
+    ///   odnelazm snapshot /mps-performance/senate/13th-parliament/ fixtures/current/senators --source current
+    Snapshot {
+        #[arg(help = "URL, site path, or slug of the page to fetch")]
+        url_or_slug: String,
+
+        #[arg(help = "File to write the HTML to (parent directories are created)")]
+        out_path: PathBuf,
+
+        #[arg(
+            long = "source",
+            value_parser = |s: &str| DataSource::from_str(s).map_err(|e| e.to_string()),
+            help = "Site to resolve a path or slug against: archive or current (default: detected)"
+        )]
+        source: Option<DataSource>,
+    },
+
+    /// Print a shell completion script
+    ///
+    /// Examples:
+    ///   odnelazm completions bash > ~/.local/share/bash-completion/completions/odnelazm
+    ///   odnelazm completions zsh > ~/.zfunc/_odnelazm
+    #[command(hide = true)]
+    Completions {
+        #[arg(value_enum, help = "Shell to generate completions for")]
+        shell: clap_complete::Shell,
+    },
+}
+
+fn write_sqlite<T: serde::Serialize>(path: &Path, table: &str, data: &T) {
+    let mut conn = rusqlite::Connection::open(path).unwrap_or_else(|e| {
+        log::error!("Failed to open SQLite database {}: {}", path.display(), e);
+        process::exit(1);
+    });
+    let rows = sqlite::write_table(&mut conn, table, data).unwrap_or_else(|e| {
+        log::error!("SQLite write error: {}", e);
+        process::exit(1);
+    });
+    log::info!(
+        "Wrote {} row(s) to table '{}' in {}",
+        rows,
+        table,
+        path.display()
+    );
+}
+
+/// `--out-file`, or stdout.
+fn open_output(path: Option<&Path>) -> Box<dyn Write> {
+    match path {
+        Some(path) => Box::new(OutFile {
+            path: path.to_path_buf(),
+            file: None,
+        }),
+        None => Box::new(std::io::stdout()),
+    }
+}
+
+/// `--out-file`, created (with its parent directories, truncating any existing file) on the
+/// first write or flush rather than up front, so a command that fails while fetching leaves
+/// an existing file as it was.
+struct OutFile {
+    path: PathBuf,
+    file: Option<BufWriter<File>>,
+}
+
+impl OutFile {
+    fn file(&mut self) -> std::io::Result<&mut BufWriter<File>> {
+        if self.file.is_none() {
+            let with_path = |e: std::io::Error| {
+                std::io::Error::new(e.kind(), format!("{}: {e}", self.path.display()))
+            };
+            if let Some(parent) = self.path.parent()
+                && !parent.as_os_str().is_empty()
+            {
+                std::fs::create_dir_all(parent).map_err(with_path)?;
+            }
+            let file = File::create(&self.path).map_err(with_path)?;
+            self.file = Some(BufWriter::new(file));
+        }
+        Ok(self.file.as_mut().expect("out file opened above"))
+    }
+}
+
+impl Write for OutFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.file()?.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file()?.flush()
+    }
 }
 
-fn print_json<T: serde::Serialize>(value: &T) {
+/// File name of the sitting's PDF link, or `<date>-<house>.pdf` when the link has none.
+fn pdf_file_name(sitting: &odnelazm::HansardSitting) -> String {
+    sitting
+        .pdf_url
+        .as_deref()
+        .and_then(|url| url.split(['?', '#']).next())
+        .and_then(|url| url.trim_end_matches('/').rsplit('/').next())
+        .filter(|name| name.to_lowercase().ends_with(".pdf"))
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{}-{}.pdf", sitting.date, sitting.house.slug()))
+}
+
+fn write_out(out: &mut dyn Write, text: &str) {
+    if let Err(e) = writeln!(out, "{}", text) {
+        log::error!("Write error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn print_json<T: serde::Serialize>(out: &mut dyn Write, value: &T) {
     match serde_json::to_string_pretty(value) {
-        Ok(json) => println!("{}", json),
+        Ok(json) => write_out(out, &json),
         Err(e) => {
             log::error!("Serialization error: {}", e);
             process::exit(1);
@@ -238,6 +628,21 @@ fn print_json<T: serde::Serialize>(value: &T) {
     }
 }
 
+/// Print list-shaped values one compact JSON record per line; anything else on a single line.
+fn print_ndjson<T: serde::Serialize>(out: &mut dyn Write, value: &T) {
+    let value = serde_json::to_value(value).unwrap_or_else(|e| {
+        log::error!("Serialization error: {}", e);
+        process::exit(1);
+    });
+    let records = match value {
+        serde_json::Value::Array(items) => items,
+        other => vec![other],
+    };
+    for record in records {
+        write_out(out, &record.to_string());
+    }
+}
+
 /// Convert any serializable value to a polars DataFrame via JSON.
 /// Single objects are wrapped in an array so polars always sees a record list.
 fn to_dataframe<T: serde::Serialize>(data: &T) -> PolarsResult<DataFrame> {
@@ -251,44 +656,165 @@ fn to_dataframe<T: serde::Serialize>(data: &T) -> PolarsResult<DataFrame> {
     JsonReader::new(std::io::Cursor::new(json.into_bytes())).finish()
 }
 
-fn print_csv<T: serde::Serialize>(data: &T) {
+fn print_csv<T: serde::Serialize>(out: &mut dyn Write, data: &T) {
     let mut df = to_dataframe(data).unwrap_or_else(|e| {
         log::error!("Failed to build dataframe: {}", e);
         process::exit(1);
     });
-    CsvWriter::new(std::io::stdout())
-        .finish(&mut df)
-        .unwrap_or_else(|e| {
-            log::error!("CSV write error: {}", e);
-            process::exit(1);
-        });
+    CsvWriter::new(out).finish(&mut df).unwrap_or_else(|e| {
+        log::error!("CSV write error: {}", e);
+        process::exit(1);
+    });
 }
 
-fn print_parquet<T: serde::Serialize>(data: &T) {
+fn print_parquet<T: serde::Serialize>(out: &mut dyn Write, data: &T) {
     let mut df = to_dataframe(data).unwrap_or_else(|e| {
         log::error!("Failed to build dataframe: {}", e);
         process::exit(1);
     });
-    ParquetWriter::new(std::io::stdout())
-        .finish(&mut df)
-        .unwrap_or_else(|e| {
-            log::error!("Parquet write error: {}", e);
-            process::exit(1);
-        });
+    ParquetWriter::new(out).finish(&mut df).unwrap_or_else(|e| {
+        log::error!("Parquet write error: {}", e);
+        process::exit(1);
+    });
+}
+
+fn print_profile(out: &mut dyn Write, profile: &MemberProfile, format: OutputFormat) {
+    match format {
+        OutputFormat::Json => print_json(out, profile),
+        OutputFormat::Ndjson => print_ndjson(out, profile),
+        OutputFormat::Csv => print_csv(out, profile),
+        OutputFormat::Parquet => print_parquet(out, profile),
+        OutputFormat::Markdown => markdown_unsupported(),
+    }
+}
+
+/// One ranked match per line, followed by its profile URL.
+fn print_matches(out: &mut dyn Write, matches: &[MemberMatch]) {
+    for (i, m) in matches.iter().enumerate() {
+        let constituency = m
+            .constituency
+            .as_deref()
+            .or(m.county.as_deref())
+            .unwrap_or("-");
+        write_out(
+            out,
+            &format!(
+                "{:>2}. {} ({}, {}) [{:.2}]\n    {}",
+                i + 1,
+                m.name,
+                constituency,
+                m.house,
+                m.score,
+                m.url
+            ),
+        );
+    }
+}
+
+fn sort_members(members: &mut [Member], sort: Option<MemberSort>, reverse: bool) {
+    match sort {
+        Some(sort) => sort.sort(members, reverse),
+        None if reverse => members.reverse(),
+        None => {}
+    }
+}
+
+fn render_progress(progress: FetchProgress) {
+    // XXX: single-page results finish before there is anything to show.
+    if progress.total <= 1 {
+        return;
+    }
+    let width = 30;
+    let filled = (progress.completed * width / progress.total) as usize;
+    eprint!(
+        "\r[{}{}] {}/{} pages",
+        "#".repeat(filled),
+        " ".repeat(width as usize - filled),
+        progress.completed,
+        progress.total
+    );
+    if progress.completed >= progress.total {
+        eprintln!();
+    }
+}
+
+/// Members of both houses, exiting when neither could be fetched. The houses that failed are
+/// returned so they can be reported after the output, see [`warn_missing_houses`].
+async fn all_houses_members(
+    scraper: &HansardScraper,
+    parliament: &Parliament,
+) -> (Vec<Member>, Vec<(House, ScraperError)>) {
+    let AllHousesMembers { members, errors } =
+        scraper.list_all_members_all_houses(parliament).await;
+    if members.is_empty()
+        && let Some((_, e)) = errors.first()
+    {
+        log::error!("Error fetching all members: {}", e);
+        process::exit(exit_code(e));
+    }
+    (members, errors)
+}
+
+fn warn_missing_houses(errors: &[(House, ScraperError)]) {
+    for (house, e) in errors {
+        log::warn!("{} members are missing from the output: {}", house, e);
+    }
+}
+
+/// Footer for rows the parser left out, so a short crawl does not pass unnoticed.
+fn warn_skipped_rows(skipped: &[String]) {
+    if skipped.is_empty() {
+        return;
+    }
+    log::warn!(
+        "{} row(s) could not be parsed and are missing from the output:",
+        skipped.len()
+    );
+    for reason in skipped {
+        log::warn!("  {}", reason);
+    }
+}
+
+fn markdown_unsupported() {
+    log::error!("Markdown output is only supported for the `sitting` command");
+    process::exit(EXIT_INVALID_ARGS);
+}
+
+fn exit_code(error: &ScraperError) -> i32 {
+    match error.kind() {
+        ScraperErrorKind::Parse => EXIT_FAILURE,
+        ScraperErrorKind::Network => EXIT_NETWORK,
+        ScraperErrorKind::NotFound => EXIT_NOT_FOUND,
+        ScraperErrorKind::InvalidInput => EXIT_INVALID_ARGS,
+    }
 }
 
 #[tokio::main]
 async fn main() {
-    let cli = Cli::parse();
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        // XXX: clap exits with 2 on usage errors, which scripts would read as a network failure
+        let _ = e.print();
+        process::exit(if e.use_stderr() { EXIT_INVALID_ARGS } else { 0 });
+    });
 
     env_logger::Builder::new()
         .filter_level(cli.log_level.into())
         .init();
 
-    let scraper = HansardScraper::new().unwrap_or_else(|e| {
+    let mut scraper = HansardScraper::new().unwrap_or_else(|e| {
         log::error!("Failed to create scraper: {}", e);
-        process::exit(1);
+        process::exit(exit_code(&e));
     });
+    if std::io::stderr().is_terminal() && log::max_level() != LevelFilter::Off {
+        scraper = scraper.with_progress(render_progress);
+    }
+    let skipped: Arc<Mutex<Vec<String>>> = Arc::default();
+    scraper = scraper.with_skip_report({
+        let skipped = Arc::clone(&skipped);
+        move |reason| skipped.lock().unwrap().push(reason.to_string())
+    });
+
+    let mut out = open_output(cli.out_file.as_deref());
 
     match cli.command {
         Commands::Sittings {
@@ -297,16 +823,22 @@ async fn main() {
             house,
             page,
             all,
+            from_page,
+            to_page,
             limit,
             offset,
+            sort,
+            reverse,
+            interleave,
             format,
+            sqlite,
         } => {
             if let Some(start) = start_date
                 && let Some(end) = end_date
                 && start > end
             {
                 log::error!("--start-date cannot be after --end-date");
-                process::exit(1);
+                process::exit(EXIT_INVALID_ARGS);
             }
 
             let listings = scraper
@@ -314,37 +846,84 @@ async fn main() {
                     start_date,
                     end_date,
                     house,
-                    page,
+                    page: from_page.unwrap_or(page),
                     all,
+                    to_page,
                     limit,
                     offset,
+                    sort: if interleave {
+                        Some(ListingSort::Date)
+                    } else {
+                        sort
+                    },
+                    reverse: reverse || interleave,
                 })
                 .await
                 .unwrap_or_else(|e| {
                     log::error!("Error fetching sittings: {}", e);
-                    process::exit(1);
+                    process::exit(exit_code(&e));
                 });
 
-            match format {
-                OutputFormat::Json => print_json(&listings),
-                OutputFormat::Csv => print_csv(&listings),
-                OutputFormat::Parquet => print_parquet(&listings),
+            if let Some(path) = sqlite {
+                write_sqlite(&path, "listings", &listings);
+            } else {
+                match format {
+                    OutputFormat::Json => print_json(&mut out, &listings),
+                    OutputFormat::Ndjson => print_ndjson(&mut out, &listings),
+                    OutputFormat::Csv => print_csv(&mut out, &listings),
+                    OutputFormat::Parquet => print_parquet(&mut out, &listings),
+                    OutputFormat::Markdown => markdown_unsupported(),
+                }
             }
         }
 
         Commands::Sitting {
             url_or_slug,
+            download_pdf,
+            max_contributions,
+            content_width,
             format,
         } => {
             let sitting = scraper.get_sitting(&url_or_slug).await.unwrap_or_else(|e| {
                 log::error!("Error fetching sitting: {}", e);
-                process::exit(1);
+                process::exit(exit_code(&e));
             });
+            log::info!(
+                "{} contributions from {} speakers",
+                sitting.contribution_count(),
+                sitting.distinct_speakers()
+            );
+
+            if let Some(dir) = download_pdf {
+                let pdf = scraper
+                    .download_sitting_pdf(&sitting)
+                    .await
+                    .unwrap_or_else(|e| {
+                        log::error!("Error downloading PDF: {}", e);
+                        process::exit(exit_code(&e));
+                    });
+                let path = dir.join(pdf_file_name(&sitting));
+                std::fs::create_dir_all(&dir)
+                    .and_then(|_| std::fs::write(&path, &pdf))
+                    .unwrap_or_else(|e| {
+                        log::error!("Failed to write {}: {}", path.display(), e);
+                        process::exit(1);
+                    });
+                log::info!("Saved PDF to {}", path.display());
+            }
 
             match format {
-                OutputFormat::Json => print_json(&sitting),
-                OutputFormat::Csv => print_csv(&sitting),
-                OutputFormat::Parquet => print_parquet(&sitting),
+                OutputFormat::Json => print_json(&mut out, &sitting),
+                OutputFormat::Ndjson => print_ndjson(&mut out, &sitting),
+                OutputFormat::Csv => print_csv(&mut out, &sitting),
+                OutputFormat::Parquet => print_parquet(&mut out, &sitting),
+                OutputFormat::Markdown => {
+                    let options = MarkdownOptions {
+                        max_contributions: (max_contributions > 0).then_some(max_contributions),
+                        content_width: (content_width > 0).then_some(content_width),
+                    };
+                    write_out(&mut out, sitting.to_markdown_with(options).trim_end())
+                }
             }
         }
 
@@ -353,39 +932,57 @@ async fn main() {
             parliament,
             page,
             all,
+            sort,
+            reverse,
             format,
+            sqlite,
         } => {
-            let members = if all {
+            let mut members = if all {
                 scraper.list_all_members(house, &parliament).await
             } else {
                 scraper.list_members(house, &parliament, page).await
             }
             .unwrap_or_else(|e| {
                 log::error!("Error fetching members: {}", e);
-                process::exit(1);
+                process::exit(exit_code(&e));
             });
+            sort_members(&mut members, sort, reverse);
 
-            match format {
-                OutputFormat::Json => print_json(&members),
-                OutputFormat::Csv => print_csv(&members),
-                OutputFormat::Parquet => print_parquet(&members),
+            if let Some(path) = sqlite {
+                write_sqlite(&path, "members", &members);
+            } else {
+                match format {
+                    OutputFormat::Json => print_json(&mut out, &members),
+                    OutputFormat::Ndjson => print_ndjson(&mut out, &members),
+                    OutputFormat::Csv => print_csv(&mut out, &members),
+                    OutputFormat::Parquet => print_parquet(&mut out, &members),
+                    OutputFormat::Markdown => markdown_unsupported(),
+                }
             }
         }
 
-        Commands::AllMembers { parliament, format } => {
-            let members = scraper
-                .list_all_members_all_houses(&parliament)
-                .await
-                .unwrap_or_else(|e| {
-                    log::error!("Error fetching all members: {}", e);
-                    process::exit(1);
-                });
+        Commands::AllMembers {
+            parliament,
+            sort,
+            reverse,
+            format,
+            sqlite,
+        } => {
+            let (mut members, missing) = all_houses_members(&scraper, &parliament).await;
+            sort_members(&mut members, sort, reverse);
 
-            match format {
-                OutputFormat::Json => print_json(&members),
-                OutputFormat::Csv => print_csv(&members),
-                OutputFormat::Parquet => print_parquet(&members),
+            if let Some(path) = sqlite {
+                write_sqlite(&path, "members", &members);
+            } else {
+                match format {
+                    OutputFormat::Json => print_json(&mut out, &members),
+                    OutputFormat::Ndjson => print_ndjson(&mut out, &members),
+                    OutputFormat::Csv => print_csv(&mut out, &members),
+                    OutputFormat::Parquet => print_parquet(&mut out, &members),
+                    OutputFormat::Markdown => markdown_unsupported(),
+                }
             }
+            warn_missing_houses(&missing);
         }
 
         Commands::Profile {
@@ -393,20 +990,174 @@ async fn main() {
             all_activity,
             all_bills,
             format,
+            sqlite,
         } => {
             let profile = scraper
                 .get_member_profile(&url_or_slug, all_activity, all_bills)
                 .await
                 .unwrap_or_else(|e| {
                     log::error!("Error fetching member profile: {}", e);
-                    process::exit(1);
+                    process::exit(exit_code(&e));
+                });
+
+            if let Some(path) = sqlite {
+                write_sqlite(&path, "profiles", &profile);
+            } else {
+                print_profile(&mut out, &profile, format);
+            }
+        }
+
+        Commands::ProfileByName {
+            house,
+            parliament,
+            name,
+            all_activity,
+            all_bills,
+            format,
+            sqlite,
+        } => {
+            let members = scraper
+                .list_all_members(house, &parliament)
+                .await
+                .unwrap_or_else(|e| {
+                    log::error!("Error fetching members: {}", e);
+                    process::exit(exit_code(&e));
                 });
 
+            let matches = rank_members(&name, members, NAME_CANDIDATES);
+            if matches.is_empty() {
+                log::error!("No {} members matching '{}'", house, name);
+                process::exit(EXIT_NOT_FOUND);
+            }
+            let Some(member) = best_member_match(&matches) else {
+                print_matches(&mut out, &matches);
+                if let Err(e) = out.flush() {
+                    log::error!("Write error: {}", e);
+                }
+                log::error!(
+                    "'{}' matches {} members; pass one of their URLs to `profile`",
+                    name,
+                    matches.len()
+                );
+                process::exit(EXIT_FAILURE);
+            };
+
+            log::info!("Matched '{}' to {}", name, member.name);
+            let profile = scraper
+                .get_member_profile(&member.url, all_activity, all_bills)
+                .await
+                .unwrap_or_else(|e| {
+                    log::error!("Error fetching member profile: {}", e);
+                    process::exit(exit_code(&e));
+                });
+
+            if let Some(path) = sqlite {
+                write_sqlite(&path, "profiles", &profile);
+            } else {
+                print_profile(&mut out, &profile, format);
+            }
+        }
+
+        Commands::Search {
+            query,
+            house,
+            parliament,
+            limit,
+            format,
+        } => {
+            let (members, missing) = match house {
+                Some(house) => {
+                    let members = scraper
+                        .list_all_members(house, &parliament)
+                        .await
+                        .unwrap_or_else(|e| {
+                            log::error!("Error fetching members: {}", e);
+                            process::exit(exit_code(&e));
+                        });
+                    (members, Vec::new())
+                }
+                None => all_houses_members(&scraper, &parliament).await,
+            };
+
+            let matches = rank_members(&query, members, limit as usize);
+
             match format {
-                OutputFormat::Json => print_json(&profile),
-                OutputFormat::Csv => print_csv(&profile),
-                OutputFormat::Parquet => print_parquet(&profile),
+                None => {
+                    if matches.is_empty() {
+                        log::warn!("No members matching '{}'", query);
+                    }
+                    print_matches(&mut out, &matches);
+                }
+                Some(OutputFormat::Json) => print_json(&mut out, &matches),
+                Some(OutputFormat::Ndjson) => print_ndjson(&mut out, &matches),
+                Some(OutputFormat::Csv) => print_csv(&mut out, &matches),
+                Some(OutputFormat::Parquet) => print_parquet(&mut out, &matches),
+                Some(OutputFormat::Markdown) => markdown_unsupported(),
+            }
+            warn_missing_houses(&missing);
+        }
+
+        Commands::Validate { file, schema } => {
+            let json = std::fs::read_to_string(&file).unwrap_or_else(|e| {
+                log::error!("Failed to read {}: {}", file.display(), e);
+                process::exit(1);
+            });
+
+            match validate::validate(&json, schema) {
+                Ok(()) => write_out(&mut out, &format!("{}: OK", file.display())),
+                Err(e) => {
+                    log::error!("{}: {}", file.display(), e);
+                    process::exit(1);
+                }
+            }
+        }
+
+        Commands::Snapshot {
+            url_or_slug,
+            out_path,
+            source,
+        } => {
+            let (url, html) = scraper
+                .get_page_html(&url_or_slug, source)
+                .await
+                .unwrap_or_else(|e| {
+                    log::error!("Error fetching page: {}", e);
+                    process::exit(exit_code(&e));
+                });
+
+            if let Some(parent) = out_path.parent()
+                && !parent.as_os_str().is_empty()
+            {
+                std::fs::create_dir_all(parent).unwrap_or_else(|e| {
+                    log::error!("Failed to create {}: {}", parent.display(), e);
+                    process::exit(1);
+                });
             }
+            std::fs::write(&out_path, &html).unwrap_or_else(|e| {
+                log::error!("Failed to write {}: {}", out_path.display(), e);
+                process::exit(1);
+            });
+            log::info!("Saved {} bytes to {}", html.len(), out_path.display());
+            write_out(&mut out, &url);
         }
+
+        Commands::Completions { shell } => {
+            let mut command = Cli::command();
+            let name = command.get_name().to_string();
+            // XXX: clap_complete panics when its writer fails, so the script is generated into
+            // a buffer and `--out-file` errors are reported like any other write error
+            let mut script = Vec::new();
+            clap_complete::generate(shell, &mut command, name, &mut script);
+            if let Err(e) = out.write_all(&script) {
+                log::error!("Write error: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if let Err(e) = out.flush() {
+        log::error!("Write error: {}", e);
+        process::exit(1);
     }
+    warn_skipped_rows(&skipped.lock().unwrap());
 }
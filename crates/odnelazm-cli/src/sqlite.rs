@@ -0,0 +1,277 @@
+use std::fmt;
+
+use rusqlite::{Connection, params_from_iter, types::Value as SqlValue};
+use serde_json::Value;
+
+#[derive(Debug)]
+pub enum WriteError {
+    Sqlite(rusqlite::Error),
+    Serialize(serde_json::Error),
+    /// The table already exists without some of the columns being written, e.g. a database
+    /// file reused for another command's output.
+    MissingColumns {
+        table: String,
+        columns: Vec<String>,
+    },
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteError::Sqlite(e) => write!(f, "{}", e),
+            WriteError::Serialize(e) => write!(f, "{}", e),
+            WriteError::MissingColumns { table, columns } => write!(
+                f,
+                "table '{}' already exists without column(s) {}; write to a new table or file",
+                table,
+                columns.join(", ")
+            ),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for WriteError {
+    fn from(e: rusqlite::Error) -> Self {
+        WriteError::Sqlite(e)
+    }
+}
+
+/// Write any serializable value into `table`, creating it if it does not exist. Appending to
+/// an existing table fails with [`WriteError::MissingColumns`] when it lacks a column of the
+/// records.
+///
+/// Records are mapped via JSON, mirroring the dataframe conversion: each top-level field
+/// becomes a column, scalar fields keep their SQLite type and nested values (lists, objects)
+/// are stored as JSON text. Single objects are written as a one-row table.
+pub fn write_table<T: serde::Serialize>(
+    conn: &mut Connection,
+    table: &str,
+    data: &T,
+) -> Result<usize, WriteError> {
+    let value = serde_json::to_value(data).map_err(WriteError::Serialize)?;
+    let records = match value {
+        Value::Array(items) => items,
+        other => vec![other],
+    };
+
+    // XXX: a column takes the type of its first non-null value; columns that are null in
+    // every record fall back to TEXT.
+    let mut columns: Vec<(String, Option<&'static str>)> = Vec::new();
+    for record in &records {
+        let Value::Object(fields) = record else {
+            continue;
+        };
+        for (name, field) in fields {
+            match columns.iter_mut().find(|(c, _)| c == name) {
+                Some((_, ty)) if ty.is_none() => *ty = column_type(field),
+                Some(_) => {}
+                None => columns.push((name.clone(), column_type(field))),
+            }
+        }
+    }
+
+    if columns.is_empty() {
+        return Ok(0);
+    }
+
+    let column_defs = columns
+        .iter()
+        .map(|(name, ty)| format!("{} {}", quote_ident(name), ty.unwrap_or("TEXT")))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let column_names = columns
+        .iter()
+        .map(|(name, _)| quote_ident(name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let placeholders = vec!["?"; columns.len()].join(", ");
+
+    let tx = conn.transaction()?;
+    tx.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {} ({})",
+            quote_ident(table),
+            column_defs
+        ),
+        [],
+    )?;
+
+    let existing = table_columns(&tx, table)?;
+    let missing: Vec<String> = columns
+        .iter()
+        .map(|(name, _)| name)
+        .filter(|name| !existing.contains(name))
+        .cloned()
+        .collect();
+    if !missing.is_empty() {
+        return Err(WriteError::MissingColumns {
+            table: table.to_string(),
+            columns: missing,
+        });
+    }
+
+    let mut inserted = 0;
+    {
+        let mut stmt = tx.prepare(&format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            quote_ident(table),
+            column_names,
+            placeholders
+        ))?;
+        for record in &records {
+            let row = columns
+                .iter()
+                .map(|(name, _)| to_sql_value(record.get(name).unwrap_or(&Value::Null)));
+            inserted += stmt.execute(params_from_iter(row))?;
+        }
+    }
+    tx.commit()?;
+
+    Ok(inserted)
+}
+
+fn table_columns(conn: &Connection, table: &str) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", quote_ident(table)))?;
+    stmt.query_map([], |row| row.get::<_, String>("name"))?
+        .collect()
+}
+
+fn column_type(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::Null => None,
+        Value::Bool(_) => Some("INTEGER"),
+        Value::Number(n) if n.is_i64() => Some("INTEGER"),
+        Value::Number(_) => Some("REAL"),
+        _ => Some("TEXT"),
+    }
+}
+
+fn to_sql_value(value: &Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(b) => SqlValue::Integer(i64::from(*b)),
+        Value::Number(n) => n
+            .as_i64()
+            .map(SqlValue::Integer)
+            .or_else(|| n.as_f64().map(SqlValue::Real))
+            .unwrap_or(SqlValue::Null),
+        Value::String(s) => SqlValue::Text(s.clone()),
+        nested => SqlValue::Text(nested.to_string()),
+    }
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use odnelazm::{DataSource, HansardListing, House};
+
+    fn listing(day: u32, house: House) -> HansardListing {
+        HansardListing {
+            house,
+            date: NaiveDate::from_ymd_opt(2026, 2, day).unwrap(),
+            url: format!("/democracy-tools/hansard/sitting-{day}/"),
+            title: format!("Thursday, {day}th February, 2026 - Afternoon Sitting"),
+            session_type: Some("Afternoon Sitting".to_string()),
+            start_time: None,
+            end_time: None,
+            source: DataSource::Current,
+        }
+    }
+
+    #[test]
+    fn test_write_listings_to_in_memory_db() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let listings = [
+            listing(12, House::NationalAssembly),
+            listing(19, House::Senate),
+        ];
+
+        let inserted = write_table(&mut conn, "listings", &listings).unwrap();
+        assert_eq!(inserted, 2);
+
+        let mut stmt = conn
+            .prepare("SELECT house, date, session_type, start_time FROM listings ORDER BY date")
+            .unwrap();
+        let rows: Vec<(String, String, Option<String>, Option<String>)> = stmt
+            .query_map([], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, "national_assembly");
+        assert_eq!(rows[0].1, "2026-02-12");
+        assert_eq!(rows[0].2.as_deref(), Some("Afternoon Sitting"));
+        assert_eq!(rows[0].3, None);
+        assert_eq!(rows[1].0, "senate");
+
+        let (url, source): (String, String) = conn
+            .query_row(
+                "SELECT url, source FROM listings WHERE house = 'senate'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(url, "/democracy-tools/hansard/sitting-19/");
+        assert_eq!(source, "https://mzalendo.com");
+    }
+
+    #[test]
+    fn test_write_appends_to_existing_table() {
+        let mut conn = Connection::open_in_memory().unwrap();
+
+        write_table(&mut conn, "listings", &[listing(12, House::Senate)]).unwrap();
+        write_table(&mut conn, "listings", &listing(19, House::Senate)).unwrap();
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM listings", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_append_with_other_columns_fails() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        write_table(&mut conn, "out", &serde_json::json!({ "name": "Kitur" })).unwrap();
+
+        let err = write_table(&mut conn, "out", &listing(12, House::Senate)).unwrap_err();
+
+        let WriteError::MissingColumns { table, columns } = &err else {
+            panic!("Expected MissingColumns, got {err:?}");
+        };
+        assert_eq!(table, "out");
+        assert!(columns.contains(&"house".to_string()));
+        assert!(!columns.contains(&"name".to_string()));
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM out", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1, "Nothing should be appended");
+    }
+
+    #[test]
+    fn test_nested_fields_are_stored_as_json() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let value = serde_json::json!({
+            "name": "Boss Gladys Jepkosgei",
+            "committees": ["Health"],
+            "bills_total": 8,
+        });
+
+        write_table(&mut conn, "profiles", &value).unwrap();
+
+        let (committees, bills_total): (String, i64) = conn
+            .query_row("SELECT committees, bills_total FROM profiles", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(committees, r#"["Health"]"#);
+        assert_eq!(bills_total, 8);
+    }
+}
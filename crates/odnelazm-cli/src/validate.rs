@@ -0,0 +1,87 @@
+use clap::ValueEnum;
+use odnelazm::{HansardListing, HansardSitting, Member, MemberProfile};
+use serde::de::DeserializeOwned;
+
+/// Shapes produced by the CLI's JSON output, named after the command that writes them.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Schema {
+    /// A list of sitting listings (`sittings`)
+    Sittings,
+    /// A full sitting transcript (`sitting`)
+    Sitting,
+    /// A list of members (`members`, `all-members`)
+    Members,
+    /// A member profile (`profile`)
+    Profile,
+}
+
+/// Deserialize `json` as `schema`. Errors carry the path to the offending field,
+/// e.g. `sections[0].subsections: invalid type: string "none", expected a sequence`.
+pub fn validate(
+    json: &str,
+    schema: Schema,
+) -> Result<(), serde_path_to_error::Error<serde_json::Error>> {
+    match schema {
+        Schema::Sittings => check::<Vec<HansardListing>>(json),
+        Schema::Sitting => check::<HansardSitting>(json),
+        Schema::Members => check::<Vec<Member>>(json),
+        Schema::Profile => check::<MemberProfile>(json),
+    }
+}
+
+fn check<T: DeserializeOwned>(
+    json: &str,
+) -> Result<(), serde_path_to_error::Error<serde_json::Error>> {
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    serde_path_to_error::deserialize::<_, T>(deserializer).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SITTING: &str = r#"{
+        "house": "senate",
+        "date": "2026-02-12",
+        "url": "/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2434/",
+        "session_type": "Afternoon Sitting",
+        "source": "https://mzalendo.com",
+        "sections": [
+            {
+                "section_type": "MOTIONS",
+                "subsections": [],
+                "contributions": [
+                    {
+                        "speaker_name": "Sen. Cherarkey",
+                        "content": "Mr. Speaker, Sir, I beg to move.",
+                        "procedural_notes": []
+                    }
+                ]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_validate_valid_sitting() {
+        validate(SITTING, Schema::Sitting).expect("Sitting should validate");
+    }
+
+    #[test]
+    fn test_validate_reports_path_of_broken_field() {
+        let broken = SITTING.replace(r#""subsections": []"#, r#""subsections": "none""#);
+
+        let err = validate(&broken, Schema::Sitting).expect_err("Sitting should not validate");
+
+        assert_eq!(err.path().to_string(), "sections[0].subsections");
+        assert!(
+            err.to_string()
+                .starts_with("sections[0].subsections: invalid type")
+        );
+    }
+
+    #[test]
+    fn test_validate_wrong_schema() {
+        let err = validate(SITTING, Schema::Members).expect_err("A sitting is not a member list");
+        assert_eq!(err.path().to_string(), ".");
+    }
+}
@@ -1,7 +1,7 @@
 use chrono::NaiveDate;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use futures::future;
-use odnelazm::HansardScraper;
+use odnelazm::{CURRENT_PARLIAMENT, HansardScraper, Parliament};
 use odnelazm_ingest::{
     DataStore, IngestPipeline,
     enricher::{LmStudioSummarizer, prompts},
@@ -9,6 +9,7 @@ use odnelazm_ingest::{
     postgres::PostgresStore,
     summarize::{Summarizer, SummaryContext},
 };
+use std::str::FromStr;
 use std::sync::Arc;
 use std::{fmt::Display, process};
 
@@ -58,8 +59,12 @@ struct IngestCmd {
     concurrency: usize,
 
     /// Parliament session to import members from
-    #[arg(long, default_value = "13th-parliament")]
-    parliament: String,
+    #[arg(
+        long,
+        default_value = CURRENT_PARLIAMENT,
+        value_parser = |s: &str| Parliament::from_str(s).map_err(|e| e.to_string()),
+    )]
+    parliament: Parliament,
 
     /// Skip scraping sittings
     #[arg(long)]
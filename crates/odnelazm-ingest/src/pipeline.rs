@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use odnelazm::{HansardScraper, HansardSitting, SittingListOptions};
+use odnelazm::{HansardScraper, HansardSitting, Parliament, SittingListOptions};
 
 use crate::{
     Result,
@@ -382,8 +382,12 @@ impl<S: DataStore> IngestPipeline<S> {
     }
 
     // XXX: limited to 2013-current (mzalendo.com)
-    pub async fn import_members(&self, parliament: &str) -> Result<u64> {
-        let members = self.scraper.list_all_members_all_houses(parliament).await?;
+    pub async fn import_members(&self, parliament: &Parliament) -> Result<u64> {
+        let members = self
+            .scraper
+            .list_all_members_all_houses(parliament)
+            .await
+            .into_result()?;
         log::info!("Importing {} members for {parliament}...", members.len());
 
         for member in &members {
@@ -394,7 +398,11 @@ impl<S: DataStore> IngestPipeline<S> {
                     house: member.house.to_string(),
                     parliament: parliament.to_string(),
                     role: member.role.clone(),
-                    constituency: member.constituency.clone(),
+                    // XXX: senators and woman representatives sit for a county
+                    constituency: member
+                        .constituency
+                        .clone()
+                        .or_else(|| member.county.clone()),
                 })
                 .await?;
         }
@@ -443,7 +451,11 @@ impl<S: DataStore> IngestPipeline<S> {
                             biography: profile.biography,
                             party: profile.party,
                             positions: profile.positions,
-                            committees: profile.committees,
+                            committees: profile
+                                .committees
+                                .iter()
+                                .map(ToString::to_string)
+                                .collect(),
                             speeches_last_year: profile.speeches_last_year,
                             speeches_total: profile.speeches_total,
                             bills_total: profile.bills_total,
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Environment variable overriding the response cache TTL, in seconds. `0` disables caching.
+pub const CACHE_TTL_ENV: &str = "ODNELAZM_MCP_CACHE_TTL_SECS";
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Serialized tool responses keyed on the tool name and its resolved parameters.
+#[derive(Debug)]
+pub struct TtlCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, String)>>,
+}
+
+impl TtlCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::default(),
+        }
+    }
+
+    /// Read the TTL from `ODNELAZM_MCP_CACHE_TTL_SECS`, falling back to 10 minutes.
+    pub fn from_env() -> Result<Self, anyhow::Error> {
+        let ttl = match std::env::var(CACHE_TTL_ENV) {
+            Ok(value) if !value.trim().is_empty() => Duration::from_secs(
+                value
+                    .trim()
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid {CACHE_TTL_ENV} '{value}': {e}"))?,
+            ),
+            _ => DEFAULT_CACHE_TTL,
+        };
+        Ok(Self::new(ttl))
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().ok()?;
+        match entries.get(key) {
+            Some((inserted, value)) if inserted.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: String, value: String) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, (inserted, _)| inserted.elapsed() < self.ttl);
+            entries.insert(key, (Instant::now(), value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_hit_within_ttl() {
+        let cache = TtlCache::new(DEFAULT_CACHE_TTL);
+        assert_eq!(cache.get("get_sitting:a"), None);
+
+        cache.insert("get_sitting:a".to_string(), "{}".to_string());
+
+        assert_eq!(cache.get("get_sitting:a").as_deref(), Some("{}"));
+        assert_eq!(cache.get("get_sitting:b"), None);
+    }
+
+    #[test]
+    fn test_cache_expires_entries() {
+        let cache = TtlCache::new(Duration::from_millis(10));
+        cache.insert("get_sitting:a".to_string(), "{}".to_string());
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(cache.get("get_sitting:a"), None);
+    }
+
+    #[test]
+    fn test_zero_ttl_disables_cache() {
+        let cache = TtlCache::new(Duration::ZERO);
+        cache.insert("get_sitting:a".to_string(), "{}".to_string());
+
+        assert_eq!(cache.get("get_sitting:a"), None);
+    }
+}
@@ -0,0 +1,216 @@
+use chrono::NaiveDate;
+use odnelazm::{Contribution, HansardSitting, House, normalize_search_text};
+use serde::Serialize;
+
+/// Phrases that mark a legislative stage, paired with the stage they name.
+const STAGES: &[(&str, &str)] = &[
+    ("first reading", "first reading"),
+    ("read a first time", "first reading"),
+    ("read the first time", "first reading"),
+    ("second reading", "second reading"),
+    ("read a second time", "second reading"),
+    ("read the second time", "second reading"),
+    (
+        "committee of the whole house",
+        "committee of the whole house",
+    ),
+    ("report stage", "report stage"),
+    ("third reading", "third reading"),
+    ("read a third time", "third reading"),
+    ("read the third time", "third reading"),
+    (
+        "consideration of senate amendments",
+        "consideration of senate amendments",
+    ),
+    (
+        "consideration of national assembly amendments",
+        "consideration of national assembly amendments",
+    ),
+    ("mediation", "mediation"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BillAppearance {
+    pub date: NaiveDate,
+    pub house: House,
+    pub sitting_url: String,
+    pub section_type: String,
+    pub title: String,
+    /// First legislative stage named in the proceedings, e.g. "second reading".
+    pub stage: Option<String>,
+    pub contributions: usize,
+}
+
+/// Every place `bill` is taken up in `sitting`.
+///
+/// Current-source sittings list bills as subsection titles under a BILLS (or similar) section;
+/// archive sittings fold the title into `section_type`, so both are matched.
+pub fn bill_appearances(sitting: &HansardSitting, bill: &str) -> Vec<BillAppearance> {
+    let query = normalize_search_text(bill);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let appearance =
+        |section_type: &str, title: &str, contributions: &[Contribution]| BillAppearance {
+            date: sitting.date,
+            house: sitting.house,
+            sitting_url: sitting.url.clone(),
+            section_type: section_type.to_string(),
+            title: title.to_string(),
+            stage: detect_stage(contributions),
+            contributions: contributions.len(),
+        };
+
+    let mut appearances = Vec::new();
+    for section in &sitting.sections {
+        if section.subsections.is_empty() {
+            if normalize_search_text(&section.section_type).contains(&query) {
+                appearances.push(appearance(
+                    &section.section_type,
+                    &section.section_type,
+                    &section.contributions,
+                ));
+            }
+            continue;
+        }
+        for subsection in &section.subsections {
+            if normalize_search_text(&subsection.title).contains(&query) {
+                appearances.push(appearance(
+                    &section.section_type,
+                    &subsection.title,
+                    &subsection.contributions,
+                ));
+            }
+        }
+    }
+    appearances
+}
+
+/// Order appearances chronologically so the journey reads top-to-bottom.
+pub fn sort_journey(appearances: &mut [BillAppearance]) {
+    appearances.sort_by(|a, b| {
+        a.date
+            .cmp(&b.date)
+            .then_with(|| a.sitting_url.cmp(&b.sitting_url))
+    });
+}
+
+fn detect_stage(contributions: &[Contribution]) -> Option<String> {
+    let text = contributions
+        .iter()
+        .flat_map(|c| std::iter::once(&c.content).chain(c.procedural_notes.iter()))
+        .map(|t| t.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    STAGES
+        .iter()
+        .filter_map(|(phrase, stage)| text.find(phrase).map(|i| (i, *stage)))
+        .min_by_key(|(i, _)| *i)
+        .map(|(_, stage)| stage.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use odnelazm::{HansardSection, HansardSubsection};
+
+    fn contribution(content: &str, notes: &[&str]) -> Contribution {
+        Contribution {
+            procedural_notes: notes.iter().map(|n| n.to_string()).collect(),
+            ..test_support::contribution("Hon. Kuria Kimani", content)
+        }
+    }
+
+    fn sitting(day: u32, sections: Vec<HansardSection>) -> HansardSitting {
+        test_support::sitting(NaiveDate::from_ymd_opt(2025, 6, day).unwrap(), sections)
+    }
+
+    fn bills_section(title: &str, contributions: Vec<Contribution>) -> HansardSection {
+        HansardSection {
+            section_type: "BILLS".to_string(),
+            subsections: vec![HansardSubsection {
+                title: title.to_string(),
+                contributions,
+                referred_to_committee: None,
+                statement_kind: None,
+            }],
+            contributions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_bill_journey_is_chronological() {
+        let title = "THE FINANCE BILL (National Assembly Bill No.30 of 2025)";
+        let third = sitting(
+            24,
+            vec![bills_section(
+                title,
+                vec![contribution(
+                    "I beg to move that the Finance Bill be now read a Third Time.",
+                    &["(The Bill was read the Third Time and passed)"],
+                )],
+            )],
+        );
+        let second = sitting(
+            10,
+            vec![
+                bills_section(
+                    title,
+                    vec![
+                        contribution(
+                            "I beg to move that the Bill be now read a Second Time.",
+                            &[],
+                        ),
+                        contribution("I rise to support.", &["(Applause)"]),
+                    ],
+                ),
+                bills_section("THE HEALTH (AMENDMENT) BILL", vec![]),
+            ],
+        );
+
+        let mut journey: Vec<BillAppearance> = [&third, &second]
+            .into_iter()
+            .flat_map(|s| bill_appearances(s, "Finance Bill"))
+            .collect();
+        sort_journey(&mut journey);
+
+        assert_eq!(journey.len(), 2);
+        assert_eq!(
+            journey[0].date,
+            NaiveDate::from_ymd_opt(2025, 6, 10).unwrap()
+        );
+        assert_eq!(journey[0].stage.as_deref(), Some("second reading"));
+        assert_eq!(journey[0].contributions, 2);
+        assert_eq!(journey[0].section_type, "BILLS");
+        assert_eq!(journey[1].stage.as_deref(), Some("third reading"));
+    }
+
+    #[test]
+    fn test_bill_appearances_by_number_and_archive_sections() {
+        let current = sitting(
+            10,
+            vec![bills_section(
+                "THE FINANCE BILL (National Assembly Bill No.30 of 2025)",
+                vec![],
+            )],
+        );
+        assert_eq!(bill_appearances(&current, "Bill No. 30 of 2025").len(), 1);
+        assert!(bill_appearances(&current, "Bill No. 31 of 2025").is_empty());
+
+        let archive = sitting(
+            3,
+            vec![HansardSection {
+                section_type: "BILLS: The Finance Bill".to_string(),
+                subsections: vec![],
+                contributions: vec![contribution("Second Reading", &[])],
+            }],
+        );
+        let appearances = bill_appearances(&archive, "finance bill");
+        assert_eq!(appearances.len(), 1);
+        assert_eq!(appearances[0].title, "BILLS: The Finance Bill");
+        assert_eq!(appearances[0].contributions, 1);
+    }
+}
@@ -1,3 +1,13 @@
+mod cache;
+mod journey;
 mod mcp;
+mod resources;
+mod search;
+#[cfg(test)]
+mod test_support;
+mod timeline;
+mod topics;
+mod truncate;
 
-pub use mcp::McpServer;
+pub use cache::CACHE_TTL_ENV;
+pub use mcp::{DEFAULT_HOUSE_ENV, McpServer};
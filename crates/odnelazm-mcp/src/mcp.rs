@@ -1,30 +1,84 @@
-use odnelazm::{HansardScraper, House, SittingListOptions};
+use futures::{StreamExt, stream};
+use odnelazm::{HansardScraper, House, Parliament, SittingListOptions, normalize_search_text};
 use rmcp::{
-    ServerHandler,
+    RoleServer, ServerHandler,
     handler::server::{tool::ToolRouter, wrapper::Parameters},
-    model::{ErrorData as McpError, ServerCapabilities, ServerInfo},
+    model::{
+        AnnotateAble, ErrorData as McpError, ListResourceTemplatesResult, ListResourcesResult,
+        PaginatedRequestParams, RawResource, RawResourceTemplate, ReadResourceRequestParams,
+        ReadResourceResult, ResourceContents, ServerCapabilities, ServerInfo,
+    },
+    service::RequestContext,
     tool, tool_handler, tool_router,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
 
 use chrono::NaiveDate;
 
+use crate::cache::TtlCache;
+use crate::journey::{self, BillAppearance};
+use crate::resources::{self, ResourceUri};
+use crate::search::{self, CachedProfile, EntityType};
+use crate::timeline;
+use crate::topics;
+use crate::truncate;
+
+/// Upper bound on sittings fetched by a single `bill_journey` call.
+const MAX_JOURNEY_SITTINGS: usize = 60;
+/// Sittings fetched concurrently by `bill_journey`.
+const JOURNEY_CONCURRENCY: usize = 8;
+/// Activity pages fetched by `member_activity_timeline` when `max_pages` is not given.
+const DEFAULT_TIMELINE_PAGES: u32 = 5;
+/// Upper bound on activity pages fetched by a single `member_activity_timeline` call.
+const MAX_TIMELINE_PAGES: u32 = 30;
+/// Profiles kept for the `search` tool; the least recently fetched is dropped past this.
+const MAX_CACHED_PROFILES: usize = 50;
+
+/// Environment variable holding the default house for single-house deployments.
+pub const DEFAULT_HOUSE_ENV: &str = "ODNELAZM_MCP_DEFAULT_HOUSE";
+
 #[derive(Debug, Clone)]
 pub struct McpServer {
     scraper: HansardScraper,
+    default_house: Option<House>,
+    // XXX: profiles fetched during the session, searched by the `search` tool for bills and
+    // topics since those are only reachable through member profiles.
+    profiles: Arc<RwLock<Vec<CachedProfile>>>,
+    cache: Arc<TtlCache>,
     tool_router: ToolRouter<Self>,
 }
 
 #[tool_router]
 impl McpServer {
+    /// Create a server, reading the default house from `ODNELAZM_MCP_DEFAULT_HOUSE` and the
+    /// response cache TTL from `ODNELAZM_MCP_CACHE_TTL_SECS` if set.
     pub fn new() -> Result<Self, anyhow::Error> {
+        let default_house = match std::env::var(DEFAULT_HOUSE_ENV) {
+            Ok(value) if !value.trim().is_empty() => Some(value.trim().parse::<House>()?),
+            _ => None,
+        };
+        if let Some(house) = default_house {
+            log::info!("Using {house} as the default house");
+        }
+
         Ok(Self {
             scraper: HansardScraper::new()?,
+            default_house,
+            profiles: Arc::default(),
+            cache: Arc::new(TtlCache::from_env()?),
             tool_router: Self::tool_router(),
         })
     }
 
+    /// Override the default house used when a tool call omits `house`.
+    /// An explicit `house` parameter always takes precedence.
+    pub fn with_default_house(mut self, house: Option<House>) -> Self {
+        self.default_house = house;
+        self
+    }
+
     #[tool(
         name = "list_sittings",
         description = "List parliamentary sittings with automatic source routing. If `end_date` is before 2013-03-28 the archive (info.mzalendo.com) is used. If `start_date` is on or after 2013-03-28 the current source (mzalendo.com) is used. If the range spans the cutoff — or one bound is absent while the other crosses it — both sources are queried in parallel and results are merged by date. With no dates, the current source is used with `page`/`all` pagination. Use `limit`/`offset` to slice the final result."
@@ -55,16 +109,31 @@ impl McpServer {
             ));
         }
 
+        let house = params.house.or(self.default_house);
+        let key = format!(
+            "list_sittings:{:?}:{:?}:{house:?}:{}:{}:{:?}:{:?}",
+            params.start_date,
+            params.end_date,
+            params.page.unwrap_or(1),
+            params.all,
+            params.limit,
+            params.offset
+        );
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
+
         let listings = self
             .scraper
             .list_sittings(SittingListOptions {
                 start_date: params.start_date,
                 end_date: params.end_date,
-                house: params.house,
+                house,
                 page: params.page.unwrap_or(1),
                 all: params.all,
                 limit: params.limit,
                 offset: params.offset,
+                ..Default::default()
             })
             .await
             .inspect_err(|e| log::error!("Failed to fetch sittings: {e}"))
@@ -72,17 +141,32 @@ impl McpServer {
                 McpError::internal_error(format!("Failed to fetch sittings: {e}"), None)
             })?;
 
-        serialize_list(listings)
+        serialize_list(listings).map(|json| self.store(key, json))
     }
 
     #[tool(
         name = "get_sitting",
-        description = "Fetch the full transcript of a parliamentary sitting, including sections, subsections, contributions, and procedural notes. The data source (archive or current) is detected automatically from the URL — archive URLs contain info.mzalendo.com, current URLs contain mzalendo.com/democracy-tools."
+        description = "Fetch the full transcript of a parliamentary sitting, including sections, subsections, contributions, and procedural notes. Set `max_chars` to cut each contribution's text to that many characters (marked `truncated: true`) when the full transcript would be too large. The data source (archive or current) is detected automatically from the URL — archive URLs contain info.mzalendo.com, current URLs contain mzalendo.com/democracy-tools."
     )]
     pub async fn get_sitting(
         &self,
         Parameters(params): Parameters<GetSittingParams>,
     ) -> Result<String, McpError> {
+        if params.max_chars == Some(0) {
+            return Err(McpError::invalid_params(
+                "max_chars must be greater than 0",
+                None,
+            ));
+        }
+        let key = format!(
+            "get_sitting:{}:{:?}",
+            self.scraper.sitting_urls(&params.url_or_slug).join(" "),
+            params.max_chars
+        );
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
+
         let sitting = self
             .scraper
             .get_sitting(&params.url_or_slug)
@@ -90,22 +174,78 @@ impl McpServer {
             .inspect_err(|e| log::error!("Failed to fetch sitting: {e}"))
             .map_err(|e| McpError::internal_error(format!("Failed to fetch sitting: {e}"), None))?;
 
-        serde_json::to_string_pretty(&sitting).map_err(|e| {
+        let json = match params.max_chars {
+            Some(max_chars) => serde_json::to_value(&sitting).and_then(|mut sitting| {
+                truncate::truncate_sitting(&mut sitting, max_chars);
+                serde_json::to_string_pretty(&sitting)
+            }),
+            None => serde_json::to_string_pretty(&sitting),
+        };
+        json.map(|json| self.store(key, json)).map_err(|e| {
             McpError::internal_error(format!("Failed to serialize sitting: {e}"), None)
         })
     }
 
+    #[tool(
+        name = "sitting_topics",
+        description = "A table of contents for a sitting: the sitting's summary and sentiment, then each section type and subsection title (e.g. a bill or statement) with its number of contributions and the distinct speakers, without the transcript text. Use it to find the part of a long sitting worth reading before calling `get_sitting`."
+    )]
+    pub async fn sitting_topics(
+        &self,
+        Parameters(params): Parameters<SittingTopicsParams>,
+    ) -> Result<String, McpError> {
+        let key = format!(
+            "sitting_topics:{}",
+            self.scraper.sitting_urls(&params.url_or_slug).join(" ")
+        );
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
+
+        let sitting = self
+            .scraper
+            .get_sitting(&params.url_or_slug)
+            .await
+            .inspect_err(|e| log::error!("Failed to fetch sitting: {e}"))
+            .map_err(|e| McpError::internal_error(format!("Failed to fetch sitting: {e}"), None))?;
+
+        serde_json::to_string_pretty(&topics::sitting_topics(&sitting))
+            .map(|json| self.store(key, json))
+            .map_err(|e| {
+                McpError::internal_error(format!("Failed to serialize sitting topics: {e}"), None)
+            })
+    }
+
     #[tool(
         name = "list_members",
-        description = "List members of parliament from the current source (mzalendo.com). Requires a house ('national_assembly' or 'senate') and parliament session (e.g. '13th-parliament'). Set `all` to true to fetch all pages at once."
+        description = "List members of parliament from the current source (mzalendo.com). Requires a house ('national_assembly' or 'senate') unless the server is configured with a default house, and a parliament session (e.g. '13th-parliament'). Set `all` to true to fetch all pages at once."
     )]
     pub async fn list_members(
         &self,
         Parameters(params): Parameters<ListMembersParams>,
     ) -> Result<String, McpError> {
+        let Some(house) = params.house.or(self.default_house) else {
+            return Err(McpError::invalid_params(
+                "house is required: 'national_assembly' or 'senate'",
+                None,
+            ));
+        };
+        let parliament = parse_parliament(Some(&params.parliament))?;
+        let key = if params.all {
+            format!("list_members:{house}:{parliament}:all")
+        } else {
+            format!(
+                "list_members:{house}:{parliament}:{}",
+                params.page.unwrap_or(1)
+            )
+        };
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
+
         let members = if params.all {
             self.scraper
-                .list_all_members(params.house, &params.parliament)
+                .list_all_members(house, &parliament)
                 .await
                 .inspect_err(|e| log::error!("Failed to fetch all members: {e}"))
                 .map_err(|e| {
@@ -114,7 +254,7 @@ impl McpServer {
         } else {
             let page = params.page.unwrap_or(1);
             self.scraper
-                .list_members(params.house, &params.parliament, page)
+                .list_members(house, &parliament, page)
                 .await
                 .inspect_err(|e| log::error!("Failed to fetch members page {page}: {e}"))
                 .map_err(|e| {
@@ -122,7 +262,7 @@ impl McpServer {
                 })?
         };
 
-        serialize_list(members)
+        serialize_list(members).map(|json| self.store(key, json))
     }
 
     #[tool(
@@ -133,18 +273,29 @@ impl McpServer {
         &self,
         Parameters(params): Parameters<GetAllMembersParams>,
     ) -> Result<String, McpError> {
-        let parliament = params.parliament.as_deref().unwrap_or("13th-parliament");
+        let parliament = parse_parliament(params.parliament.as_deref())?;
+        let key = format!("get_all_members:{parliament}");
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
 
-        let members = self
-            .scraper
-            .list_all_members_all_houses(parliament)
-            .await
+        let fetched = self.scraper.list_all_members_all_houses(&parliament).await;
+        // XXX: a house that failed is left out of the list, which is not cached so the next
+        // call retries it.
+        let complete = fetched.errors.is_empty();
+        let members = fetched
+            .into_result()
             .inspect_err(|e| log::error!("Failed to fetch all members (all houses): {e}"))
             .map_err(|e| {
                 McpError::internal_error(format!("Failed to fetch all members: {e}"), None)
             })?;
 
-        serialize_list(members)
+        let json = serialize_list(members)?;
+        Ok(if complete {
+            self.store(key, json)
+        } else {
+            json
+        })
     }
 
     #[tool(
@@ -155,6 +306,16 @@ impl McpServer {
         &self,
         Parameters(params): Parameters<GetMemberProfileParams>,
     ) -> Result<String, McpError> {
+        let key = format!(
+            "get_member_profile:{}:{}:{}",
+            self.scraper.member_url(&params.url_or_slug),
+            params.all_activity,
+            params.all_bills
+        );
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
+
         let profile = self
             .scraper
             .get_member_profile(&params.url_or_slug, params.all_activity, params.all_bills)
@@ -164,12 +325,261 @@ impl McpServer {
                 McpError::internal_error(format!("Failed to fetch member profile: {e}"), None)
             })?;
 
-        serde_json::to_string_pretty(&profile).map_err(|e| {
+        let json = serde_json::to_string_pretty(&profile).map_err(|e| {
             McpError::internal_error(format!("Failed to serialize profile: {e}"), None)
-        })
+        })?;
+
+        if let Ok(mut profiles) = self.profiles.write() {
+            profiles.retain(|cached| cached.profile.slug != profile.slug);
+            if profiles.len() >= MAX_CACHED_PROFILES {
+                profiles.remove(0);
+            }
+            profiles.push(CachedProfile {
+                url: params.url_or_slug,
+                profile,
+            });
+        }
+
+        Ok(self.store(key, json))
+    }
+
+    #[tool(
+        name = "member_contributions",
+        description = "Fetch only a member's parliamentary activity (recent contributions: date, topic, contribution type, section, sitting URL, text preview) without the rest of the profile. Faster and smaller than `get_member_profile` for questions like 'what did X say recently'. Returns page 1 by default; set `page` for older activity or `all` to fetch every page."
+    )]
+    pub async fn member_contributions(
+        &self,
+        Parameters(params): Parameters<MemberContributionsParams>,
+    ) -> Result<String, McpError> {
+        let url = self.scraper.member_url(&params.url_or_slug);
+        let key = if params.all {
+            format!("member_contributions:{url}:all")
+        } else {
+            format!("member_contributions:{url}:{}", params.page.unwrap_or(1))
+        };
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
+
+        let activity = if params.all {
+            self.scraper
+                .get_all_member_activity(&params.url_or_slug)
+                .await
+                .inspect_err(|e| log::error!("Failed to fetch all member activity: {e}"))
+                .map_err(|e| {
+                    McpError::internal_error(format!("Failed to fetch member activity: {e}"), None)
+                })?
+        } else {
+            let page = params.page.unwrap_or(1);
+            self.scraper
+                .get_member_activity(&params.url_or_slug, page)
+                .await
+                .inspect_err(|e| log::error!("Failed to fetch member activity page {page}: {e}"))
+                .map_err(|e| {
+                    McpError::internal_error(format!("Failed to fetch member activity: {e}"), None)
+                })?
+        };
+
+        serialize_list(activity).map(|json| self.store(key, json))
+    }
+
+    #[tool(
+        name = "member_activity_timeline",
+        description = "Summarise a member's parliamentary activity over time. Fetches the member's activity pages (newest first) and returns a month-by-month timeline with the number of contributions and the topics spoken on in each month, most frequent first. Fetches 5 pages by default and at most 30; `truncated` is true when older pages were left out, so the earliest months may be missing."
+    )]
+    pub async fn member_activity_timeline(
+        &self,
+        Parameters(params): Parameters<MemberActivityTimelineParams>,
+    ) -> Result<String, McpError> {
+        let max_pages = params.max_pages.unwrap_or(DEFAULT_TIMELINE_PAGES);
+        if max_pages == 0 || max_pages > MAX_TIMELINE_PAGES {
+            return Err(McpError::invalid_params(
+                format!("max_pages must be between 1 and {MAX_TIMELINE_PAGES}"),
+                None,
+            ));
+        }
+        let key = format!(
+            "member_activity_timeline:{}:{max_pages}",
+            self.scraper.member_url(&params.url_or_slug)
+        );
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
+
+        let (activity, total_pages) = self
+            .scraper
+            .get_member_activity_pages(&params.url_or_slug, max_pages)
+            .await
+            .inspect_err(|e| log::error!("Failed to fetch member activity for timeline: {e}"))
+            .map_err(|e| {
+                McpError::internal_error(format!("Failed to fetch member activity: {e}"), None)
+            })?;
+
+        let timeline =
+            timeline::activity_timeline(&activity, max_pages.min(total_pages), total_pages);
+        let json = serde_json::to_string_pretty(&timeline).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize timeline: {e}"), None)
+        })?;
+
+        Ok(self.store(key, json))
+    }
+
+    #[tool(
+        name = "bill_journey",
+        description = "Trace a bill across the record. Given a bill name or number (e.g. 'Finance Bill' or 'Bill No. 30 of 2025'), fetches the sittings in the date range and returns every appearance of the bill in chronological order, with the sitting date, house, URL, section type, subsection title, detected stage (e.g. 'second reading'), and the number of contributions. Without dates only the most recent page of sittings is scanned. At most 60 sittings are fetched per call; narrow the date range or filter by house if the range is larger."
+    )]
+    pub async fn bill_journey(
+        &self,
+        Parameters(params): Parameters<BillJourneyParams>,
+    ) -> Result<String, McpError> {
+        if params.bill.trim().is_empty() {
+            return Err(McpError::invalid_params("bill cannot be empty", None));
+        }
+        if let Some(start) = params.start_date
+            && let Some(end) = params.end_date
+            && start > end
+        {
+            return Err(McpError::invalid_params(
+                "start_date cannot be after end_date",
+                None,
+            ));
+        }
+        let house = params.house.or(self.default_house);
+        let key = format!(
+            "bill_journey:{}:{:?}:{:?}:{house:?}",
+            normalize_search_text(&params.bill),
+            params.start_date,
+            params.end_date
+        );
+        if let Some(json) = self.cached(&key) {
+            return Ok(json);
+        }
+
+        let listings = self
+            .scraper
+            .list_sittings(SittingListOptions {
+                start_date: params.start_date,
+                end_date: params.end_date,
+                house,
+                page: 1,
+                ..Default::default()
+            })
+            .await
+            .inspect_err(|e| log::error!("Failed to fetch sittings for bill journey: {e}"))
+            .map_err(|e| {
+                McpError::internal_error(format!("Failed to fetch sittings: {e}"), None)
+            })?;
+
+        if listings.len() > MAX_JOURNEY_SITTINGS {
+            return Err(McpError::invalid_params(
+                format!(
+                    "The date range covers {} sittings; narrow it to at most {MAX_JOURNEY_SITTINGS}",
+                    listings.len()
+                ),
+                None,
+            ));
+        }
+
+        log::info!(
+            "Tracing '{}' across {} sitting(s)",
+            params.bill,
+            listings.len()
+        );
+        let mut appearances: Vec<BillAppearance> = stream::iter(listings)
+            .map(|listing| async move {
+                self.scraper
+                    .get_sitting(&listing.url)
+                    .await
+                    .inspect_err(|e| log::warn!("Failed to fetch sitting {}: {e}", listing.url))
+                    .ok()
+            })
+            .buffer_unordered(JOURNEY_CONCURRENCY)
+            .flat_map(|sitting| {
+                stream::iter(
+                    sitting
+                        .map(|s| journey::bill_appearances(&s, &params.bill))
+                        .unwrap_or_default(),
+                )
+            })
+            .collect()
+            .await;
+        journey::sort_journey(&mut appearances);
+
+        serialize_list(appearances).map(|json| self.store(key, json))
+    }
+
+    #[tool(
+        name = "search",
+        description = "Fuzzy search for members by name or constituency, bills by name or number, and topics from members' parliamentary activity. Members are searched in a freshly fetched list of both houses for the given parliament; bills and topics are searched only within the last 50 member profiles fetched with `get_member_profile` in this session. Results are ranked by score (0-100) and include canonical URLs: the member profile for members and bills, the contribution for topics."
+    )]
+    pub async fn search(
+        &self,
+        Parameters(params): Parameters<SearchParams>,
+    ) -> Result<String, McpError> {
+        if params.query.trim().is_empty() {
+            return Err(McpError::invalid_params("query cannot be empty", None));
+        }
+        if params.limit.is_some_and(|l| l == 0) {
+            return Err(McpError::invalid_params(
+                "limit must be greater than 0",
+                None,
+            ));
+        }
+
+        let members = if params.entity_type.is_none_or(|ty| ty == EntityType::Member) {
+            let parliament = parse_parliament(params.parliament.as_deref())?;
+            self.scraper
+                .list_all_members_all_houses(&parliament)
+                .await
+                .into_result()
+                .inspect_err(|e| log::error!("Failed to fetch members for search: {e}"))
+                .map_err(|e| {
+                    McpError::internal_error(format!("Failed to fetch members: {e}"), None)
+                })?
+        } else {
+            Vec::new()
+        };
+
+        let mut results = {
+            let profiles = self.profiles.read().map_err(|e| {
+                McpError::internal_error(format!("Failed to read profile cache: {e}"), None)
+            })?;
+            search::search(&params.query, params.entity_type, &members, &profiles)
+        };
+        results.truncate(params.limit.unwrap_or(10));
+
+        serialize_list(results)
+    }
+}
+
+impl McpServer {
+    fn cached(&self, key: &str) -> Option<String> {
+        let hit = self.cache.get(key);
+        log::debug!(
+            "Cache {} for {key}",
+            if hit.is_some() { "hit" } else { "miss" }
+        );
+        hit
+    }
+
+    /// Cache a serialized response and hand it back.
+    fn store(&self, key: String, json: String) -> String {
+        self.cache.insert(key, json.clone());
+        json
     }
 }
 
+/// The requested parliament, or the sitting one when unset.
+fn parse_parliament(parliament: Option<&str>) -> Result<Parliament, McpError> {
+    parliament.map_or_else(
+        || Ok(Parliament::current()),
+        |p| {
+            p.parse()
+                .map_err(|e| McpError::invalid_params(format!("{e}"), None))
+        },
+    )
+}
+
 fn serialize_list<T: Serialize>(items: Vec<T>) -> Result<String, McpError> {
     let count = items.len();
     serde_json::to_string_pretty(&serde_json::json!({ "count": count, "data": items }))
@@ -188,6 +598,7 @@ pub struct ListSittingsParams {
     /// On or after 2013-03-28 with `start_date` absent or also on/after the cutoff → current only.
     pub end_date: Option<NaiveDate>,
     /// Filter by house: "senate" or "national_assembly".
+    /// Falls back to the server's default house, if configured.
     pub house: Option<House>,
     /// Page number for current-only queries (default: 1). Ignored for cross-source merged queries.
     pub page: Option<u32>,
@@ -204,12 +615,21 @@ pub struct ListSittingsParams {
 pub struct GetSittingParams {
     /// Full URL or slug of the sitting. Archive URLs contain info.mzalendo.com; current URLs contain mzalendo.com/democracy-tools.
     pub url_or_slug: String,
+    /// Cut each contribution's text to this many characters (default: no limit).
+    pub max_chars: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SittingTopicsParams {
+    /// Full URL or slug of the sitting, as for `get_sitting`.
+    pub url_or_slug: String,
 }
 
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ListMembersParams {
     /// House to list: "national_assembly" or "senate".
-    pub house: House,
+    /// Required unless the server is configured with a default house.
+    pub house: Option<House>,
     /// Parliament session, e.g. "13th-parliament", "12th-parliament", "11th-parliament".
     pub parliament: String,
     /// Page number (default: 1). Ignored when `all` is true.
@@ -237,13 +657,184 @@ pub struct GetMemberProfileParams {
     pub all_bills: bool,
 }
 
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct MemberContributionsParams {
+    /// Full URL or slug of the member's profile page.
+    pub url_or_slug: String,
+    /// Activity page number (default: 1). Ignored when `all` is true.
+    pub page: Option<u32>,
+    /// Fetch all pages of activity (may be slow).
+    #[serde(default)]
+    pub all: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct MemberActivityTimelineParams {
+    /// Full URL or slug of the member's profile page.
+    pub url_or_slug: String,
+    /// Activity pages to fetch, newest first (default: 5, at most 30).
+    pub max_pages: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BillJourneyParams {
+    /// Bill name or number, e.g. "Finance Bill" or "Bill No. 30 of 2025".
+    pub bill: String,
+    /// Start of date range (YYYY-MM-DD).
+    pub start_date: Option<NaiveDate>,
+    /// End of date range (YYYY-MM-DD).
+    pub end_date: Option<NaiveDate>,
+    /// Filter by house: "senate" or "national_assembly".
+    /// Falls back to the server's default house, if configured.
+    pub house: Option<House>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SearchParams {
+    /// Free-text query, e.g. a member name, constituency, bill name or number, or topic.
+    pub query: String,
+    /// Restrict results to one entity type: "member", "bill" or "topic".
+    pub entity_type: Option<EntityType>,
+    /// Parliament session whose members are searched. Defaults to "13th-parliament".
+    pub parliament: Option<String>,
+    /// Maximum results to return (default: 10).
+    pub limit: Option<usize>,
+}
+
 #[tool_handler]
 impl ServerHandler for McpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .build(),
             instructions: Some(include_str!("./instructions.md").to_string()),
             ..Default::default()
         }
     }
+
+    // XXX: only the most recent page of sittings and the profiles fetched in this session are
+    // listed; any other sitting or member is reachable through the resource templates.
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let listings = self
+            .scraper
+            .list_sittings(SittingListOptions {
+                house: self.default_house,
+                page: 1,
+                ..Default::default()
+            })
+            .await
+            .inspect_err(|e| log::error!("Failed to fetch sittings for resources: {e}"))
+            .map_err(|e| {
+                McpError::internal_error(format!("Failed to fetch sittings: {e}"), None)
+            })?;
+
+        let mut resources: Vec<_> = listings
+            .iter()
+            .filter_map(|listing| {
+                let uri = resources::sitting_uri(&listing.url)?;
+                Some(
+                    RawResource {
+                        title: Some(listing.title.clone()),
+                        description: Some(format!("{} sitting on {}", listing.house, listing.date)),
+                        mime_type: Some("application/json".to_string()),
+                        ..RawResource::new(uri, listing.title.clone())
+                    }
+                    .no_annotation(),
+                )
+            })
+            .collect();
+
+        if let Ok(profiles) = self.profiles.read() {
+            resources.extend(profiles.iter().filter_map(|cached| {
+                let uri = resources::member_uri(&cached.url)?;
+                Some(
+                    RawResource {
+                        title: Some(cached.profile.name.clone()),
+                        description: Some("Member profile".to_string()),
+                        mime_type: Some("application/json".to_string()),
+                        ..RawResource::new(uri, cached.profile.name.clone())
+                    }
+                    .no_annotation(),
+                )
+            }));
+        }
+
+        Ok(ListResourcesResult::with_all_items(resources))
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        let template = |uri_template: &str, name: &str, description: &str| {
+            RawResourceTemplate {
+                uri_template: uri_template.to_string(),
+                name: name.to_string(),
+                title: None,
+                description: Some(description.to_string()),
+                mime_type: Some("application/json".to_string()),
+                icons: None,
+            }
+            .no_annotation()
+        };
+
+        Ok(ListResourceTemplatesResult::with_all_items(vec![
+            template(
+                resources::SITTING_URI_TEMPLATE,
+                "sitting",
+                "Full transcript of a current-source sitting, as returned by `get_sitting`. `slug` is the last path segment of the sitting URL.",
+            ),
+            template(
+                resources::MEMBER_URI_TEMPLATE,
+                "member",
+                "A member's profile, as returned by `get_member_profile`, e.g. national-assembly/13th-parliament/boss-gladys-jepkosgei.",
+            ),
+        ]))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let json = match ResourceUri::parse(&request.uri) {
+            Some(ResourceUri::Sitting(url_or_slug)) => {
+                self.get_sitting(Parameters(GetSittingParams {
+                    url_or_slug,
+                    max_chars: None,
+                }))
+                .await?
+            }
+            Some(ResourceUri::Member(url_or_slug)) => {
+                self.get_member_profile(Parameters(GetMemberProfileParams {
+                    url_or_slug,
+                    all_activity: false,
+                    all_bills: false,
+                }))
+                .await?
+            }
+            None => {
+                return Err(McpError::resource_not_found(
+                    format!("Unknown resource: {}", request.uri),
+                    None,
+                ));
+            }
+        };
+
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: request.uri,
+                mime_type: Some("application/json".to_string()),
+                text: json,
+                meta: None,
+            }],
+        })
+    }
 }
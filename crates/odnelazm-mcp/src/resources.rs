@@ -0,0 +1,114 @@
+pub const SITTING_URI_TEMPLATE: &str = "odnelazm://current/sitting/{slug}";
+pub const MEMBER_URI_TEMPLATE: &str = "odnelazm://current/member/{house}/{parliament}/{slug}";
+
+const SITTING_URI_PREFIX: &str = "odnelazm://current/sitting/";
+const MEMBER_URI_PREFIX: &str = "odnelazm://current/member/";
+const SITTING_PATH: &str = "/democracy-tools/hansard/";
+const MEMBER_PATH: &str = "/mps-performance/";
+
+/// A resource URI resolved to the mzalendo.com path it stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceUri {
+    Sitting(String),
+    Member(String),
+}
+
+impl ResourceUri {
+    pub fn parse(uri: &str) -> Option<Self> {
+        if let Some(slug) = uri.strip_prefix(SITTING_URI_PREFIX) {
+            let slug = slug.trim_matches('/');
+            if slug.is_empty() || slug.contains('/') {
+                return None;
+            }
+            return Some(Self::Sitting(format!("{SITTING_PATH}{slug}/")));
+        }
+
+        let path = uri.strip_prefix(MEMBER_URI_PREFIX)?.trim_matches('/');
+        let segments: Vec<&str> = path.split('/').collect();
+        if segments.len() != 3 || segments.iter().any(|s| s.is_empty()) {
+            return None;
+        }
+        Some(Self::Member(format!("{MEMBER_PATH}{path}/")))
+    }
+}
+
+/// Resource URI for a current-source sitting URL; `None` for archive sittings.
+pub fn sitting_uri(url: &str) -> Option<String> {
+    let slug = site_path(url).strip_prefix(SITTING_PATH)?.trim_matches('/');
+    (!slug.is_empty() && !slug.contains('/')).then(|| format!("{SITTING_URI_PREFIX}{slug}"))
+}
+
+/// Resource URI for a member profile URL.
+pub fn member_uri(url: &str) -> Option<String> {
+    let path = site_path(url).strip_prefix(MEMBER_PATH)?.trim_matches('/');
+    (path.split('/').filter(|s| !s.is_empty()).count() == 3)
+        .then(|| format!("{MEMBER_URI_PREFIX}{path}"))
+}
+
+fn site_path(url: &str) -> &str {
+    match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("/", |i| &rest[i..]),
+        None => url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sitting_uri_round_trip() {
+        let url = "https://mzalendo.com/democracy-tools/hansard/tuesday-28th-april-2026-afternoon-sitting-2501/";
+
+        let uri = sitting_uri(url).unwrap();
+        assert_eq!(
+            uri,
+            "odnelazm://current/sitting/tuesday-28th-april-2026-afternoon-sitting-2501"
+        );
+        assert_eq!(
+            ResourceUri::parse(&uri),
+            Some(ResourceUri::Sitting(
+                "/democracy-tools/hansard/tuesday-28th-april-2026-afternoon-sitting-2501/"
+                    .to_string()
+            ))
+        );
+        assert_eq!(
+            sitting_uri("/democracy-tools/hansard/tuesday-28th-april-2026-afternoon-sitting-2501/"),
+            Some(uri)
+        );
+    }
+
+    #[test]
+    fn test_member_uri_round_trip() {
+        let url = "https://mzalendo.com/mps-performance/national-assembly/13th-parliament/boss-gladys-jepkosgei/";
+
+        let uri = member_uri(url).unwrap();
+        assert_eq!(
+            uri,
+            "odnelazm://current/member/national-assembly/13th-parliament/boss-gladys-jepkosgei"
+        );
+        assert_eq!(
+            ResourceUri::parse(&uri),
+            Some(ResourceUri::Member(
+                "/mps-performance/national-assembly/13th-parliament/boss-gladys-jepkosgei/"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_rejects_foreign_uris() {
+        assert_eq!(
+            sitting_uri(
+                "https://info.mzalendo.com/hansard/sitting/national_assembly/2012-10-11-14-30-00"
+            ),
+            None
+        );
+        assert_eq!(ResourceUri::parse("odnelazm://current/sitting/"), None);
+        assert_eq!(
+            ResourceUri::parse("odnelazm://current/member/boss-gladys-jepkosgei"),
+            None
+        );
+        assert_eq!(ResourceUri::parse("file:///etc/passwd"), None);
+    }
+}
@@ -0,0 +1,238 @@
+use odnelazm::{Member, MemberProfile, match_score, rank_members};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityType {
+    Member,
+    Bill,
+    Topic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SearchResult {
+    pub entity_type: EntityType,
+    pub title: String,
+    pub detail: Option<String>,
+    /// Canonical URL: the member profile for members and bills, the contribution for topics.
+    pub url: String,
+    pub score: u32,
+}
+
+/// A member profile together with the URL it was fetched from.
+#[derive(Debug, Clone)]
+pub struct CachedProfile {
+    pub url: String,
+    pub profile: MemberProfile,
+}
+
+/// Rank members by name/constituency/county, and bills and topics from `profiles`, against `query`.
+/// Results are ordered by descending score, then title.
+pub fn search(
+    query: &str,
+    entity_type: Option<EntityType>,
+    members: &[Member],
+    profiles: &[CachedProfile],
+) -> Vec<SearchResult> {
+    let wants = |ty: EntityType| entity_type.is_none_or(|t| t == ty);
+    let mut results = Vec::new();
+
+    if wants(EntityType::Member) {
+        for matched in rank_members(query, members.iter().cloned(), usize::MAX) {
+            let seat = matched
+                .constituency
+                .as_deref()
+                .or(matched.county.as_deref());
+            results.push(SearchResult {
+                entity_type: EntityType::Member,
+                detail: Some(match seat {
+                    Some(seat) => format!("{}, {}", matched.house, seat),
+                    None => matched.house.to_string(),
+                }),
+                title: matched.name,
+                url: matched.url,
+                score: percent(matched.score),
+            });
+        }
+    }
+
+    for cached in profiles {
+        let profile = &cached.profile;
+
+        if wants(EntityType::Bill) {
+            for bill in &profile.bills {
+                if let Some(score) = match_score(query, &bill.name) {
+                    results.push(SearchResult {
+                        entity_type: EntityType::Bill,
+                        title: bill.name.clone(),
+                        detail: Some(format!(
+                            "{} ({}), sponsored by {}",
+                            bill.status, bill.year, profile.name
+                        )),
+                        url: cached.url.clone(),
+                        score: percent(score),
+                    });
+                }
+            }
+        }
+
+        if wants(EntityType::Topic) {
+            for activity in &profile.activity {
+                if let Some(score) = match_score(query, &activity.topic) {
+                    results.push(SearchResult {
+                        entity_type: EntityType::Topic,
+                        title: activity.topic.clone(),
+                        detail: Some(format!("{}, {}", activity.date, profile.name)),
+                        url: activity.url.clone(),
+                        score: percent(score),
+                    });
+                }
+            }
+        }
+    }
+
+    results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.title.cmp(&b.title)));
+    results.dedup_by(|a, b| a.entity_type == b.entity_type && a.url == b.url && a.title == b.title);
+    results
+}
+
+fn percent(score: f64) -> u32 {
+    (score * 100.0).round() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use odnelazm::{Bill, House, ParliamentaryActivity};
+
+    fn member(name: &str, constituency: &str) -> Member {
+        Member {
+            name: name.to_string(),
+            url: format!(
+                "/mps-performance/national-assembly/13th-parliament/{}/",
+                name.to_lowercase().replace(' ', "-")
+            ),
+            house: House::NationalAssembly,
+            role: None,
+            photo_url: None,
+            constituency: Some(constituency.to_string()),
+            county: None,
+        }
+    }
+
+    fn profile() -> CachedProfile {
+        CachedProfile {
+            url: "/mps-performance/national-assembly/13th-parliament/vincent-musau/".to_string(),
+            profile: MemberProfile {
+                name: "Vincent Musau".to_string(),
+                slug: "vincent-musau".to_string(),
+                house: Some(House::NationalAssembly),
+                parliament: Some("13th-parliament".to_string()),
+                photo_url: None,
+                biography: None,
+                education: None,
+                first_elected: None,
+                position_type: None,
+                positions: vec![],
+                assumed_office: None,
+                left_office: None,
+                party: Some("UDA".to_string()),
+                email: None,
+                telephone: None,
+                committees: vec![],
+                speeches_last_year: None,
+                speeches_total: None,
+                bills: vec![Bill {
+                    name: "The Sugar Bill (National Assembly Bill No. 34 of 2024)".to_string(),
+                    year: "2024".to_string(),
+                    status: "Passed".to_string(),
+                }],
+                bills_total: Some(1),
+                bills_pages: 1,
+                voting_patterns: vec![],
+                activity: vec![ParliamentaryActivity {
+                    date: "12 Feb 2026".to_string(),
+                    date_parsed: NaiveDate::from_ymd_opt(2026, 2, 12),
+                    topic: "Drought mitigation in Machakos County".to_string(),
+                    contribution_type: "Speech".to_string(),
+                    section_title: "STATEMENTS".to_string(),
+                    sitting_url: "/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/".to_string(),
+                    text_preview: "Hon. Speaker...".to_string(),
+                    url: "/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/#chunk-1".to_string(),
+                }],
+                activity_pages: 1,
+            },
+        }
+    }
+
+    #[test]
+    fn test_search_member_by_constituency() {
+        let members = [
+            member("Vincent Musau", "Mwala"),
+            member("Gladys Wanga", "Homa Bay County"),
+        ];
+
+        let results = search("Mwala", None, &members, &[]);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].entity_type, EntityType::Member);
+        assert_eq!(results[0].title, "Vincent Musau");
+        assert_eq!(results[0].score, 100);
+        assert_eq!(
+            results[0].url,
+            "/mps-performance/national-assembly/13th-parliament/vincent-musau/"
+        );
+    }
+
+    #[test]
+    fn test_search_tolerates_misspelling() {
+        let members = [
+            member("Vincent Musau", "Mwala"),
+            member("Gladys Wanga", "Homa Bay County"),
+        ];
+
+        let results = search("gladis wanga", Some(EntityType::Member), &members, &[]);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "Gladys Wanga");
+        assert!(results[0].score < 100);
+    }
+
+    #[test]
+    fn test_search_bills_and_topics_from_profiles() {
+        let profiles = [profile()];
+
+        let bills = search("bill no. 34", Some(EntityType::Bill), &[], &profiles);
+        assert_eq!(bills.len(), 1);
+        assert_eq!(bills[0].url, profiles[0].url);
+        assert!(
+            bills[0]
+                .detail
+                .as_deref()
+                .unwrap()
+                .contains("Vincent Musau")
+        );
+
+        let topics = search("drought machakos", Some(EntityType::Topic), &[], &profiles);
+        assert_eq!(topics.len(), 1);
+        assert!(topics[0].url.ends_with("#chunk-1"));
+
+        assert!(search("sugar", Some(EntityType::Topic), &[], &profiles).is_empty());
+    }
+
+    #[test]
+    fn test_search_ranks_exact_matches_first() {
+        let members = [
+            member("John Mbadi", "Suba South"),
+            member("John Mbadi Ng'ong'o", "Nominated"),
+        ];
+
+        let results = search("John Mbadi", None, &members, &[]);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "John Mbadi");
+        assert!(results[0].score > results[1].score);
+    }
+}
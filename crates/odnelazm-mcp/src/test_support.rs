@@ -0,0 +1,43 @@
+use chrono::NaiveDate;
+use odnelazm::{Contribution, DataSource, HansardSection, HansardSitting, House};
+
+pub fn contribution(speaker: &str, content: &str) -> Contribution {
+    Contribution {
+        speaker_name: speaker.to_string(),
+        speaker_raw: speaker.to_string(),
+        speaker_role: None,
+        speaker_url: None,
+        speaker_party: None,
+        speaker_details: None,
+        content: content.to_string(),
+        procedural_notes: vec![],
+        contribution_kind: None,
+        source_page: None,
+    }
+}
+
+pub fn sitting(date: NaiveDate, sections: Vec<HansardSection>) -> HansardSitting {
+    HansardSitting {
+        house: House::NationalAssembly,
+        date,
+        url: format!("/democracy-tools/hansard/{date}-afternoon-sitting/"),
+        session_type: "Afternoon Sitting".to_string(),
+        sections,
+        source: DataSource::Current,
+        day_of_week: None,
+        start_time: None,
+        end_time: None,
+        parliament_number: None,
+        session_number: None,
+        speaker_in_chair: None,
+        opening: vec![],
+        quorum_notes: vec![],
+        summary: None,
+        sentiment: None,
+        sentiment_label: None,
+        pdf_url: None,
+        divisions: vec![],
+        questions_status: vec![],
+        starts_at: None,
+    }
+}
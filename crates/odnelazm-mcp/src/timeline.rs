@@ -0,0 +1,152 @@
+use std::collections::{BTreeMap, HashMap};
+
+use odnelazm::ParliamentaryActivity;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TopicCount {
+    pub topic: String,
+    pub contributions: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MonthActivity {
+    /// Calendar month, e.g. "2025-11".
+    pub month: String,
+    pub contributions: usize,
+    /// Most contributions first; ties in alphabetical order.
+    pub topics: Vec<TopicCount>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActivityTimeline {
+    /// Oldest month first.
+    pub months: Vec<MonthActivity>,
+    /// Contributions whose date could not be read, left out of `months`.
+    pub undated: usize,
+    pub pages_fetched: u32,
+    pub total_pages: u32,
+    /// Whether older pages were left unfetched, so the earliest months may be missing.
+    pub truncated: bool,
+}
+
+/// Bucket a member's activity by calendar month, counting contributions per topic. Each
+/// activity item is one contribution; items without a topic count towards the month only.
+pub fn activity_timeline(
+    activity: &[ParliamentaryActivity],
+    pages_fetched: u32,
+    total_pages: u32,
+) -> ActivityTimeline {
+    let mut months: BTreeMap<String, HashMap<&str, usize>> = BTreeMap::new();
+    let mut totals: HashMap<String, usize> = HashMap::new();
+    let mut undated = 0;
+
+    for item in activity {
+        let Some(date) = item.parsed_date() else {
+            undated += 1;
+            continue;
+        };
+        let month = date.format("%Y-%m").to_string();
+        *totals.entry(month.clone()).or_default() += 1;
+        let topics = months.entry(month).or_default();
+        let topic = item.topic.trim();
+        if !topic.is_empty() {
+            *topics.entry(topic).or_default() += 1;
+        }
+    }
+
+    let months = months
+        .into_iter()
+        .map(|(month, topics)| {
+            let mut topics: Vec<TopicCount> = topics
+                .into_iter()
+                .map(|(topic, contributions)| TopicCount {
+                    topic: topic.to_string(),
+                    contributions,
+                })
+                .collect();
+            topics.sort_by(|a, b| {
+                b.contributions
+                    .cmp(&a.contributions)
+                    .then_with(|| a.topic.cmp(&b.topic))
+            });
+            MonthActivity {
+                contributions: totals[&month],
+                month,
+                topics,
+            }
+        })
+        .collect();
+
+    ActivityTimeline {
+        months,
+        undated,
+        pages_fetched,
+        total_pages,
+        truncated: pages_fetched < total_pages,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(date: &str, topic: &str) -> ParliamentaryActivity {
+        ParliamentaryActivity {
+            date: date.to_string(),
+            date_parsed: None,
+            topic: topic.to_string(),
+            contribution_type: "Debate".to_string(),
+            section_title: "BILLS".to_string(),
+            sitting_url: "/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/".to_string(),
+            text_preview: "Hon. Speaker...".to_string(),
+            url: "/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/#chunk-1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_activity_timeline_buckets_by_month() {
+        let items = [
+            activity("19th November 2025", "Health"),
+            activity("19th November 2025", "Health"),
+            activity("13th November 2025", "Education"),
+            activity("1st October 2025", "Health"),
+            activity("30th September 2025", ""),
+            activity("Yesterday", "Health"),
+        ];
+
+        let timeline = activity_timeline(&items, 2, 11);
+
+        let months: Vec<&str> = timeline.months.iter().map(|m| m.month.as_str()).collect();
+        assert_eq!(months, ["2025-09", "2025-10", "2025-11"]);
+
+        let november = &timeline.months[2];
+        assert_eq!(november.contributions, 3);
+        assert_eq!(
+            november.topics,
+            [
+                TopicCount {
+                    topic: "Health".to_string(),
+                    contributions: 2,
+                },
+                TopicCount {
+                    topic: "Education".to_string(),
+                    contributions: 1,
+                },
+            ]
+        );
+        assert_eq!(timeline.months[0].contributions, 1);
+        assert!(timeline.months[0].topics.is_empty());
+
+        assert_eq!(timeline.undated, 1);
+        assert!(timeline.truncated);
+    }
+
+    #[test]
+    fn test_activity_timeline_empty() {
+        let timeline = activity_timeline(&[], 1, 1);
+        assert!(timeline.months.is_empty());
+        assert_eq!(timeline.undated, 0);
+        assert!(!timeline.truncated);
+    }
+}
@@ -0,0 +1,169 @@
+use chrono::NaiveDate;
+use odnelazm::{HansardSitting, House, Sentiment, distinct_speaker_names};
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SittingTopics {
+    pub date: NaiveDate,
+    pub house: House,
+    pub url: String,
+    pub summary: Option<String>,
+    pub sentiment: Option<String>,
+    pub sentiment_label: Option<Sentiment>,
+    /// Contributions across the whole sitting.
+    pub contributions: usize,
+    /// Speakers across the whole sitting, with honorifics ignored.
+    pub distinct_speakers: usize,
+    pub sections: Vec<SectionTopics>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SectionTopics {
+    pub section_type: String,
+    /// Contributions in the section, including those under its subsections.
+    pub contributions: usize,
+    /// Distinct speakers across the section, with honorifics ignored, in order of first
+    /// appearance.
+    pub speakers: Vec<String>,
+    pub subsections: Vec<TopicSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TopicSummary {
+    pub title: String,
+    pub contributions: usize,
+    /// Distinct speakers, with honorifics ignored, in order of first appearance.
+    pub speakers: Vec<String>,
+}
+
+/// A table of contents for `sitting`: each section and subsection with how many contributions
+/// it holds and who spoke, without the contributions themselves.
+pub fn sitting_topics(sitting: &HansardSitting) -> SittingTopics {
+    let sections = sitting
+        .sections
+        .iter()
+        .map(|section| {
+            let all = section
+                .contributions
+                .iter()
+                .chain(section.subsections.iter().flat_map(|s| &s.contributions));
+            SectionTopics {
+                section_type: section.section_type.clone(),
+                contributions: section.contributions.len()
+                    + section
+                        .subsections
+                        .iter()
+                        .map(|s| s.contributions.len())
+                        .sum::<usize>(),
+                speakers: distinct_speaker_names(all),
+                subsections: section
+                    .subsections
+                    .iter()
+                    .map(|s| TopicSummary {
+                        title: s.title.clone(),
+                        contributions: s.contributions.len(),
+                        speakers: distinct_speaker_names(&s.contributions),
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    SittingTopics {
+        date: sitting.date,
+        house: sitting.house,
+        url: sitting.url.clone(),
+        summary: sitting.summary.clone(),
+        sentiment: sitting.sentiment.clone(),
+        sentiment_label: sitting.sentiment_label.clone(),
+        contributions: sitting.contribution_count(),
+        distinct_speakers: sitting.distinct_speakers(),
+        sections,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use odnelazm::{Contribution, HansardSection, HansardSubsection};
+
+    fn subsection(title: &str, speakers: &[&str]) -> HansardSubsection {
+        HansardSubsection {
+            title: title.to_string(),
+            contributions: speakers.iter().map(|s| contribution(s)).collect(),
+            referred_to_committee: None,
+            statement_kind: None,
+        }
+    }
+
+    fn contribution(speaker: &str) -> Contribution {
+        test_support::contribution(speaker, "Hon. Speaker, I rise to support.")
+    }
+
+    #[test]
+    fn test_sitting_topics() {
+        let sitting = HansardSitting {
+            summary: Some("The House debated the Finance Bill.".to_string()),
+            sentiment: Some("Mixed".to_string()),
+            sentiment_label: Some(Sentiment::Mixed),
+            ..test_support::sitting(
+                NaiveDate::from_ymd_opt(2026, 2, 12).unwrap(),
+                vec![
+                    HansardSection {
+                        section_type: "PRAYERS".to_string(),
+                        subsections: vec![],
+                        contributions: vec![contribution("Hon. Speaker")],
+                    },
+                    HansardSection {
+                        section_type: "BILLS".to_string(),
+                        subsections: vec![
+                            subsection(
+                                "The Finance Bill (National Assembly Bill No. 30 of 2025)",
+                                &[
+                                    "Hon. Kuria Kimani",
+                                    "Hon. Ndindi Nyoro",
+                                    "Hon. Kuria Kimani",
+                                ],
+                            ),
+                            subsection("The Health Bill", &["Ndindi Nyoro", ""]),
+                        ],
+                        contributions: vec![],
+                    },
+                ],
+            )
+        };
+
+        let topics = sitting_topics(&sitting);
+        assert_eq!(
+            topics.summary.as_deref(),
+            Some("The House debated the Finance Bill.")
+        );
+        assert_eq!(topics.sentiment_label, Some(Sentiment::Mixed));
+        assert_eq!(topics.contributions, 6);
+        assert_eq!(topics.distinct_speakers, 3);
+        assert_eq!(topics.sections.len(), 2);
+
+        let prayers = &topics.sections[0];
+        assert_eq!(prayers.contributions, 1);
+        assert_eq!(prayers.speakers, ["Hon. Speaker"]);
+        assert!(prayers.subsections.is_empty());
+
+        let bills = &topics.sections[1];
+        assert_eq!(bills.contributions, 5);
+        assert_eq!(bills.speakers, ["Hon. Kuria Kimani", "Hon. Ndindi Nyoro"]);
+        assert_eq!(
+            bills.subsections[0],
+            TopicSummary {
+                title: "The Finance Bill (National Assembly Bill No. 30 of 2025)".to_string(),
+                contributions: 3,
+                speakers: vec![
+                    "Hon. Kuria Kimani".to_string(),
+                    "Hon. Ndindi Nyoro".to_string(),
+                ],
+            }
+        );
+        assert_eq!(bills.subsections[1].contributions, 2);
+        assert_eq!(bills.subsections[1].speakers, ["Ndindi Nyoro"]);
+    }
+}
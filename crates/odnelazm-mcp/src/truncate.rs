@@ -0,0 +1,133 @@
+use serde_json::{Value, json};
+
+/// Cut every contribution's `content` in a serialized sitting down to `max_chars` characters,
+/// marking each shortened one with `"truncated": true`. When anything was cut, a top-level
+/// `note` says how to get the full text. Returns the number of contributions truncated.
+pub fn truncate_sitting(sitting: &mut Value, max_chars: usize) -> usize {
+    let mut truncated = 0;
+    let Some(sections) = sitting.get_mut("sections").and_then(Value::as_array_mut) else {
+        return truncated;
+    };
+
+    for section in sections {
+        truncated += truncate_contributions(section, max_chars);
+        if let Some(subsections) = section.get_mut("subsections").and_then(Value::as_array_mut) {
+            for subsection in subsections {
+                truncated += truncate_contributions(subsection, max_chars);
+            }
+        }
+    }
+
+    if truncated > 0
+        && let Some(sitting) = sitting.as_object_mut()
+    {
+        sitting.insert(
+            "note".to_string(),
+            json!(format!(
+                "{truncated} contribution(s) were cut to {max_chars} characters and marked \
+                 `truncated: true`. Call get_sitting again without max_chars for the full text."
+            )),
+        );
+    }
+    truncated
+}
+
+fn truncate_contributions(parent: &mut Value, max_chars: usize) -> usize {
+    let Some(contributions) = parent
+        .get_mut("contributions")
+        .and_then(Value::as_array_mut)
+    else {
+        return 0;
+    };
+
+    let mut truncated = 0;
+    for contribution in contributions {
+        let Some(content) = contribution.get("content").and_then(Value::as_str) else {
+            continue;
+        };
+        if let Some(short) = truncate_text(content, max_chars) {
+            contribution["content"] = Value::String(short);
+            contribution["truncated"] = Value::Bool(true);
+            truncated += 1;
+        }
+    }
+    truncated
+}
+
+/// `text` cut to `max_chars` characters with an ellipsis, or `None` if it already fits.
+fn truncate_text(text: &str, max_chars: usize) -> Option<String> {
+    let (cut, _) = text.char_indices().nth(max_chars)?;
+    let mut short = text[..cut].trim_end().to_string();
+    short.push('…');
+    Some(short)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_text() {
+        assert_eq!(truncate_text("Hon. Speaker", 20), None);
+        assert_eq!(truncate_text("Hon. Speaker", 12), None);
+        assert_eq!(
+            truncate_text("Hon. Speaker, I rise", 13),
+            Some("Hon. Speaker,…".to_string())
+        );
+        assert_eq!(
+            truncate_text("Mheshimiwa Spika", 11),
+            Some("Mheshimiwa…".to_string())
+        );
+        assert_eq!(
+            truncate_text("Naibu – Spika", 7),
+            Some("Naibu –…".to_string())
+        );
+    }
+
+    #[test]
+    fn test_truncate_sitting() {
+        let mut sitting = json!({
+            "url": "/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/",
+            "sections": [
+                {
+                    "section_type": "PRAYERS",
+                    "subsections": [],
+                    "contributions": [{ "speaker_name": "Hon. Speaker", "content": "Prayers" }]
+                },
+                {
+                    "section_type": "BILLS",
+                    "subsections": [{
+                        "title": "The Finance Bill",
+                        "contributions": [
+                            { "speaker_name": "Hon. Kuria Kimani", "content": "Hon. Speaker, I beg to move." },
+                            { "speaker_name": "Hon. Ndindi Nyoro", "content": "I second." }
+                        ]
+                    }],
+                    "contributions": []
+                }
+            ]
+        });
+
+        assert_eq!(truncate_sitting(&mut sitting, 12), 1);
+        let finance = &sitting["sections"][1]["subsections"][0]["contributions"];
+        assert_eq!(finance[0]["content"], "Hon. Speaker…");
+        assert_eq!(finance[0]["truncated"], true);
+        assert_eq!(finance[1]["content"], "I second.");
+        assert!(finance[1].get("truncated").is_none());
+        assert!(
+            sitting["sections"][0]["contributions"][0]
+                .get("truncated")
+                .is_none()
+        );
+        assert!(
+            sitting["note"]
+                .as_str()
+                .unwrap()
+                .contains("without max_chars")
+        );
+
+        let mut untouched = json!({ "sections": [] });
+        assert_eq!(truncate_sitting(&mut untouched, 12), 0);
+        assert!(untouched.get("note").is_none());
+    }
+}
@@ -4,6 +4,8 @@ use std::sync::LazyLock;
 use super::types::{
     Contribution, HansardListing, HansardSection, HansardSitting, House, PersonDetails,
 };
+use crate::types::ParseReport;
+use crate::util::clean_procedural_notes;
 
 use chrono::{NaiveDate, NaiveTime};
 use regex::Regex;
@@ -38,15 +40,77 @@ static RE_NAME_PREFIX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"(?i)^(Hon\.|Sen\.)\s(Dr\.\s)?").expect("invalid regex: name prefix")
 });
 static RE_ROLE_PREFIX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?i)^(The\s)?(Ayes|Noes|Teller|Temporary Speaker|Speaker|Chairperson|Majority Leader|Minority Leader|Majority Whip|Minority Whip)")
+    Regex::new(r"(?i)^(The\s)?(Ayes|Noes|Teller|Temporary Speaker|Deputy Speaker|Speaker|Chairperson|Majority Leader|Minority Leader|Majority Whip|Minority Whip)")
         .expect("invalid regex: role prefix")
 });
 static RE_CONSTITUENCY: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^[^,]+,\s*.+").expect("invalid regex: constituency"));
 static RE_NAME_IN_PARENS: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^(.+?)\s*\((.+?)\)$").expect("invalid regex: name in parens"));
+static RE_PARTY_SUFFIX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(.+?),\s*(ODM|UDA|ANC|KANU|Wiper|Jubilee(?:\sParty)?|JP|FORD[-\s]?K(?:enya)?|DAP[-\s]?K|UDM|PAA|MCCP|NARC(?:[-\s]?K(?:enya)?)?|TNA|URP|KUP|CCM|MDG|PDR|Independent|IND)$")
+        .expect("invalid regex: party suffix")
+});
+static RE_PARTY_ABBREV: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[A-Z]{2,5}(?:-[A-Z]{1,5})?$").expect("invalid regex: party abbreviation")
+});
+static RE_PLACE_SUFFIX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\s(?:county|constituency|city)$").expect("invalid regex: place suffix")
+});
 static RE_END_TIME: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\bto\s+(\d{1,2}):(\d{2})\b").expect("invalid regex: end time"));
+static RE_PAGE_MARKER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bpage\s+(\d+)").expect("invalid regex: page marker"));
+
+const COUNTIES: &[&str] = &[
+    "Baringo",
+    "Bomet",
+    "Bungoma",
+    "Busia",
+    "Elgeyo-Marakwet",
+    "Embu",
+    "Garissa",
+    "Homa Bay",
+    "Isiolo",
+    "Kajiado",
+    "Kakamega",
+    "Kericho",
+    "Kiambu",
+    "Kilifi",
+    "Kirinyaga",
+    "Kisii",
+    "Kisumu",
+    "Kitui",
+    "Kwale",
+    "Laikipia",
+    "Lamu",
+    "Machakos",
+    "Makueni",
+    "Mandera",
+    "Marsabit",
+    "Meru",
+    "Migori",
+    "Mombasa",
+    "Murang'a",
+    "Nairobi",
+    "Nakuru",
+    "Nandi",
+    "Narok",
+    "Nyamira",
+    "Nyandarua",
+    "Nyeri",
+    "Samburu",
+    "Siaya",
+    "Taita-Taveta",
+    "Tana River",
+    "Tharaka-Nithi",
+    "Trans Nzoia",
+    "Turkana",
+    "Uasin Gishu",
+    "Vihiga",
+    "Wajir",
+    "West Pokot",
+];
 
 fn elem_text(element: ElementRef) -> String {
     element.text().collect::<String>()
@@ -62,10 +126,16 @@ fn extract_parenthesized(text: &str) -> Option<String> {
     (end > start).then(|| text[start + 1..end].trim().to_string())
 }
 
-pub fn parse_hansard_list(html: &str) -> Result<Vec<HansardListing>, ParseError> {
+/// Listings on the archive's hansard index, with a report of the entries left out because
+/// their text does not name a house and date. Relative links resolve against `base_url`.
+pub fn parse_hansard_list(
+    html: &str,
+    base_url: &str,
+) -> Result<(Vec<HansardListing>, ParseReport), ParseError> {
     let document = Html::parse_document(html);
     let list_selector = Selector::parse("ul.listing li a")?;
     let mut listings = Vec::new();
+    let mut report = ParseReport::default();
 
     for element in document.select(&list_selector) {
         let url = element
@@ -76,13 +146,17 @@ pub fn parse_hansard_list(html: &str) -> Result<Vec<HansardListing>, ParseError>
 
         let display_text = elem_text(element);
 
-        match parse_hansard_entry(&url, &display_text) {
+        match parse_hansard_entry(&url, &display_text, base_url) {
             Ok(listing) => listings.push(listing),
-            Err(e) => log::warn!("Skipping entry '{}': {}", display_text, e),
+            Err(e) => report.skip(format!(
+                "listing '{}': {e}",
+                normalize_whitespace(&display_text)
+            )),
         }
     }
 
-    Ok(listings)
+    report.parsed = listings.len();
+    Ok((listings, report))
 }
 
 pub fn parse_hansard_sitting(html: &str, url: &str) -> Result<HansardSitting, ParseError> {
@@ -134,6 +208,7 @@ pub fn parse_hansard_sitting(html: &str, url: &str) -> Result<HansardSitting, Pa
         })
         .unwrap_or_else(|| "[Speaker information not found]".to_string());
 
+    let (opening, quorum_notes) = parse_scene_notes(&document)?;
     let sections = parse_sections(&document)?;
 
     Ok(HansardSitting {
@@ -145,10 +220,39 @@ pub fn parse_hansard_sitting(html: &str, url: &str) -> Result<HansardSitting, Pa
         session_number,
         session_type,
         speaker_in_chair,
+        opening,
+        quorum_notes,
         sections,
     })
 }
 
+/// The scene notes that open the sitting, before anyone speaks, and those mentioning the quorum.
+fn parse_scene_notes(document: &Html) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let selector = Selector::parse("li.speech, li.scene")?;
+    let mut opening = Vec::new();
+    let mut quorum_notes = Vec::new();
+    let mut spoken = false;
+
+    for element in document.select(&selector) {
+        if element.value().classes().any(|c| c == "speech") {
+            spoken = true;
+            continue;
+        }
+        let scene = normalize_whitespace(&elem_text(element));
+        if scene.is_empty() {
+            continue;
+        }
+        if scene.to_lowercase().contains("quorum") {
+            quorum_notes.push(scene.clone());
+        }
+        if !spoken {
+            opening.push(scene);
+        }
+    }
+
+    Ok((opening, quorum_notes))
+}
+
 pub fn parse_person_details(html: &str, url: &str) -> Result<PersonDetails, ParseError> {
     let document = Html::parse_document(html);
 
@@ -229,7 +333,11 @@ pub fn parse_person_details(html: &str, url: &str) -> Result<PersonDetails, Pars
     })
 }
 
-fn parse_hansard_entry(url: &str, display_text: &str) -> Result<HansardListing, ParseError> {
+fn parse_hansard_entry(
+    url: &str,
+    display_text: &str,
+    base_url: &str,
+) -> Result<HansardListing, ParseError> {
     let parts: Vec<&str> = url.split('/').filter(|s| !s.is_empty()).collect();
 
     if parts.len() < 4 {
@@ -245,7 +353,7 @@ fn parse_hansard_entry(url: &str, display_text: &str) -> Result<HansardListing,
     let full_url = if url.starts_with("http") {
         url.to_string()
     } else {
-        format!("{}{}", super::BASE_URL, url)
+        format!("{}{}", base_url, url)
     };
 
     Ok(HansardListing {
@@ -325,22 +433,37 @@ fn parse_end_time_from_display(display_text: &str) -> Result<Option<NaiveTime>,
 fn parse_sections(document: &Html) -> Result<Vec<HansardSection>, ParseError> {
     let mut sections: Vec<HansardSection> = Vec::new();
     let mut current: Option<HansardSection> = None;
+    // XXX: page markers come before the entries on that page, so each speech takes the last
+    // page seen
+    let mut page: Option<u32> = None;
 
-    let all_items_selector = Selector::parse("li.heading, li.speech, li.scene")?;
+    let all_items_selector =
+        Selector::parse("li.heading, li.speech, li.scene, li.page_number, li.page")?;
 
     for element in document.select(&all_items_selector) {
         let class = element.value().attr("class").unwrap_or_default();
 
-        if class.contains("heading") {
+        if class
+            .split_whitespace()
+            .any(|c| c == "page_number" || c == "page")
+        {
+            if let Some(number) = parse_page_marker(&elem_text(element)) {
+                page = Some(number);
+            }
+        } else if class.contains("heading") {
             if let Some(section) = current.take() {
                 sections.push(section);
             }
 
             let heading = normalize_whitespace(&elem_text(element));
 
-            if heading.contains("PARLIAMENT")
-                || heading.contains("SENATE")
-                || heading.contains("NATIONAL ASSEMBLY")
+            // XXX: only the masthead names the house on its own; bill titles such as "... BILL
+            // (NATIONAL ASSEMBLY BILLS NO. 51 OF 2020)" are real sections
+            if heading.starts_with("PARLIAMENT")
+                || matches!(
+                    heading.trim_start_matches("THE "),
+                    "SENATE" | "NATIONAL ASSEMBLY"
+                )
             {
                 continue;
             }
@@ -352,8 +475,9 @@ fn parse_sections(document: &Html) -> Result<Vec<HansardSection>, ParseError> {
             });
         } else if class.contains("speech") {
             if let Some(ref mut section) = current
-                && let Ok(contribution) = parse_contribution(element)
+                && let Ok(mut contribution) = parse_contribution(element)
             {
+                contribution.source_page = page;
                 section.contributions.push(contribution);
             }
         } else if class.contains("scene")
@@ -372,10 +496,20 @@ fn parse_sections(document: &Html) -> Result<Vec<HansardSection>, ParseError> {
     if let Some(section) = current {
         sections.push(section);
     }
+    for contribution in sections.iter_mut().flat_map(|s| &mut s.contributions) {
+        clean_procedural_notes(&mut contribution.procedural_notes);
+    }
 
     Ok(sections)
 }
 
+/// The page number in a marker such as "Page 3 of ..." or "[Page 3]".
+fn parse_page_marker(text: &str) -> Option<u32> {
+    RE_PAGE_MARKER
+        .captures(text)
+        .and_then(|caps| caps[1].parse().ok())
+}
+
 fn parse_contribution(element: ElementRef) -> Result<Contribution, ParseError> {
     let strong_selector = Selector::parse("strong")?;
     let a_selector = Selector::parse("a")?;
@@ -411,6 +545,7 @@ fn parse_contribution(element: ElementRef) -> Result<Contribution, ParseError> {
         .replace(&strong_text, "")
         .replace(&content_text, "");
 
+    let speaker_raw = normalize_whitespace(&format!("{strong_text}{header_text}"));
     let mut speaker_role = extract_parenthesized(&header_text);
 
     // XXX: Normalize speaker name/role inconsistencies from hansard authors.
@@ -419,9 +554,12 @@ fn parse_contribution(element: ElementRef) -> Result<Contribution, ParseError> {
     // We detect and normalize these cases by swapping when appropriate.
 
     if let Some(role) = &mut speaker_role {
-        // case 1: name is "Constituency, Party", role is the actual person name
-        let name_is_constituency =
-            RE_CONSTITUENCY.is_match(&speaker_name) && !RE_NAME_PREFIX.is_match(&speaker_name);
+        // case 1: name is "Constituency, Party", role is the actual person name. A name in
+        // "Surname, Given" form also has a comma, so everything after the first comma must read
+        // as a party or a place before we swap.
+        let name_is_constituency = RE_CONSTITUENCY.is_match(&speaker_name)
+            && !RE_NAME_PREFIX.is_match(&speaker_name)
+            && is_affiliation_tail(&speaker_name);
         let role_is_name = RE_NAME_PREFIX.is_match(role);
 
         if name_is_constituency && role_is_name {
@@ -454,6 +592,20 @@ fn parse_contribution(element: ElementRef) -> Result<Contribution, ParseError> {
             speaker_role = Some(outer);
         }
     }
+
+    // XXX: some hansards annotate the speaker's party inline ("Hon. Mbadi, ODM"). Split it off
+    // the name; when the name was swapped out above, the "Constituency, Party" form ends up
+    // in the role, which we leave intact and only read the party from.
+    let speaker_party = if let Some((name, party)) = split_party(&speaker_name) {
+        speaker_name = name;
+        Some(party)
+    } else {
+        speaker_role
+            .as_deref()
+            .and_then(split_party)
+            .map(|(_, party)| party)
+    };
+
     let content = element
         .select(&p_selector)
         .map(|p| normalize_whitespace(&elem_text(p)))
@@ -462,17 +614,44 @@ fn parse_contribution(element: ElementRef) -> Result<Contribution, ParseError> {
 
     Ok(Contribution {
         speaker_name,
+        speaker_raw,
         speaker_role,
         speaker_url,
+        speaker_party,
         speaker_details: None,
         content,
         procedural_notes: Vec::new(),
+        source_page: None,
     })
 }
 
+/// Whether every comma-separated part of `text` after the first is a party (a recognised name
+/// or an upper-case abbreviation such as "UDA") or a place (a county, or a name ending in
+/// "County", "Constituency" or "City"), as in "Mwala, UDA" but not "Cherarkey, Samson, UDA".
+fn is_affiliation_tail(text: &str) -> bool {
+    let mut parts = text.split(',').map(str::trim).skip(1).peekable();
+    parts.peek().is_some()
+        && parts.all(|part| {
+            RE_PARTY_ABBREV.is_match(part)
+                || split_party(&format!("_, {part}")).is_some()
+                || RE_PLACE_SUFFIX.is_match(part)
+                || COUNTIES
+                    .iter()
+                    .any(|county| county.eq_ignore_ascii_case(part))
+        })
+}
+
+/// Split a trailing party or coalition token off `text`, e.g. "Hon. Mbadi, ODM" into
+/// ("Hon. Mbadi", "ODM"). Returns `None` when the suffix is not a recognised party.
+fn split_party(text: &str) -> Option<(String, String)> {
+    let caps = RE_PARTY_SUFFIX.captures(text.trim())?;
+    Some((caps[1].trim().to_string(), caps[2].to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::archive::BASE_URL;
     use chrono::{NaiveDate, Timelike};
     use std::fs;
 
@@ -481,7 +660,8 @@ mod tests {
         let html = fs::read_to_string("fixtures/archive/root-page/Hansard __ Mzalendo")
             .expect("Failed to read sample HTML file");
 
-        let listings = parse_hansard_list(&html).expect("Failed to parse hansard list");
+        let (listings, _) =
+            parse_hansard_list(&html, BASE_URL).expect("Failed to parse hansard list");
 
         assert!(!listings.is_empty(), "Should parse at least one listing");
 
@@ -514,7 +694,7 @@ mod tests {
             </ul>
         "#;
 
-        let listings = parse_hansard_list(html).expect("Failed to parse");
+        let (listings, _) = parse_hansard_list(html, BASE_URL).expect("Failed to parse");
 
         assert_eq!(listings.len(), 1);
         let listing = &listings[0];
@@ -524,6 +704,28 @@ mod tests {
         assert!(listing.end_time.is_none());
     }
 
+    #[test]
+    fn test_parse_relative_listing_against_base_url() {
+        let html = r#"
+            <ul class="listing">
+                <li><a href="/hansard/sitting/senate/2025-07-17">Senate 2025-07-17</a></li>
+            </ul>
+        "#;
+
+        let (listings, _) = parse_hansard_list(html, BASE_URL).expect("Failed to parse");
+        assert_eq!(
+            listings[0].url,
+            "https://info.mzalendo.com/hansard/sitting/senate/2025-07-17"
+        );
+
+        let (listings, _) =
+            parse_hansard_list(html, "http://127.0.0.1:8080").expect("Failed to parse");
+        assert_eq!(
+            listings[0].url,
+            "http://127.0.0.1:8080/hansard/sitting/senate/2025-07-17"
+        );
+    }
+
     #[test]
     fn test_parse_national_assembly_with_time() {
         let html = r#"
@@ -532,7 +734,7 @@ mod tests {
             </ul>
         "#;
 
-        let listings = parse_hansard_list(html).expect("Failed to parse");
+        let (listings, _) = parse_hansard_list(html, BASE_URL).expect("Failed to parse");
 
         assert_eq!(listings.len(), 1);
         let listing = &listings[0];
@@ -558,7 +760,7 @@ mod tests {
             </ul>
         "#;
 
-        let listings = parse_hansard_list(html).expect("Failed to parse");
+        let (listings, _) = parse_hansard_list(html, BASE_URL).expect("Failed to parse");
 
         assert_eq!(listings.len(), 3);
         assert_eq!(listings[0].house, House::Senate);
@@ -566,6 +768,27 @@ mod tests {
         assert_eq!(listings[2].house, House::NationalAssembly);
     }
 
+    #[test]
+    fn test_parse_hansard_list_reports_skips() {
+        let html = r#"
+            <ul class="listing">
+                <li><a href="https://info.mzalendo.com/hansard/sitting/senate/2025-07-17">Senate 2025-07-17</a></li>
+                <li><a href="https://info.mzalendo.com/hansard/sitting/senate/">Senate    sitting</a></li>
+            </ul>
+        "#;
+
+        let (listings, report) = parse_hansard_list(html, BASE_URL).expect("Failed to parse");
+
+        assert_eq!(listings.len(), 1);
+        assert_eq!(report.parsed, 1);
+        assert_eq!(report.skipped.len(), 1);
+        assert!(
+            report.skipped[0].starts_with("listing 'Senate sitting': "),
+            "{:?}",
+            report.skipped
+        );
+    }
+
     #[test]
     fn test_parse_hansard_sitting_2020() {
         let html = fs::read_to_string("fixtures/archive/hansard_detail_2020")
@@ -593,6 +816,179 @@ mod tests {
                 .any(|c| c.speaker_url.is_some())
         });
         assert!(has_speaker_urls, "2020 hansard should have speaker URLs");
+
+        let pages: Vec<Option<u32>> = detail
+            .sections
+            .iter()
+            .flat_map(|section| section.contributions.iter().map(|c| c.source_page))
+            .collect();
+        assert_eq!(pages.first(), Some(&Some(1)));
+        assert_eq!(pages.last(), Some(&Some(8)));
+        assert!(pages.is_sorted(), "Pages should only move forward");
+
+        assert_eq!(
+            detail.opening,
+            [
+                "(Convened via Kenya Gazette Notice No.11251 of 26th December, 2020)",
+                "The House met at the Senate Chamber, Parliament Buildings, at 2.30 p.m.",
+                "[The Speaker (Hon. Lusaka) in the Chair]",
+            ]
+        );
+        assert!(detail.opening.contains(&detail.speaker_in_chair));
+        assert!(detail.quorum_notes.is_empty());
+    }
+
+    #[test]
+    fn test_parse_scene_notes() {
+        let document = Html::parse_document(
+            r#"<ul>
+                <li class="scene">The House met at 9.30 a.m.</li>
+                <li class="scene">[The Speaker (Hon. Lusaka) in the Chair]</li>
+                <li class="speech"><strong>Hon. Lusaka</strong><p>Hon. Senators, we do not have quorum.</p></li>
+                <li class="scene">(The Quorum Bell was rung)</li>
+                <li class="scene">(Applause)</li>
+            </ul>"#,
+        );
+
+        let (opening, quorum_notes) = parse_scene_notes(&document).unwrap();
+
+        assert_eq!(
+            opening,
+            [
+                "The House met at 9.30 a.m.",
+                "[The Speaker (Hon. Lusaka) in the Chair]"
+            ]
+        );
+        assert_eq!(quorum_notes, ["(The Quorum Bell was rung)"]);
+    }
+
+    fn parse_speech(html: &str) -> Contribution {
+        let document = Html::parse_document(&format!("<ul>{html}</ul>"));
+        let selector = Selector::parse("li.speech").unwrap();
+        let element = document.select(&selector).next().expect("Missing speech");
+        parse_contribution(element).expect("Failed to parse contribution")
+    }
+
+    #[test]
+    fn test_parse_sections_page_markers() {
+        let document = Html::parse_document(
+            r#"<ul>
+                <li class="heading">MOTIONS</li>
+                <li class="speech"><strong>Hon. Lusaka</strong><p>Hon. Senators, order.</p></li>
+                <li class="page_number">Page 4 of <a href="/hansard.pdf">Tuesday, 29th December 2020</a></li>
+                <li class="speech"><strong>Sen. Murkomen</strong><p>Thank you, Mr. Speaker.</p></li>
+                <li class="page">[Page 5]</li>
+                <li class="speech"><strong>Sen. Orengo</strong><p>I rise to support.</p></li>
+            </ul>"#,
+        );
+
+        let sections = parse_sections(&document).unwrap();
+        let pages: Vec<Option<u32>> = sections[0]
+            .contributions
+            .iter()
+            .map(|c| c.source_page)
+            .collect();
+        assert_eq!(pages, [None, Some(4), Some(5)]);
+    }
+
+    #[test]
+    fn test_parse_sections_dedupes_procedural_notes() {
+        let document = Html::parse_document(
+            r#"<ul>
+                <li class="heading">COMMUNICATION FROM THE CHAIR</li>
+                <li class="speech"><strong>Hon. Lusaka</strong><p>Hon. Senators, join me in welcoming the students.</p></li>
+                <li class="scene">(Applause)</li>
+                <li class="scene">(Applause)</li>
+                <li class="scene">(Laughter)</li>
+                <li class="scene">(Applause)</li>
+            </ul>"#,
+        );
+
+        let sections = parse_sections(&document).unwrap();
+        assert_eq!(
+            sections[0].contributions[0].procedural_notes,
+            ["(Applause)", "(Laughter)", "(Applause)"]
+        );
+    }
+
+    #[test]
+    fn test_parse_contribution_inline_party() {
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Hon. Mbadi, ODM</strong><br><p>Hon. Speaker, I rise to oppose.</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Hon. Mbadi");
+        assert_eq!(contribution.speaker_party, Some("ODM".to_string()));
+        assert_eq!(contribution.content, "Hon. Speaker, I rise to oppose.");
+
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Sen. Wetangula, Ford-Kenya</strong><p>Thank you.</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Sen. Wetangula");
+        assert_eq!(contribution.speaker_party, Some("Ford-Kenya".to_string()));
+    }
+
+    #[test]
+    fn test_parse_contribution_party_from_constituency_swap() {
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Mwala, UDA</strong> (Hon. Vincent Musau)<br><p>Thank you.</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Hon. Vincent Musau");
+        assert_eq!(contribution.speaker_raw, "Mwala, UDA (Hon. Vincent Musau)");
+        assert_eq!(contribution.speaker_role, Some("Mwala, UDA".to_string()));
+        assert_eq!(contribution.speaker_party, Some("UDA".to_string()));
+    }
+
+    #[test]
+    fn test_parse_contribution_swap_regressions() {
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Uasin Gishu County, Jubilee</strong> (Hon. Gladys Boss)<br><p>Thank you.</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Hon. Gladys Boss");
+        assert_eq!(
+            contribution.speaker_role,
+            Some("Uasin Gishu County, Jubilee".to_string())
+        );
+        assert_eq!(contribution.speaker_party, Some("Jubilee".to_string()));
+
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Nandi, FORD-K</strong> (Sen. Cherarkey)<br><p>Thank you.</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Sen. Cherarkey");
+        assert_eq!(contribution.speaker_role, Some("Nandi, FORD-K".to_string()));
+
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Cherarkey, Samson, UDA</strong> (Sen. Cherarkey)<br><p>Thank you.</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Cherarkey, Samson");
+        assert_eq!(
+            contribution.speaker_role,
+            Some("Sen. Cherarkey".to_string())
+        );
+        assert_eq!(contribution.speaker_party, Some("UDA".to_string()));
+
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Cherarkey, Samson</strong> (Hon. Temporary Speaker)<br><p>Order!</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Cherarkey, Samson");
+        assert_eq!(
+            contribution.speaker_role,
+            Some("Hon. Temporary Speaker".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_contribution_without_party() {
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Hon. Lusaka</strong> (The Speaker)<br><p>Order!</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Hon. Lusaka");
+        assert_eq!(contribution.speaker_party, None);
+
+        let contribution = parse_speech(
+            r#"<li class="speech"><strong>Hon. Mbadi, Suba South</strong><p>Order!</p></li>"#,
+        );
+        assert_eq!(contribution.speaker_name, "Hon. Mbadi, Suba South");
+        assert_eq!(contribution.speaker_party, None);
     }
 
     #[test]
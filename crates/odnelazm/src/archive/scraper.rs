@@ -1,5 +1,7 @@
 use super::parser::{ParseError, parse_hansard_list, parse_hansard_sitting, parse_person_details};
 use super::types::{HansardListing, HansardSitting, PersonDetails};
+use crate::types::SkipHook;
+use crate::util::get_with_retry;
 
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
@@ -19,6 +21,7 @@ pub enum ScraperError {
 pub struct WebScraper {
     client: Client,
     base_url: String,
+    skips: SkipHook,
 }
 
 impl WebScraper {
@@ -32,10 +35,35 @@ impl WebScraper {
             ))
             .build()?;
 
-        Ok(Self {
+        Ok(Self::from_client(client))
+    }
+
+    /// Wrap an already configured client instead of building one, e.g. to share its
+    /// connection pool and proxy settings. The client's timeout and user agent are used as-is.
+    pub fn from_client(client: Client) -> Self {
+        Self {
             client,
             base_url: super::BASE_URL.to_string(),
-        })
+            skips: SkipHook::default(),
+        }
+    }
+
+    /// Point the scraper at another host serving the archive's pages, e.g. a local mirror or a
+    /// mock server in tests. `new()` uses the production site.
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The host pages are fetched from, without a trailing slash.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Report the entries of the hansard index left out for being malformed to `skips`.
+    pub fn with_skip_hook(mut self, skips: SkipHook) -> Self {
+        self.skips = skips;
+        self
     }
 
     pub async fn fetch_hansard_list(&self) -> Result<Vec<HansardListing>, ScraperError> {
@@ -44,7 +72,8 @@ impl WebScraper {
         let url = format!("{}/hansard/", self.base_url);
         let html = self.get_html(&url).await?;
 
-        let listings = parse_hansard_list(&html)?;
+        let (listings, report) = parse_hansard_list(&html, &self.base_url)?;
+        self.skips.report(report);
         Ok(listings)
     }
 
@@ -53,6 +82,18 @@ impl WebScraper {
         url_or_slug: &str,
         nest_speaker_fetch: bool,
     ) -> Result<HansardSitting, ScraperError> {
+        let (sitting, _) = self
+            .fetch_sitting_with_html(url_or_slug, nest_speaker_fetch)
+            .await?;
+        Ok(sitting)
+    }
+
+    /// Fetch and parse a sitting, returning the raw page alongside it.
+    pub async fn fetch_sitting_with_html(
+        &self,
+        url_or_slug: &str,
+        nest_speaker_fetch: bool,
+    ) -> Result<(HansardSitting, String), ScraperError> {
         let url = if url_or_slug.starts_with("http") {
             url_or_slug.to_string()
         } else {
@@ -67,9 +108,7 @@ impl WebScraper {
 
         if nest_speaker_fetch {
             let speaker_urls: HashSet<String> = sitting
-                .sections
-                .iter()
-                .flat_map(|s| &s.contributions)
+                .all_contributions()
                 .filter_map(|c| c.speaker_url.as_ref())
                 .cloned()
                 .collect();
@@ -92,11 +131,7 @@ impl WebScraper {
                     }
                 }
 
-                for contrib in sitting
-                    .sections
-                    .iter_mut()
-                    .flat_map(|s| &mut s.contributions)
-                {
+                for contrib in sitting.all_contributions_mut() {
                     if let Some(url) = &contrib.speaker_url {
                         contrib.speaker_details = speaker_map.get(url).cloned();
                     }
@@ -111,7 +146,7 @@ impl WebScraper {
             log::info!("Nested speaker profile fetch skipped");
         }
 
-        Ok(sitting)
+        Ok((sitting, html))
     }
 
     pub async fn fetch_person_details(
@@ -137,14 +172,17 @@ impl WebScraper {
         Ok(details)
     }
 
+    /// Fetch the raw page at `url` without parsing it, with the same client and headers the
+    /// parsing fetches use.
+    pub async fn fetch_page(&self, url: &str) -> Result<String, ScraperError> {
+        log::info!("Fetching page: {}", url);
+        self.get_html(url).await
+    }
+
     async fn get_html(&self, url: &str) -> Result<String, ScraperError> {
-        let html = self
-            .client
-            .get(url)
-            .send()
+        let html = get_with_retry(&self.client, url)
             .await
             .inspect_err(|e| log::error!("HTTP error: {e:?}"))?
-            .error_for_status()?
             .text()
             .await
             .inspect_err(|e| log::error!("Decode error: {e:?}"))?;
@@ -152,3 +190,70 @@ impl WebScraper {
         Ok(html)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn serve(server: &MockServer, page_path: &str, fixture: &str) {
+        let html = fs::read_to_string(fixture).expect("Failed to read fixture");
+        Mock::given(method("GET"))
+            .and(path(page_path))
+            .respond_with(ResponseTemplate::new(200).set_body_string(html))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_sitting_with_speaker_profiles() {
+        let server = MockServer::start().await;
+        serve(
+            &server,
+            "/hansard/sitting/senate/2020-12-29-14-30-00",
+            "fixtures/archive/hansard_detail_2020",
+        )
+        .await;
+        for (slug, fixture) in [
+            ("farhiya-ali-haji", "person_farhiya"),
+            ("cherarkey-k-samson", "person_samson"),
+            ("rose-nyamunga-ogendo", "person_rose"),
+            ("samuel-poghisio", "person_samuel"),
+        ] {
+            serve(
+                &server,
+                &format!("/person/{slug}/"),
+                &format!("fixtures/archive/persons/{fixture}"),
+            )
+            .await;
+        }
+
+        let scraper = WebScraper::new().unwrap().with_base_url(server.uri());
+        let sitting = scraper
+            .fetch_hansard_sitting("/hansard/sitting/senate/2020-12-29-14-30-00", true)
+            .await
+            .unwrap();
+
+        let details = |url: &str| {
+            sitting
+                .all_contributions()
+                .find(|c| c.speaker_url.as_deref() == Some(url))
+                .map(|c| c.speaker_details.as_ref().map(|d| d.name.as_str()))
+        };
+        assert_eq!(
+            details("/person/farhiya-ali-haji/"),
+            Some(Some("Farhiya Ali Haji"))
+        );
+        assert_eq!(
+            details("/person/cherarkey-k-samson/"),
+            Some(Some("Cherarkey K Samson"))
+        );
+        assert_eq!(
+            details("/person/mutula-kilonzo-jnr/"),
+            Some(None),
+            "A profile that fails to load leaves the contribution without details"
+        );
+    }
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 pub use crate::types::House;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardListing {
     pub house: House,
     pub date: NaiveDate,
@@ -14,6 +15,7 @@ pub struct HansardListing {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardSitting {
     pub house: House,
     pub date: NaiveDate,
@@ -23,10 +25,28 @@ pub struct HansardSitting {
     pub session_number: String,
     pub session_type: String,
     pub speaker_in_chair: String,
+    /// Scene notes before the first speech, e.g. how the sitting was convened and where the
+    /// House met, including the speaker-in-chair line.
+    pub opening: Vec<String>,
+    /// Scene notes anywhere in the sitting that mention the quorum, e.g. "(Quorum Bell was rung)".
+    pub quorum_notes: Vec<String>,
     pub sections: Vec<HansardSection>,
 }
 
+impl HansardSitting {
+    /// Every contribution in document order.
+    pub fn all_contributions(&self) -> impl Iterator<Item = &Contribution> {
+        self.sections.iter().flat_map(|s| &s.contributions)
+    }
+
+    /// Like [`all_contributions`](Self::all_contributions), but mutable.
+    pub fn all_contributions_mut(&mut self) -> impl Iterator<Item = &mut Contribution> {
+        self.sections.iter_mut().flat_map(|s| &mut s.contributions)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardSection {
     pub section_type: String,
     pub title: Option<String>,
@@ -34,16 +54,24 @@ pub struct HansardSection {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Contribution {
     pub speaker_name: String,
+    /// The speaker line as scraped, before the name, role, and party are teased apart.
+    pub speaker_raw: String,
     pub speaker_role: Option<String>,
     pub speaker_url: Option<String>,
+    pub speaker_party: Option<String>,
     pub speaker_details: Option<PersonDetails>,
     pub content: String,
     pub procedural_notes: Vec<String>,
+    /// The page of the official Hansard the speech falls on, from the transcript's page markers.
+    #[serde(default)]
+    pub source_page: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PersonDetails {
     pub name: String,
     pub slug: String,
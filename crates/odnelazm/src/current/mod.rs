@@ -1,4 +1,4 @@
-mod parser;
+pub(crate) mod parser;
 pub(crate) mod scraper;
 pub(crate) mod types;
 
@@ -5,9 +5,12 @@ use regex::Regex;
 use scraper::{ElementRef, Html, Selector, error::SelectorErrorKind};
 
 use super::types::{
-    Bill, Contribution, HansardListing, HansardSection, HansardSitting, HansardSubsection, House,
-    Member, MemberProfile, ParliamentaryActivity, VoteRecord,
+    Bill, Committee, Contribution, ContributionKind, Division, HansardListing, HansardSection,
+    HansardSitting, HansardSubsection, House, Member, MemberProfile, ParliamentaryActivity,
+    QuestionStatusEntry, Sentiment, StatementKind, VoteDecision, VoteRecord,
 };
+use crate::types::{Parliament, ParseReport};
+use crate::util::clean_procedural_notes;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
@@ -21,6 +24,20 @@ pub enum ParseError {
     MissingField(String),
     #[error("Failed to parse selector: {0}")]
     HtmlSelector(String),
+    #[error("Unexpected structure: {0}")]
+    UnexpectedStructure(String),
+}
+
+/// Options for [`parse_hansard_sitting_with`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseConfig {
+    /// Fail with [`ParseError::UnexpectedStructure`] on headings the parser does not recognize,
+    /// instead of skipping them. Meant for monitoring markup drift on the site.
+    pub strict: bool,
+    /// Split a speech at paragraphs that open with another Member's name, e.g.
+    /// "(Hon. Junet Mohamed): Point of order!", into separate contributions. The speech
+    /// resumes as a new contribution after the interjection.
+    pub split_interjections: bool,
 }
 
 impl<'a> From<SelectorErrorKind<'a>> for ParseError {
@@ -34,6 +51,11 @@ static RE_LISTING_TITLE: LazyLock<Regex> = LazyLock::new(|| {
         .expect("invalid regex: listing title")
 });
 
+static RE_COMMITTEE_PHRASE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:an?|the)\s+(.+?)\s+of\s+the\s+(.+?)(?:\s+committee)?\.?$")
+        .expect("invalid regex: committee phrase")
+});
+
 static RE_SPEECHES: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"has made\D+(\d+)\D+speeches last year\D+(\d+)\D+speeches")
         .expect("invalid regex: speeches")
@@ -43,6 +65,132 @@ static RE_BILLS_TOTAL: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"has sponsored\D+(\d+)\D+bill").expect("invalid regex: bills total")
 });
 
+// XXX: the term line reads "A member of the 13th Parliament from September 8, 2022 to Present";
+// the end is a date instead of "Present" once the member has left.
+static RE_TERM: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\bfrom\s+([a-z]+)\.?\s+(\d{1,2}),?\s+(\d{4})(?:\s+to\s+(?:([a-z]+)\.?\s+(\d{1,2}),?\s+(\d{4})|present))?",
+    )
+    .expect("invalid regex: term")
+});
+
+// XXX: bios give the start of a member's service as "first elected in 2013", "first elected to
+// Parliament in 2013" or "MP since 2017"; the first such phrase wins.
+static RE_FIRST_ELECTED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\bfirst\s+elected\b(?:\s+[\w'’-]+){0,6}?\s+in\s+((?:19|20)\d{2})\b|\b(?:MP|M\.P\.|member\s+of\s+parliament|senator)\s+since\s+((?:19|20)\d{2})\b",
+    )
+    .expect("invalid regex: first elected")
+});
+
+// XXX: a sentence is anything up to the next full stop, so abbreviated degrees ("B.A.") cut the
+// sentence short; later fragments that still name a qualification are joined back on.
+static RE_EDUCATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)[^.]*\b(?:educated|studied|graduated|graduate|alumn(?:us|a|i)|degree|bachelor'?s?|master'?s|diploma|ph\.?\s?d|doctorate)\b[^.]*\.?",
+    )
+    .expect("invalid regex: education")
+});
+
+static RE_QUESTION_NUMBER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:question\s+no\.?|q\.)\s*0*(\d{1,4})(?:\s*/\s*\d{4})?")
+        .expect("invalid regex: question number")
+});
+static RE_QUESTION_DEFERRED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:deferred|dropped|withdrawn)\b").expect("invalid regex: question deferred")
+});
+static RE_PRESIDING_OFFICER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:the\s+)?(?:hon\.\s+)?(?:(?:temporary|deputy)\s+)?(?:speaker|chairperson|chairman)\b")
+        .expect("invalid regex: presiding officer")
+});
+static RE_ANSWERING_MEMBER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bcabinet\s+secretary\b|\bminister\b|^(?:the\s+)?CS\b")
+        .expect("invalid regex: answering member")
+});
+static RE_NOT_FOUND_PAGE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)<(?:title|h1)[^>]*>\s*(?:error\s+)?(?:404\b|(?:page\s+)?not\s+found\b)")
+        .expect("invalid regex: not found page")
+});
+static RE_ACTIVITY_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(\d{1,2})(?:st|nd|rd|th)?\s+([a-z]+)\.?,?\s+(\d{4})$")
+        .expect("invalid regex: activity date")
+});
+static RE_VOTE_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^([a-z]+)\.?\s+(\d{1,2}),?\s+(\d{4})$").expect("invalid regex: vote date")
+});
+static RE_NAME_PREFIX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(Hon\.?|Sen\.?)\s").expect("invalid regex: name prefix"));
+static RE_ROLE_PREFIX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(The\s)?(Ayes|Noes|Teller|Temporary Speaker|Deputy Speaker|Speaker|Temporary Chairperson|Chairperson|Majority Leader|Minority Leader|Majority Whip|Minority Whip|Leader of the (Majority|Minority) Party|Cabinet Secretary|CS\s|Minister|Attorney[-\s]General)")
+        .expect("invalid regex: role prefix")
+});
+static RE_NAME_IN_PARENS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(.+?)\s*\((.+?)\)$").expect("invalid regex: name in parens"));
+// XXX: committee names are title-cased ("Departmental Committee on Health", "Committee of
+// the whole House"); requiring a capitalised name skips generic phrasing such as
+// "referred to relevant Committees".
+static RE_REFERRED_COMMITTEE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i:referred|committed)\s+to\s+(?:(?i:the)\s+)?((?:[A-Z][\w’'-]*\s+)*Committees?(?:\s+(?:on|of|for|and|the|whole|[A-Z][\w’'-]*))*)",
+    )
+    .expect("invalid regex: referred committee")
+});
+static RE_HOUSE_BUSINESS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)business\s+for\s+the\s+week|house\s+business\s+committee|standing\s+order\s+44\s*\(2\)\s*\(a\)")
+        .expect("invalid regex: house business")
+});
+static RE_HOUSE_LEADER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)leader\s+of\s+(the\s+)?(majority|minority)|(majority|minority)\s+leader")
+        .expect("invalid regex: house leader")
+});
+
+static RE_DIVISION_START: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bdivided\b|^\W*division\W*$").expect("invalid regex: division start")
+});
+
+static RE_DIVISION_SIDE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:the\s+)?(ayes|noes|abstentions)\b").expect("invalid regex: division side")
+});
+
+static RE_DIVISION_TALLY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(ayes|noes|abstentions)\s*[:\-–]\s*(\d+)")
+        .expect("invalid regex: division tally")
+});
+
+static RE_DIVISION_RESULT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\bthe (?:ayes|noes) have it\b|\b(?:question|motion)\b[^.]*?\b(?:agreed to|negatived)\b",
+    )
+    .expect("invalid regex: division result")
+});
+
+// XXX: an interjection folded into someone else's speech: "(Hon. Junet Mohamed): Point of
+// order!" or "Hon. Junet Mohamed (Suna East, ODM): On a point of order". the name stops short
+// of punctuation so a sentence like "Hon. Speaker, I rise: ..." is left alone.
+static RE_INTERJECTION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(\(?((?:Hon|Sen)\.\s+[A-Z][^():,.!?]{1,60}?)\)?(?:\s*\([^()]*\))?)\s*:\s*(\S.*)$")
+        .expect("invalid regex: interjection")
+});
+
+static RE_PROFILE_PATH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"/mps-performance/([a-z-]+)/([^/]+)/[^/?#]+/?(?:[?#].*)?$")
+        .expect("invalid regex: profile path")
+});
+
+static RE_PARLIAMENT_ORDINAL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(\d{1,2}(?:st|nd|rd|th))[\s-]+parliament\b")
+        .expect("invalid regex: parliament ordinal")
+});
+static RE_PDF_PARLIAMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"/hansards/[^/]+/(\d{1,2}(?:st|nd|rd|th))/").expect("invalid regex: pdf parliament")
+});
+static RE_SESSION_NUMBER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:first|second|third|fourth|fifth|sixth|seventh|\d(?:st|nd|rd|th))\s+session\b",
+    )
+    .expect("invalid regex: session number")
+});
+
 fn elem_text(element: ElementRef) -> String {
     element.text().collect::<String>()
 }
@@ -51,20 +199,34 @@ fn normalize_whitespace(text: &str) -> String {
     text.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
+/// Resolve an `href`/`src` against [`BASE_URL`](super::BASE_URL), leaving absolute (and empty)
+/// URLs as-is.
+fn absolute_url(url: &str) -> String {
+    if url.is_empty() || url.starts_with("http://") || url.starts_with("https://") {
+        url.to_string()
+    } else if let Some(rest) = url.strip_prefix("//") {
+        format!("https://{rest}")
+    } else if url.starts_with('/') {
+        format!("{}{}", super::BASE_URL, url)
+    } else {
+        format!("{}/{}", super::BASE_URL, url.trim_start_matches("./"))
+    }
+}
+
 fn parse_month(month: &str) -> Result<u32, ParseError> {
     match month.to_lowercase().as_str() {
-        "january" => Ok(1),
-        "february" => Ok(2),
-        "march" => Ok(3),
-        "april" => Ok(4),
+        "january" | "jan" => Ok(1),
+        "february" | "feb" => Ok(2),
+        "march" | "mar" => Ok(3),
+        "april" | "apr" => Ok(4),
         "may" => Ok(5),
-        "june" => Ok(6),
-        "july" => Ok(7),
-        "august" => Ok(8),
-        "september" => Ok(9),
-        "october" => Ok(10),
-        "november" => Ok(11),
-        "december" => Ok(12),
+        "june" | "jun" => Ok(6),
+        "july" | "jul" => Ok(7),
+        "august" | "aug" => Ok(8),
+        "september" | "sept" | "sep" => Ok(9),
+        "october" | "oct" => Ok(10),
+        "november" | "nov" => Ok(11),
+        "december" | "dec" => Ok(12),
         _ => Err(ParseError::DateParse(format!("Unknown month: {}", month))),
     }
 }
@@ -181,15 +343,24 @@ fn parse_date_from_url_slug(url: &str) -> Result<(NaiveDate, String, String), Pa
     Ok((date, day_of_week, session_type))
 }
 
+// XXX: the site answers some bad slugs with a 200 and its "Page Not Found" page rather than a
+// 404, so the page heading is the only sign there is nothing at the URL.
+pub fn is_not_found_page(html: &str) -> bool {
+    RE_NOT_FOUND_PAGE.is_match(html)
+}
+
+/// Returns `None` when the page renders no pagination widget, i.e. a single page of results.
 pub fn parse_page_info(html: &str) -> Result<Option<(u32, u32)>, ParseError> {
     let document = Html::parse_document(html);
 
     let active_sel = Selector::parse("li.active.active_number_box span")?;
-    let current_page = document
+    let Some(current_page) = document
         .select(&active_sel)
         .next()
         .and_then(|e| normalize_whitespace(&elem_text(e)).parse::<u32>().ok())
-        .ok_or_else(|| ParseError::MissingField("Missing pagination elements".to_string()))?;
+    else {
+        return Ok(None);
+    };
 
     let page_label_sel = Selector::parse("a.page_label[href]").unwrap();
     let total_pages = document
@@ -298,19 +469,26 @@ pub fn parse_voting_patterns(html: &str) -> Result<Vec<VoteRecord>, ParseError>
 
             let title_elem = row.select(&title_sel).next()?;
             let title = normalize_whitespace(&elem_text(title_elem));
-            let url = title_elem.value().attr("href").map(str::to_string);
+            let url = title_elem.value().attr("href").map(absolute_url);
 
-            let decision = row
-                .select(&decision_sel)
-                .next()
+            let badge = row.select(&decision_sel).next();
+            let raw_decision = badge
                 .map(|e| normalize_whitespace(&elem_text(e)))
                 .unwrap_or_default();
+            let modifier = badge
+                .and_then(|e| {
+                    e.value()
+                        .classes()
+                        .find_map(|c| c.strip_prefix("decision-badge--"))
+                })
+                .unwrap_or_default();
 
             Some(VoteRecord {
-                date,
+                date: parse_vote_date(&date),
                 title,
                 url,
-                decision,
+                decision: parse_vote_decision(modifier, &raw_decision),
+                raw_decision,
             })
         })
         .collect();
@@ -318,6 +496,31 @@ pub fn parse_voting_patterns(html: &str) -> Result<Vec<VoteRecord>, ParseError>
     Ok(vote_records)
 }
 
+/// Date of a vote, written "June 20, 2024".
+fn parse_vote_date(text: &str) -> Option<NaiveDate> {
+    let caps = RE_VOTE_DATE.captures(text.trim())?;
+    let month = parse_month(&caps[1]).ok()?;
+    let day = caps[2].parse().ok()?;
+    let year = caps[3].parse().ok()?;
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+// XXX: the badge's `decision-badge--<decision>` modifier is the more stable signal; its text
+// is only read when the modifier is missing or unfamiliar.
+fn parse_vote_decision(modifier: &str, text: &str) -> VoteDecision {
+    [modifier, text]
+        .iter()
+        .map(|s| s.trim().to_lowercase())
+        .find_map(|s| match s.as_str() {
+            "yes" | "aye" | "ayes" => Some(VoteDecision::Yes),
+            "no" | "nay" | "noes" => Some(VoteDecision::No),
+            "abstain" | "abstained" | "abstention" => Some(VoteDecision::Abstain),
+            "absent" | "did not vote" => Some(VoteDecision::Absent),
+            _ => None,
+        })
+        .unwrap_or(VoteDecision::Unknown)
+}
+
 pub fn parse_activity_page_info(html: &str) -> Result<Option<(u32, u32)>, ParseError> {
     let document = Html::parse_document(html);
 
@@ -375,6 +578,7 @@ pub fn parse_parliamentary_activity(html: &str) -> Result<Vec<ParliamentaryActiv
             .next()
             .map(|e| normalize_whitespace(&elem_text(e)))
             .unwrap_or_default();
+        let date_parsed = parse_activity_date(&date);
 
         for subgroup in group.select(&subgroup_sel) {
             let contribution_type = subgroup
@@ -388,7 +592,7 @@ pub fn parse_parliamentary_activity(html: &str) -> Result<Vec<ParliamentaryActiv
                 .next()
                 .map(|e| {
                     let title = normalize_whitespace(&elem_text(e));
-                    let raw_url = e.value().attr("href").unwrap_or("").to_string();
+                    let raw_url = absolute_url(e.value().attr("href").unwrap_or(""));
                     let sitting_url = raw_url.split('#').next().unwrap_or(&raw_url).to_string();
                     (title, sitting_url)
                 })
@@ -398,7 +602,7 @@ pub fn parse_parliamentary_activity(html: &str) -> Result<Vec<ParliamentaryActiv
                 let Some(link) = item.select(&link_sel).next() else {
                     continue;
                 };
-                let url = link.value().attr("href").unwrap_or("").to_string();
+                let url = absolute_url(link.value().attr("href").unwrap_or(""));
                 let text_preview = link
                     .select(&text_sel)
                     .next()
@@ -411,6 +615,7 @@ pub fn parse_parliamentary_activity(html: &str) -> Result<Vec<ParliamentaryActiv
 
                 items.push(ParliamentaryActivity {
                     date: date.clone(),
+                    date_parsed,
                     topic: topic.clone(),
                     contribution_type: contribution_type.clone(),
                     section_title: section_title.clone(),
@@ -425,15 +630,18 @@ pub fn parse_parliamentary_activity(html: &str) -> Result<Vec<ParliamentaryActiv
     Ok(items)
 }
 
+/// Listings on a hansard list page, with a report of the links left out for lacking a URL,
+/// a title, or a readable date. Links of the house not asked for are not counted as skipped.
 pub fn parse_hansard_list(
     html: &str,
     house_filter: Option<House>,
-) -> Result<Vec<HansardListing>, ParseError> {
+) -> Result<(Vec<HansardListing>, ParseReport), ParseError> {
     let document = Html::parse_document(html);
     let split_selector = Selector::parse("div.split-docs")?;
     let link_selector = Selector::parse("div.hansard-document h3 a")?;
 
     let mut listings = Vec::new();
+    let mut report = ParseReport::default();
 
     for (i, split_div) in document.select(&split_selector).enumerate() {
         let house = if i == 0 {
@@ -447,13 +655,15 @@ pub fn parse_hansard_list(
         }
 
         for link_elem in split_div.select(&link_selector) {
-            let url = match link_elem.value().attr("href") {
-                Some(href) => href.to_string(),
-                None => continue,
+            let title = normalize_whitespace(&elem_text(link_elem));
+            let Some(href) = link_elem.value().attr("href") else {
+                report.skip(format!("listing '{title}' without a link"));
+                continue;
             };
+            let url = absolute_url(href);
 
-            let title = normalize_whitespace(&elem_text(link_elem));
             if title.is_empty() {
+                report.skip(format!("listing {url} without a title"));
                 continue;
             }
 
@@ -467,17 +677,33 @@ pub fn parse_hansard_list(
                         title,
                     });
                 }
-                Err(e) => log::warn!("Skipping listing '{}': {}", title, e),
+                Err(e) => report.skip(format!("listing '{title}': {e}")),
             }
         }
     }
 
-    Ok(listings)
+    report.parsed = listings.len();
+    Ok((listings, report))
 }
 
+#[cfg(test)]
 pub fn parse_hansard_sitting(html: &str, url: &str) -> Result<HansardSitting, ParseError> {
-    let document = Html::parse_document(html);
+    parse_hansard_sitting_with(html, url, ParseConfig::default())
+}
+
+pub fn parse_hansard_sitting_with(
+    html: &str,
+    url: &str,
+    config: ParseConfig,
+) -> Result<HansardSitting, ParseError> {
+    sitting_from_document(&Html::parse_document(html), url, config)
+}
 
+fn sitting_from_document(
+    document: &Html,
+    url: &str,
+    config: ParseConfig,
+) -> Result<HansardSitting, ParseError> {
     let house_selector = Selector::parse("span.house")?;
     let house_text = document
         .select(&house_selector)
@@ -539,7 +765,10 @@ pub fn parse_hansard_sitting(html: &str, url: &str) -> Result<HansardSitting, Pa
         .next()
         .and_then(|e| e.value().attr("href"))
         .filter(|h| h.ends_with(".pdf"))
-        .map(str::to_string);
+        .map(absolute_url);
+
+    let (parliament_number, session_number) =
+        parse_parliament_and_session(document, pdf_url.as_deref())?;
 
     let doc_summary_sel = Selector::parse("div.doc-summary")?;
     let (summary, sentiment) = document
@@ -547,10 +776,12 @@ pub fn parse_hansard_sitting(html: &str, url: &str) -> Result<HansardSitting, Pa
         .next()
         .map(|elem| parse_doc_summary(elem))
         .unwrap_or((None, None));
+    let sentiment_label = sentiment.as_deref().map(parse_sentiment);
 
-    let sections = parse_sitting_sections(&document)?;
+    let (sections, divisions) = parse_sitting_sections(document, config)?;
+    let questions_status = parse_questions_status(&sections);
 
-    Ok(HansardSitting {
+    let mut sitting = HansardSitting {
         house,
         date,
         day_of_week,
@@ -558,9 +789,197 @@ pub fn parse_hansard_sitting(html: &str, url: &str) -> Result<HansardSitting, Pa
         time,
         summary,
         sentiment,
+        sentiment_label,
         pdf_url,
+        parliament_number,
+        session_number,
         sections,
-    })
+        divisions,
+        questions_status,
+    };
+    for contribution in sitting.all_contributions_mut() {
+        clean_procedural_notes(&mut contribution.procedural_notes);
+    }
+    Ok(sitting)
+}
+
+/// The contribution at a `#chunk-N` anchor of a sitting page, e.g. from a member's activity
+/// link. The anchor may mark the contributor line, the speech, or the newer layout's wrapper
+/// around both. Returns `None` when it marks no contribution, e.g. a heading or page break.
+pub fn parse_contribution_at(
+    html: &str,
+    url: &str,
+    anchor: &str,
+) -> Result<Option<Contribution>, ParseError> {
+    let document = Html::parse_document(html);
+    let elements = transcript_elements(&document)?;
+
+    let marks = |e: &ElementRef| {
+        e.value().id() == Some(anchor)
+            || e.parent()
+                .and_then(ElementRef::wrap)
+                .is_some_and(|p| p.value().id() == Some(anchor))
+    };
+    let is_speaker = |e: &ElementRef| {
+        e.value().name() == "div"
+            && e.value()
+                .attr("class")
+                .unwrap_or_default()
+                .contains("contributor-name")
+    };
+    let is_boundary = |e: &ElementRef| is_speaker(e) || e.value().name() == "h2";
+
+    let Some(position) = elements.iter().position(marks) else {
+        return Ok(None);
+    };
+    let Some(start) = elements[..=position]
+        .iter()
+        .rposition(is_boundary)
+        .filter(|&i| is_speaker(&elements[i]))
+    else {
+        return Ok(None);
+    };
+    let end = elements[start + 1..]
+        .iter()
+        .position(is_boundary)
+        .map_or(elements.len(), |i| start + 1 + i);
+
+    // XXX: the speech is parsed on its own to know which contribution the anchor marks, then
+    // looked up in the whole sitting, where its kind and procedural notes are filled in.
+    let fragment: String = elements[start..end].iter().map(|e| e.html()).collect();
+    let fragment = Html::parse_document(&format!(
+        r#"<article class="hansard-document">{fragment}</article>"#
+    ));
+    let (sections, _) = parse_sitting_sections(&fragment, ParseConfig::default())?;
+    let Some(target) = sections
+        .into_iter()
+        .find_map(|s| s.contributions.into_iter().next())
+    else {
+        return Ok(None);
+    };
+
+    let sitting = sitting_from_document(&document, url, ParseConfig::default())?;
+    let found = sitting
+        .all_contributions()
+        .find(|c| c.speaker_raw == target.speaker_raw && c.content == target.content)
+        .cloned();
+    Ok(Some(found.unwrap_or(target)))
+}
+
+// XXX: the page header only reads "THE PARLIAMENT OF KENYA", so the parliament is taken from
+// the first place that names it: the header, the PDF path (".../hansards/senate/13th/..."),
+// then the speakers' profile links (".../13th-parliament/..."). No session number has been seen
+// on current pages yet; it is read from the header should one appear.
+fn parse_parliament_and_session(
+    document: &Html,
+    pdf_url: Option<&str>,
+) -> Result<(Option<String>, Option<String>), ParseError> {
+    let header_sel = Selector::parse("header.hansard-header, div.head-metadata")?;
+    let header = document
+        .select(&header_sel)
+        .map(|e| normalize_whitespace(&elem_text(e)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let profile_link_sel = Selector::parse("a[href*=\"-parliament/\"]")?;
+    let ordinal = RE_PARLIAMENT_ORDINAL
+        .captures(&header)
+        .or_else(|| pdf_url.and_then(|url| RE_PDF_PARLIAMENT.captures(url)))
+        .map(|caps| caps[1].to_string())
+        .or_else(|| {
+            document
+                .select(&profile_link_sel)
+                .filter_map(|a| a.value().attr("href"))
+                .find_map(|href| RE_PARLIAMENT_ORDINAL.captures(href))
+                .map(|caps| caps[1].to_string())
+        });
+    let parliament_number = ordinal
+        .and_then(|ordinal| format!("{ordinal}-parliament").parse::<Parliament>().ok())
+        .map(|parliament| parliament.as_str().to_string());
+
+    let session_number = RE_SESSION_NUMBER
+        .find(&header)
+        .map(|m| m.as_str().to_string());
+
+    Ok((parliament_number, session_number))
+}
+
+// XXX: the sitting page carries no Order Paper, so the scheduled questions are taken from the
+// transcript itself: a subsection titled "Question No.091/2025" (the subject either follows in
+// the same title or as the next subsection) is a question taken up, while numbers only
+// mentioned in a contribution ("Question No.093/2025 ... is deferred") were on the agenda but
+// not reached. A deferral note applies to the numbers in the same contribution, or else to the
+// question whose subsection it appears in.
+fn parse_questions_status(sections: &[HansardSection]) -> Vec<QuestionStatusEntry> {
+    let mut entries: Vec<QuestionStatusEntry> = Vec::new();
+
+    for section in sections {
+        let mut current: Option<u32> = None;
+        let mut subsections = section.subsections.iter().peekable();
+
+        while let Some(subsection) = subsections.next() {
+            let mut contributions: Vec<&Contribution> = subsection.contributions.iter().collect();
+
+            if let Some(caps) = RE_QUESTION_NUMBER.captures(&subsection.title)
+                && let Ok(number) = caps[1].parse::<u32>()
+            {
+                let rest = subsection.title[caps.get(0).map_or(0, |m| m.end())..].trim_matches(
+                    |c: char| c.is_whitespace() || matches!(c, '–' | '-' | ':' | '.'),
+                );
+                let mut subject = (!rest.is_empty()).then(|| rest.to_string());
+                if subject.is_none()
+                    && let Some(&next) = subsections.peek()
+                    && !RE_QUESTION_NUMBER.is_match(&next.title)
+                {
+                    subject = Some(next.title.clone());
+                    contributions.extend(next.contributions.iter());
+                    subsections.next();
+                }
+
+                entries.retain(|e| e.number != number);
+                entries.push(QuestionStatusEntry {
+                    number,
+                    subject,
+                    reached: !contributions.is_empty(),
+                });
+                current = Some(number);
+            }
+
+            for contribution in contributions {
+                let mentioned: Vec<u32> = RE_QUESTION_NUMBER
+                    .captures_iter(&contribution.content)
+                    .filter_map(|caps| caps[1].parse().ok())
+                    .collect();
+                for &number in &mentioned {
+                    if !entries.iter().any(|e| e.number == number) {
+                        entries.push(QuestionStatusEntry {
+                            number,
+                            subject: None,
+                            reached: false,
+                        });
+                    }
+                }
+
+                if contribution
+                    .procedural_notes
+                    .iter()
+                    .any(|n| RE_QUESTION_DEFERRED.is_match(n))
+                {
+                    let deferred = if mentioned.is_empty() {
+                        current.into_iter().collect()
+                    } else {
+                        mentioned
+                    };
+                    for entry in entries.iter_mut().filter(|e| deferred.contains(&e.number)) {
+                        entry.reached = false;
+                    }
+                }
+            }
+        }
+    }
+
+    entries.sort_by_key(|e| e.number);
+    entries
 }
 
 fn parse_doc_summary(elem: ElementRef) -> (Option<String>, Option<String>) {
@@ -597,7 +1016,31 @@ fn parse_doc_summary(elem: ElementRef) -> (Option<String>, Option<String>) {
     (summary, sentiment)
 }
 
-fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseError> {
+// XXX: the site's sentiment text is free-form ("Mixed", "Largely positive", ...). both
+// polarities mentioned together are treated as mixed; anything unrecognised is kept raw.
+fn parse_sentiment(text: &str) -> Sentiment {
+    let lower = text.to_lowercase();
+    let positive = lower.contains("positive");
+    let negative = lower.contains("negative");
+
+    if lower.contains("mixed") || (positive && negative) {
+        Sentiment::Mixed
+    } else if positive {
+        Sentiment::Positive
+    } else if negative {
+        Sentiment::Negative
+    } else if lower.contains("neutral") {
+        Sentiment::Neutral
+    } else {
+        Sentiment::Other {
+            raw: text.to_string(),
+        }
+    }
+}
+
+/// The transcript's elements in document order, with the newer layout's chunk wrappers
+/// flattened away. Empty when the page has no transcript.
+fn transcript_elements(document: &Html) -> Result<Vec<ElementRef<'_>>, ParseError> {
     // XXX: support both HTML formats:
     //   old: article.hansard-document → semantic elements as direct children
     //   new: div.hansard-content → div.chunk-wrapper → semantic elements
@@ -630,11 +1073,23 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
             }
         })
         .collect();
+    Ok(elements)
+}
+
+fn parse_sitting_sections(
+    document: &Html,
+    config: ParseConfig,
+) -> Result<(Vec<HansardSection>, Vec<Division>), ParseError> {
+    let elements = transcript_elements(document)?;
 
     let mut sections: Vec<HansardSection> = Vec::new();
     let mut current_section: Option<HansardSection> = None;
     let mut current_subsection: Option<HansardSubsection> = None;
-    let mut pending_speaker: Option<(String, Option<String>)> = None;
+    let mut pending_speaker: Option<(String, Option<String>, Option<String>, String)> = None;
+    let mut divisions: Vec<Division> = Vec::new();
+    let mut current_division: Option<Division> = None;
+    // XXX: set while the element stream is inside an "AYES"/"NOES"/"ABSTENTIONS" member list
+    let mut division_side: Option<String> = None;
 
     for element in elements {
         let tag = element.value().name();
@@ -648,8 +1103,13 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
             if let Some(section) = current_section.take() {
                 sections.push(section);
             }
+            divisions.extend(finish_division(current_division.take()));
+            division_side = None;
 
             let heading = normalize_whitespace(&elem_text(element));
+            if RE_DIVISION_START.is_match(&heading) {
+                current_division = Some(Division::default());
+            }
             if !heading.is_empty() {
                 current_section = Some(HansardSection {
                     section_type: heading,
@@ -662,6 +1122,7 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
                 push_contribution(contrib, &mut current_subsection, &mut current_section);
             }
             flush_subsection(&mut current_subsection, &mut current_section);
+            division_side = None;
 
             let heading = normalize_whitespace(&elem_text(element));
             if !heading.is_empty() {
@@ -677,6 +1138,8 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
                 current_subsection = Some(HansardSubsection {
                     title: heading,
                     contributions: Vec::new(),
+                    referred_to_committee: None,
+                    statement_kind: None,
                 });
             }
         } else if tag == "div" && class.contains("contributor-name") {
@@ -685,19 +1148,32 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
             }
 
             let a_sel = Selector::parse("a")?;
+            let raw = normalize_whitespace(&elem_text(element));
             let (name, speaker_url) = if let Some(a) = element.select(&a_sel).next() {
                 let name = normalize_whitespace(&elem_text(a));
-                let url = a.value().attr("href").map(str::to_string);
+                let url = a.value().attr("href").map(absolute_url);
                 (name, url)
             } else {
                 (normalize_whitespace(&elem_text(element)), None)
             };
 
-            if !name.is_empty() {
-                pending_speaker = Some((name, speaker_url));
+            division_side = RE_DIVISION_SIDE
+                .captures(&name)
+                .map(|caps| caps[1].to_lowercase());
+            if division_side.is_some() {
+                current_division.get_or_insert_with(Division::default);
+            } else if !name.is_empty() {
+                let (name, role) = split_speaker_role(name);
+                pending_speaker = Some((name, role, speaker_url, raw));
             }
+        } else if tag == "div"
+            && class.contains("speech-content")
+            && let Some(side) = &division_side
+        {
+            let p_sel = Selector::parse("p")?;
+            push_division_members(side, element, &p_sel, &mut current_division);
         } else if tag == "div" && class.contains("speech-content") {
-            if let Some((name, url)) = pending_speaker.take() {
+            if let Some((name, role, url, raw)) = pending_speaker.take() {
                 let p_sel = Selector::parse("p")?;
                 let procedural_sel = Selector::parse("aside.procedural-note")?;
 
@@ -707,24 +1183,42 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
                     .collect::<Vec<_>>()
                     .join("\n\n");
 
-                let procedural_notes = element
+                let procedural_notes: Vec<String> = element
                     .select(&procedural_sel)
                     .map(|a| normalize_whitespace(&elem_text(a)))
                     .collect();
 
-                push_contribution(
-                    Contribution {
-                        speaker_name: name,
-                        speaker_url: url,
-                        content,
-                        procedural_notes,
-                    },
-                    &mut current_subsection,
-                    &mut current_section,
-                );
+                if procedural_notes
+                    .iter()
+                    .any(|n| RE_DIVISION_START.is_match(n))
+                {
+                    current_division.get_or_insert_with(Division::default);
+                }
+                scan_division_text(&content, &mut current_division, &mut divisions);
+
+                let speech = Contribution {
+                    speaker_name: name,
+                    speaker_raw: raw,
+                    speaker_role: role,
+                    speaker_url: url,
+                    content,
+                    procedural_notes,
+                    contribution_kind: None,
+                };
+                let parts = if config.split_interjections {
+                    split_interjections(speech)
+                } else {
+                    vec![speech]
+                };
+                for part in parts {
+                    push_contribution(part, &mut current_subsection, &mut current_section);
+                }
             }
         } else if tag == "div" && class.contains("scene-description") {
             let scene = normalize_whitespace(&elem_text(element));
+            if RE_DIVISION_START.is_match(&scene) {
+                current_division.get_or_insert_with(Division::default);
+            }
             if !scene.is_empty() {
                 if let Some(ref mut sub) = current_subsection {
                     if let Some(last) = sub.contributions.last_mut() {
@@ -739,8 +1233,15 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
         } else if tag == "p" {
             let text = normalize_whitespace(&elem_text(element));
             if !text.is_empty() {
+                scan_division_text(&text, &mut current_division, &mut divisions);
                 append_text_to_active("\n\n", text, &mut current_subsection, &mut current_section);
             }
+        } else if tag == "ol"
+            && class.contains("content-list")
+            && let Some(side) = &division_side
+        {
+            let li_sel = Selector::parse("li")?;
+            push_division_members(side, element, &li_sel, &mut current_division);
         } else if tag == "ol" && class.contains("content-list") {
             // XXX: auto-generated list from PDF conversion — often a direct continuation of a
             // preceding <p> (e.g. bill metadata or NG-CDF constituency lists). flatten all
@@ -755,6 +1256,11 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
             if !text.is_empty() {
                 append_text_to_active(" ", text, &mut current_subsection, &mut current_section);
             }
+        } else if config.strict && matches!(tag, "h1" | "h2" | "h3" | "h4" | "h5" | "h6") {
+            return Err(ParseError::UnexpectedStructure(format!(
+                "unrecognized heading <{tag} class=\"{class}\">: {}",
+                normalize_whitespace(&elem_text(element))
+            )));
         }
     }
 
@@ -765,8 +1271,170 @@ fn parse_sitting_sections(document: &Html) -> Result<Vec<HansardSection>, ParseE
     if let Some(section) = current_section {
         sections.push(section);
     }
+    divisions.extend(finish_division(current_division));
+
+    for section in &mut sections {
+        tag_contribution_kinds(section);
+    }
+
+    Ok((sections, divisions))
+}
+
+// XXX: the transcript never labels a speech as a question or an answer, so the kind is read off
+// the structure around it. under a "Question No.091/2025" heading (or the subject heading that
+// follows an empty one) the Cabinet Secretary answers and every other Member asks, including
+// supplementaries; statement subsections carry statements and bill or motion sections carry
+// debate. the Chair's remarks are left untagged.
+fn tag_contribution_kinds(section: &mut HansardSection) {
+    let section_type = section.section_type.to_uppercase();
+    let section_kind = if section_type.contains("BILL") || section_type.contains("MOTION") {
+        Some(ContributionKind::Debate)
+    } else if section_type.contains("STATEMENT") {
+        Some(ContributionKind::Statement)
+    } else {
+        None
+    };
+
+    for contribution in &mut section.contributions {
+        contribution.contribution_kind = contribution_kind(contribution, section_kind, false);
+    }
+
+    let mut question_pending = false;
+    for subsection in &mut section.subsections {
+        let numbered = RE_QUESTION_NUMBER.is_match(&subsection.title);
+        let is_question = numbered || question_pending;
+        question_pending = numbered && subsection.contributions.is_empty();
+
+        let kind = if subsection.statement_kind.is_some() && !is_question {
+            Some(ContributionKind::Statement)
+        } else if subsection.title.to_uppercase().contains("BILL") {
+            Some(ContributionKind::Debate)
+        } else if section_type.contains("QUESTION") && !is_question {
+            None
+        } else {
+            section_kind
+        };
+        for contribution in &mut subsection.contributions {
+            contribution.contribution_kind = contribution_kind(contribution, kind, is_question);
+        }
+    }
+}
+
+fn contribution_kind(
+    contribution: &Contribution,
+    kind: Option<ContributionKind>,
+    is_question: bool,
+) -> Option<ContributionKind> {
+    let speaker = contribution.speaker_name.trim();
+    let presiding = speaker_matches(contribution, &RE_PRESIDING_OFFICER);
+    if speaker.is_empty() || presiding {
+        None
+    } else if is_question && speaker_matches(contribution, &RE_ANSWERING_MEMBER) {
+        Some(ContributionKind::Answer)
+    } else if is_question {
+        Some(ContributionKind::Question)
+    } else {
+        kind
+    }
+}
+
+/// Whether the speaker's name, or the role split off it, matches `re`.
+fn speaker_matches(contribution: &Contribution, re: &Regex) -> bool {
+    re.is_match(contribution.speaker_name.trim())
+        || contribution
+            .speaker_role
+            .as_deref()
+            .is_some_and(|role| re.is_match(role))
+}
+
+fn push_division_members(
+    side: &str,
+    element: ElementRef,
+    item_sel: &Selector,
+    current_division: &mut Option<Division>,
+) {
+    let division = current_division.get_or_insert_with(Division::default);
+    let members = match side {
+        "ayes" => &mut division.ayes_members,
+        "noes" => &mut division.noes_members,
+        _ => &mut division.abstentions_members,
+    };
+    members.get_or_insert_with(Vec::new).extend(
+        element
+            .select(item_sel)
+            .map(|e| normalize_whitespace(&elem_text(e)))
+            .filter(|s| !s.is_empty()),
+    );
+}
+
+// XXX: the chair announces tallies in ordinary speech, e.g. "AYES: 195, NOES: 106,
+// ABSTENTIONS: 3. The Ayes have it." an announcement naming both ayes and noes opens a
+// division even when no "(Question put and the House divided)" note preceded it. the
+// result phrase is only honoured inside a division so voice votes are not recorded.
+fn scan_division_text(
+    text: &str,
+    current_division: &mut Option<Division>,
+    divisions: &mut Vec<Division>,
+) {
+    let tallies: Vec<(String, u32)> = RE_DIVISION_TALLY
+        .captures_iter(text)
+        .filter_map(|caps| Some((caps[1].to_lowercase(), caps[2].parse().ok()?)))
+        .collect();
+
+    let announces = |side: &str| tallies.iter().any(|(s, _)| s == side);
+    if current_division.is_none() && announces("ayes") && announces("noes") {
+        *current_division = Some(Division::default());
+    }
+
+    let Some(division) = current_division else {
+        return;
+    };
+
+    for (side, count) in tallies {
+        match side.as_str() {
+            "ayes" => division.ayes = count,
+            "noes" => division.noes = count,
+            _ => division.abstentions = count,
+        }
+    }
+
+    if let Some(m) = RE_DIVISION_RESULT.find(text) {
+        division.result = m.as_str().to_string();
+        divisions.extend(finish_division(current_division.take()));
+    }
+}
+
+// XXX: fills in counts from the member lists when no tally was announced and derives
+// the result from the counts when no result phrase was found. empty divisions (e.g. a
+// "divided" note that never led to a vote) are dropped.
+fn finish_division(division: Option<Division>) -> Option<Division> {
+    let mut division = division?;
+
+    let listed = |members: &Option<Vec<String>>| members.as_ref().map_or(0, |m| m.len() as u32);
+    if division.ayes == 0 {
+        division.ayes = listed(&division.ayes_members);
+    }
+    if division.noes == 0 {
+        division.noes = listed(&division.noes_members);
+    }
+    if division.abstentions == 0 {
+        division.abstentions = listed(&division.abstentions_members);
+    }
+
+    if division.ayes == 0 && division.noes == 0 && division.abstentions == 0 {
+        return None;
+    }
+
+    if division.result.is_empty() {
+        division.result = match division.ayes.cmp(&division.noes) {
+            std::cmp::Ordering::Greater => "The Ayes have it",
+            std::cmp::Ordering::Less => "The Noes have it",
+            std::cmp::Ordering::Equal => "Tied",
+        }
+        .to_string();
+    }
 
-    Ok(sections)
+    Some(division)
 }
 
 // XXX: appends `text` to the last contribution in the active target (subsection → section).
@@ -794,21 +1462,90 @@ fn append_text_to_active(
     } else {
         target_contributions.push(Contribution {
             speaker_name: String::new(),
+            speaker_raw: String::new(),
+            speaker_role: None,
             speaker_url: None,
             content: text,
             procedural_notes: Vec::new(),
+            contribution_kind: None,
         });
     }
 }
 
+// XXX: chair occupants and ministers are written "The Temporary Speaker (Hon. Omboko Milemba)",
+// and now and then the other way around as "Hon. Omboko Milemba (The Temporary Speaker)". Either
+// way the person is kept as the name and the role split out, as the archive parser does.
+// A parenthesized "(Constituency, Party)" is not a role and is left on the name.
+fn split_speaker_role(name: String) -> (String, Option<String>) {
+    let Some(caps) = RE_NAME_IN_PARENS.captures(&name) else {
+        return (name, None);
+    };
+    let outer = caps[1].trim().to_string();
+    let inner = caps[2].trim().to_string();
+
+    if RE_NAME_PREFIX.is_match(&inner) && RE_ROLE_PREFIX.is_match(&outer) {
+        (inner, Some(outer))
+    } else if RE_NAME_PREFIX.is_match(&outer) && RE_ROLE_PREFIX.is_match(&inner) {
+        (outer, Some(inner))
+    } else {
+        (name, None)
+    }
+}
+
+/// Split `speech` at paragraphs marking an interjection (see [`RE_INTERJECTION`]). Each
+/// interjection becomes its own contribution, and the paragraphs after it a new contribution
+/// by the original speaker. Procedural notes stay on the first part.
+fn split_interjections(speech: Contribution) -> Vec<Contribution> {
+    if !speech
+        .content
+        .split("\n\n")
+        .any(|p| RE_INTERJECTION.is_match(p))
+    {
+        return vec![speech];
+    }
+
+    let mut parts: Vec<Contribution> = Vec::new();
+    let mut resumed = true;
+    for paragraph in speech.content.split("\n\n") {
+        if let Some(caps) = RE_INTERJECTION.captures(paragraph) {
+            let (name, role) = split_speaker_role(caps[2].trim().to_string());
+            parts.push(Contribution {
+                speaker_name: name,
+                speaker_raw: caps[1].trim().to_string(),
+                speaker_role: role,
+                speaker_url: None,
+                content: caps[3].to_string(),
+                procedural_notes: Vec::new(),
+                contribution_kind: None,
+            });
+            resumed = false;
+        } else if resumed && let Some(last) = parts.last_mut() {
+            last.content.push_str("\n\n");
+            last.content.push_str(paragraph);
+        } else {
+            parts.push(Contribution {
+                content: paragraph.to_string(),
+                procedural_notes: Vec::new(),
+                ..speech.clone()
+            });
+            resumed = true;
+        }
+    }
+    parts[0].procedural_notes = speech.procedural_notes;
+    parts
+}
+
 fn take_pending_contribution(
-    pending: &mut Option<(String, Option<String>)>,
+    pending: &mut Option<(String, Option<String>, Option<String>, String)>,
 ) -> Option<Contribution> {
-    pending.take().map(|(name, url)| Contribution {
+    pending.take().map(|(name, role, url, raw)| Contribution {
         speaker_name: name,
+        speaker_raw: raw,
+        speaker_role: role,
         speaker_url: url,
         content: String::new(),
         procedural_notes: Vec::new(),
+        contribution_kind: None,
     })
 }
 
@@ -836,36 +1573,95 @@ fn flush_subsection(
     current_subsection: &mut Option<HansardSubsection>,
     current_section: &mut Option<HansardSection>,
 ) {
-    if let Some(subsection) = current_subsection.take()
+    if let Some(mut subsection) = current_subsection.take()
         && let Some(section) = current_section
     {
+        if subsection.title.to_uppercase().contains("BILL") {
+            subsection.referred_to_committee = parse_referred_committee(&subsection);
+        }
+        subsection.statement_kind = classify_statement(&section.section_type, &subsection);
         section.subsections.push(subsection);
     }
 }
 
-pub fn parse_member_list(html: &str, house: House) -> Result<Vec<Member>, ParseError> {
+// XXX: the weekly House Business statement is made by the Leader of the Majority (or Minority)
+// Party under Standing Order 44(2)(a). The current source rarely names the role, so we also
+// key off the subsection title and the opening of the first contribution.
+fn classify_statement(section_type: &str, subsection: &HansardSubsection) -> Option<StatementKind> {
+    let section_type = section_type.to_uppercase();
+    let title = subsection.title.to_uppercase();
+    if !section_type.contains("STATEMENT") && !title.contains("STATEMENT") {
+        return None;
+    }
+
+    let opening = subsection.contributions.first();
+    let by_leader = opening.is_some_and(|c| speaker_matches(c, &RE_HOUSE_LEADER));
+    let about_business = RE_HOUSE_BUSINESS.is_match(&subsection.title)
+        || opening.is_some_and(|c| RE_HOUSE_BUSINESS.is_match(&c.content));
+
+    let kind = if about_business || (by_leader && title.contains("BUSINESS")) {
+        StatementKind::HouseBusiness
+    } else if title.contains("PERSONAL STATEMENT") || section_type.contains("PERSONAL STATEMENT") {
+        StatementKind::PersonalStatement
+    } else if title.contains("MINISTERIAL") || section_type.contains("MINISTERIAL") {
+        StatementKind::MinisterialStatement
+    } else {
+        StatementKind::Other
+    };
+    Some(kind)
+}
+
+fn parse_referred_committee(subsection: &HansardSubsection) -> Option<String> {
+    subsection
+        .contributions
+        .iter()
+        .flat_map(|c| std::iter::once(&c.content).chain(c.procedural_notes.iter()))
+        .find_map(|text| {
+            let caps = RE_REFERRED_COMMITTEE.captures(text)?;
+            let mut words: Vec<&str> = caps[1].split_whitespace().collect();
+            while words
+                .last()
+                .is_some_and(|w| matches!(*w, "on" | "of" | "for" | "and" | "the" | "whole"))
+            {
+                words.pop();
+            }
+            Some(words.join(" "))
+        })
+}
+
+/// Members on a member list page, with a report of the entries left out for lacking a
+/// profile link or a name.
+pub fn parse_member_list(
+    html: &str,
+    house: House,
+) -> Result<(Vec<Member>, ParseReport), ParseError> {
     let document = Html::parse_document(html);
     let item_sel = Selector::parse("a.members-list--item, a.senators-list--item")?;
     let name_sel = Selector::parse("div.members-list--name, div.senators-list--name")?;
     let leader_role_sel = Selector::parse("p.leader-role")?;
     let repr_sel =
         Selector::parse("div.members-list--representation, div.senators-list--representation")?;
+    let strong_sel = Selector::parse("strong")?;
+    let image_sel = Selector::parse("img.members-list--image, img.senators-list--image")?;
 
     let mut members = Vec::new();
+    let mut report = ParseReport::default();
 
     for item in document.select(&item_sel) {
-        let url = match item.value().attr("href") {
-            Some(href) => href.to_string(),
-            None => continue,
-        };
-
         let name = item
             .select(&name_sel)
             .next()
             .map(|e| normalize_whitespace(&elem_text(e)))
             .unwrap_or_default();
 
+        let Some(href) = item.value().attr("href") else {
+            report.skip(format!("member '{name}' without a profile link"));
+            continue;
+        };
+        let url = absolute_url(href);
+
         if name.is_empty() {
+            report.skip(format!("member {url} without a name"));
             continue;
         }
 
@@ -875,22 +1671,92 @@ pub fn parse_member_list(html: &str, house: House) -> Result<Vec<Member>, ParseE
             .map(|e| normalize_whitespace(&elem_text(e)))
             .filter(|s| !s.is_empty());
 
-        let constituency = item
+        // XXX: lazy-loaded thumbnails keep the real image in data-src and a placeholder in src
+        let photo_url = item.select(&image_sel).next().and_then(|img| {
+            ["data-src", "src"]
+                .into_iter()
+                .filter_map(|attr| img.value().attr(attr))
+                .map(str::trim)
+                .find(|src| !src.is_empty() && !src.starts_with("data:"))
+                .map(absolute_url)
+        });
+
+        let (constituency, county) = item
             .select(&repr_sel)
             .next()
-            .map(|e| normalize_whitespace(&elem_text(e)))
-            .filter(|s| !s.is_empty());
+            .map(|e| parse_representation(e, &strong_sel))
+            .unwrap_or_default();
 
         members.push(Member {
             name,
             url,
             house,
             role,
+            photo_url,
             constituency,
+            county,
         });
     }
 
-    Ok(members)
+    report.parsed = members.len();
+    Ok((members, report))
+}
+
+/// Constituency and county from a member list representation, where each place is a
+/// `<strong>` followed by its kind: "MNA for <strong>Kikuyu</strong> constituency",
+/// "Senator for <strong>Meru</strong> county". Nominated members have neither.
+fn parse_representation(
+    repr: ElementRef,
+    strong_sel: &Selector,
+) -> (Option<String>, Option<String>) {
+    let woman_rep = elem_text(repr)
+        .to_lowercase()
+        .contains("woman representative");
+    let mut constituency = None;
+    let mut county = None;
+
+    for strong in repr.select(strong_sel) {
+        let place = normalize_whitespace(&elem_text(strong));
+        if place.is_empty() {
+            continue;
+        }
+        let kind = strong
+            .next_sibling()
+            .and_then(|n| n.value().as_text().map(|t| t.trim().to_lowercase()))
+            .unwrap_or_default();
+
+        if kind.starts_with("constituency") {
+            constituency = Some(place);
+        } else if kind.starts_with("county") {
+            county = Some(place);
+        } else if woman_rep && county.is_none() {
+            // XXX: woman representatives sit for a whole county, but the site drops the suffix
+            county = Some(place);
+        }
+    }
+
+    (constituency, county)
+}
+
+/// Date of an activity item, written "19th November 2025" on the page.
+pub fn parse_activity_date(text: &str) -> Option<NaiveDate> {
+    let caps = RE_ACTIVITY_DATE.captures(text.trim())?;
+    let day = caps[1].parse().ok()?;
+    let month = parse_month(&caps[2]).ok()?;
+    let year = caps[3].parse().ok()?;
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Start and, unless still serving, end date from a "... from <date> to <date|Present>" term.
+fn parse_term(text: &str) -> Option<(NaiveDate, Option<NaiveDate>)> {
+    let caps = RE_TERM.captures(text)?;
+    let date = |m: usize| {
+        let month = parse_month(caps.get(m)?.as_str()).ok()?;
+        let day = caps.get(m + 1)?.as_str().parse().ok()?;
+        let year = caps.get(m + 2)?.as_str().parse().ok()?;
+        NaiveDate::from_ymd_opt(year, month, day)
+    };
+    Some((date(1)?, date(4)))
 }
 
 pub fn parse_member_profile(html: &str, url: &str) -> Result<MemberProfile, ParseError> {
@@ -903,6 +1769,8 @@ pub fn parse_member_profile(html: &str, url: &str) -> Result<MemberProfile, Pars
         .ok_or_else(|| ParseError::UrlParse("Could not extract slug from URL".to_string()))?
         .to_string();
 
+    let (house, parliament) = parse_profile_path(url).unzip();
+
     let name_sel = Selector::parse("h1.page-heading")?;
     let name = document
         .select(&name_sel)
@@ -916,6 +1784,8 @@ pub fn parse_member_profile(html: &str, url: &str) -> Result<MemberProfile, Pars
         .next()
         .map(|e| normalize_whitespace(&elem_text(e)))
         .filter(|s| !s.is_empty());
+    let education = biography.as_deref().and_then(parse_education);
+    let first_elected = biography.as_deref().and_then(parse_first_elected);
 
     let position_type_sel = Selector::parse("h2.assembly-entry")?;
     let position_type = document
@@ -929,7 +1799,7 @@ pub fn parse_member_profile(html: &str, url: &str) -> Result<MemberProfile, Pars
         .select(&photo_sel)
         .next()
         .and_then(|e| e.value().attr("src"))
-        .map(str::to_string);
+        .map(absolute_url);
 
     let header_two_sel = Selector::parse("h2.header-two")?;
     let parties_heading_sel = Selector::parse("h2.header-two, h2.header-three")?;
@@ -970,6 +1840,11 @@ pub fn parse_member_profile(html: &str, url: &str) -> Result<MemberProfile, Pars
         })
         .unwrap_or_default();
 
+    let (assumed_office, left_office) = positions
+        .iter()
+        .find_map(|p| parse_term(p))
+        .map_or((None, None), |(from, to)| (Some(from), to));
+
     // XXX: (party) first p.elected-post that follows the "Parties and Coalitions" heading
     let party = document
         .select(&parties_heading_sel)
@@ -986,11 +1861,29 @@ pub fn parse_member_profile(html: &str, url: &str) -> Result<MemberProfile, Pars
         .map(|e| normalize_whitespace(&elem_text(e)))
         .filter(|s| !s.is_empty());
 
+    let email_sel = Selector::parse("a[href^='mailto:']")?;
+    let email = contact_link(&document, &email_sel, "mailto:");
+
+    let tel_sel = Selector::parse("a[href^='tel:']")?;
+    let telephone = contact_link(&document, &tel_sel, "tel:");
+
     let committee_sel = Selector::parse("li.committee-item")?;
+    let committee_name_sel = Selector::parse("strong")?;
+    let committee_role_sel = Selector::parse(".committee-role")?;
     let committees = document
         .select(&committee_sel)
-        .map(|e| normalize_whitespace(&elem_text(e)))
-        .filter(|s| !s.is_empty())
+        .filter_map(|e| {
+            let text = normalize_whitespace(&elem_text(e));
+            let name = e
+                .select(&committee_name_sel)
+                .next()
+                .map(|n| normalize_whitespace(&elem_text(n)));
+            let role = e
+                .select(&committee_role_sel)
+                .next()
+                .map(|r| normalize_whitespace(&elem_text(r)));
+            parse_committee(&text, name, role)
+        })
         .collect();
 
     let activity_sel = Selector::parse("div.activity-section p")?;
@@ -1030,11 +1923,19 @@ pub fn parse_member_profile(html: &str, url: &str) -> Result<MemberProfile, Pars
     Ok(MemberProfile {
         name,
         slug,
+        house,
+        parliament,
         photo_url,
         biography,
+        education,
+        first_elected,
         position_type,
         positions,
+        assumed_office,
+        left_office,
         party,
+        email,
+        telephone,
         committees,
         speeches_last_year,
         speeches_total,
@@ -1047,12 +1948,106 @@ pub fn parse_member_profile(html: &str, url: &str) -> Result<MemberProfile, Pars
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
+/// House and parliament of a profile URL shaped like
+/// `/mps-performance/national-assembly/13th-parliament/<slug>/`.
+fn parse_profile_path(url: &str) -> Option<(House, String)> {
+    let caps = RE_PROFILE_PATH.captures(url)?;
+    let house = caps[1].parse::<House>().ok()?;
+    let parliament = caps[2].parse::<Parliament>().ok()?;
+    Some((house, parliament.as_str().to_string()))
+}
 
-    #[test]
+/// The biography's sentences about the member's schooling, e.g. "She holds a Bachelor of Laws
+/// degree from the University of Nairobi."
+fn parse_education(biography: &str) -> Option<String> {
+    let sentences: Vec<&str> = RE_EDUCATION
+        .find_iter(biography)
+        .map(|m| m.as_str().trim())
+        .collect();
+    (!sentences.is_empty()).then(|| sentences.join(" "))
+}
+
+/// The year the member first took a seat, from "first elected in 2013" or "MP since 2017".
+fn parse_first_elected(biography: &str) -> Option<u16> {
+    let caps = RE_FIRST_ELECTED.captures(biography)?;
+    caps.get(1).or_else(|| caps.get(2))?.as_str().parse().ok()
+}
+
+/// Split a committee list item into the committee and the member's role.
+///
+/// Items read "The Chair of the LIAISON committee." or "A member of the APPOINTMENTS
+/// committee.", with the name in `<strong>` and, for office holders, the role in
+/// `.committee-role`; `name` and `role` are those elements' text when present. Plain text items
+/// of the form "Name (Role)" or "Name - Role" are also accepted.
+fn parse_committee(text: &str, name: Option<String>, role: Option<String>) -> Option<Committee> {
+    let phrase = RE_COMMITTEE_PHRASE.captures(text);
+    let (name, role) = match (name.filter(|n| !n.is_empty()), phrase) {
+        (Some(name), phrase) => (name, role.or_else(|| phrase.map(|c| c[1].to_string()))),
+        (None, Some(c)) => (c[2].to_string(), role.or_else(|| Some(c[1].to_string()))),
+        (None, None) => {
+            let text = text.trim_end_matches('.');
+            if let Some((name, rest)) = text.split_once(" (")
+                && let Some(inner) = rest.strip_suffix(')')
+            {
+                (name.to_string(), role.or_else(|| Some(inner.to_string())))
+            } else if let Some((name, dashed)) =
+                text.split_once(" - ").or_else(|| text.split_once(" – "))
+            {
+                (name.to_string(), role.or_else(|| Some(dashed.to_string())))
+            } else {
+                (text.to_string(), role)
+            }
+        }
+    };
+
+    let name = name.trim().to_string();
+    let role = role
+        .map(|r| normalize_committee_role(&r))
+        .filter(|r| !r.is_empty());
+    (!name.is_empty()).then_some(Committee { name, role })
+}
+
+/// "The Chair" → "Chair", "member" → "Member".
+fn normalize_committee_role(role: &str) -> String {
+    let role = role.trim();
+    let role = ["the ", "a ", "an "]
+        .iter()
+        .find_map(|article| {
+            role.get(..article.len())
+                .filter(|p| p.eq_ignore_ascii_case(article))
+                .map(|_| &role[article.len()..])
+        })
+        .unwrap_or(role)
+        .trim();
+    let mut chars = role.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// XXX: every page footer carries Mzalendo's own phone and email, so only links outside the
+// footer belong to the member.
+fn contact_link(document: &Html, selector: &Selector, scheme: &str) -> Option<String> {
+    document
+        .select(selector)
+        .find(|e| {
+            !e.ancestors().filter_map(ElementRef::wrap).any(|a| {
+                a.value().name() == "footer" || a.value().classes().any(|c| c.contains("footer"))
+            })
+        })
+        .and_then(|e| e.value().attr("href"))
+        .and_then(|h| h.trim_start_matches(scheme).split('?').next())
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
     fn test_parse_page_info_hansard_list() {
         let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
             .expect("Failed to read fixture");
@@ -1077,12 +2072,42 @@ mod tests {
         assert_eq!(total, 8);
     }
 
+    #[test]
+    fn test_parse_page_info_single_page() {
+        let html = fs::read_to_string("fixtures/current/Hansard_list_single_page")
+            .expect("Failed to read fixture");
+
+        assert_eq!(parse_page_info(&html).unwrap(), None);
+
+        let (listings, _) = parse_hansard_list(&html, None).expect("Failed to parse hansard list");
+        assert!(!listings.is_empty(), "Should still parse listings");
+    }
+
+    #[test]
+    fn test_is_not_found_page() {
+        assert!(is_not_found_page(
+            "<html><head><title>Page Not Found | Mzalendo</title></head><body></body></html>"
+        ));
+        assert!(is_not_found_page(
+            "<html><body><h1 class=\"title\">404 - Page not found</h1></body></html>"
+        ));
+
+        for fixture in [
+            "fixtures/current/national_assembly_hansard_sitting",
+            "fixtures/current/Hansard_list_paginated",
+            "fixtures/current/national_assembly_10th_parliament_empty",
+        ] {
+            let html = fs::read_to_string(fixture).expect("Failed to read fixture");
+            assert!(!is_not_found_page(&html), "{fixture} is not a 404 page");
+        }
+    }
+
     #[test]
     fn test_parse_hansard_list_from_fixture() {
         let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
             .expect("Failed to read fixture");
 
-        let listings = parse_hansard_list(&html, None).expect("Failed to parse hansard list");
+        let (listings, _) = parse_hansard_list(&html, None).expect("Failed to parse hansard list");
 
         assert!(!listings.is_empty(), "Should parse at least one listing");
         println!("Parsed {} listings", listings.len());
@@ -1109,7 +2134,7 @@ mod tests {
         let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
             .expect("Failed to read fixture");
 
-        let listings = parse_hansard_list(&html, Some(House::NationalAssembly))
+        let (listings, _) = parse_hansard_list(&html, Some(House::NationalAssembly))
             .expect("Failed to parse hansard list");
 
         assert!(!listings.is_empty(), "Should have listings");
@@ -1124,7 +2149,7 @@ mod tests {
         let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
             .expect("Failed to read fixture");
 
-        let listings =
+        let (listings, _) =
             parse_hansard_list(&html, Some(House::Senate)).expect("Failed to parse hansard list");
 
         assert!(!listings.is_empty(), "Should have listings");
@@ -1139,11 +2164,11 @@ mod tests {
         let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
             .expect("Failed to read fixture");
 
-        let na = parse_hansard_list(&html, Some(House::NationalAssembly))
+        let (na, _) = parse_hansard_list(&html, Some(House::NationalAssembly))
             .expect("Failed to parse NA listings");
-        let senate = parse_hansard_list(&html, Some(House::Senate))
+        let (senate, _) = parse_hansard_list(&html, Some(House::Senate))
             .expect("Failed to parse Senate listings");
-        let all = parse_hansard_list(&html, None).expect("Failed to parse all listings");
+        let (all, _) = parse_hansard_list(&html, None).expect("Failed to parse all listings");
 
         assert_eq!(
             na.len() + senate.len(),
@@ -1157,7 +2182,7 @@ mod tests {
         let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
             .expect("Failed to read fixture");
 
-        let listings = parse_hansard_list(&html, None).expect("Failed to parse");
+        let (listings, _) = parse_hansard_list(&html, None).expect("Failed to parse");
 
         let feb12 = listings
             .iter()
@@ -1171,6 +2196,39 @@ mod tests {
         assert!(feb12.url.contains("2438"), "URL should contain sitting ID");
     }
 
+    #[test]
+    fn test_parse_hansard_list_reports_skips() {
+        let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
+            .expect("Failed to read fixture");
+        let (listings, report) = parse_hansard_list(&html, None).expect("Failed to parse");
+        assert_eq!(report.parsed, listings.len());
+        assert!(report.skipped.is_empty(), "{:?}", report.skipped);
+
+        let html = r#"
+            <div class="split-docs">
+              <div class="hansard-document"><h3><a href="/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/">Thursday, 12th February 2026 - Afternoon Sitting</a></h3></div>
+              <div class="hansard-document"><h3><a href="/democracy-tools/hansard/report-2437/">Hansard Report</a></h3></div>
+              <div class="hansard-document"><h3><a>Wednesday, 11th February 2026 - Afternoon Sitting</a></h3></div>
+              <div class="hansard-document"><h3><a href="/democracy-tools/hansard/2436/"> </a></h3></div>
+            </div>"#;
+        let (listings, report) = parse_hansard_list(html, None).expect("Failed to parse");
+        assert_eq!(listings.len(), 1);
+        assert_eq!(report.parsed, 1);
+        assert_eq!(report.skipped.len(), 3);
+        assert!(report.skipped[0].starts_with("listing 'Hansard Report'"));
+        assert_eq!(
+            report.skipped[1],
+            "listing 'Wednesday, 11th February 2026 - Afternoon Sitting' without a link"
+        );
+        assert_eq!(
+            report.skipped[2],
+            "listing https://mzalendo.com/democracy-tools/hansard/2436/ without a title"
+        );
+
+        let (_, report) = parse_hansard_list(html, Some(House::Senate)).expect("Failed to parse");
+        assert_eq!(report, ParseReport::default(), "Other houses are not skips");
+    }
+
     #[test]
     fn test_parse_national_assembly_sitting() {
         let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
@@ -1184,7 +2242,14 @@ mod tests {
         assert_eq!(sitting.session_type, "Afternoon Sitting");
         assert!(sitting.time.is_some(), "Should have a time");
         assert!(sitting.summary.is_some(), "Should have a summary");
+        assert_eq!(sitting.sentiment.as_deref(), Some("Mixed"));
+        assert_eq!(sitting.sentiment_label, Some(Sentiment::Mixed));
         assert!(sitting.pdf_url.is_some(), "Should have a PDF URL");
+        assert_eq!(
+            sitting.parliament_number.as_deref(),
+            Some("13th-parliament")
+        );
+        assert_eq!(sitting.session_number, None);
         assert!(
             !sitting.sections.is_empty(),
             "Should have at least one section"
@@ -1213,17 +2278,7 @@ mod tests {
             "New-format sitting should have sections"
         );
 
-        let all_contributions: Vec<_> = sitting
-            .sections
-            .iter()
-            .flat_map(|s| {
-                s.contributions.iter().chain(
-                    s.subsections
-                        .iter()
-                        .flat_map(|sub| sub.contributions.iter()),
-                )
-            })
-            .collect();
+        let all_contributions: Vec<_> = sitting.all_contributions().collect();
         assert!(
             !all_contributions.is_empty(),
             "New-format sitting should have contributions"
@@ -1239,6 +2294,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_sitting_division() {
+        let html =
+            fs::read_to_string("fixtures/current/national_assembly_hansard_sitting_division")
+                .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/tuesday-24th-june-2025-afternoon-sitting-2310/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        assert_eq!(sitting.divisions.len(), 1, "Should parse one division");
+        let division = &sitting.divisions[0];
+        assert_eq!(division.ayes, 195);
+        assert_eq!(division.noes, 106);
+        assert_eq!(division.abstentions, 3);
+        assert_eq!(division.result, "The Ayes have it");
+        assert_eq!(division.ayes_members.as_ref().map(Vec::len), Some(3));
+        assert_eq!(division.noes_members.as_ref().map(Vec::len), Some(2));
+        assert_eq!(
+            division.abstentions_members,
+            Some(vec!["Hon. Peter Kaluma (Homa Bay Town, ODM)".to_string()])
+        );
+
+        let speakers: Vec<&str> = sitting
+            .all_contributions()
+            .map(|c| c.speaker_name.as_str())
+            .collect();
+        assert!(
+            !speakers.iter().any(|s| *s == "AYES" || *s == "NOES"),
+            "Division member lists should not be parsed as contributions, got: {:?}",
+            speakers
+        );
+    }
+
+    #[test]
+    fn test_parse_sitting_without_division() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        assert!(sitting.divisions.is_empty(), "Should have no divisions");
+    }
+
     #[test]
     fn test_parse_senate_sitting() {
         let html = fs::read_to_string("fixtures/current/senate_hansard_sitting")
@@ -1260,21 +2359,246 @@ mod tests {
 
         let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
 
-        let with_url = sitting
-            .sections
-            .iter()
-            .flat_map(|s| {
-                s.contributions.iter().chain(
-                    s.subsections
-                        .iter()
-                        .flat_map(|sub| sub.contributions.iter()),
-                )
-            })
-            .any(|c| c.speaker_url.is_some());
+        let with_url = sitting.all_contributions().any(|c| c.speaker_url.is_some());
         assert!(
             with_url,
             "Should have at least one contribution with a speaker URL"
         );
+
+        let relative = sitting
+            .all_contributions()
+            .filter_map(|c| c.speaker_url.as_deref())
+            .find(|u| !u.starts_with("https://mzalendo.com/"));
+        assert_eq!(relative, None, "Speaker URLs should be absolute");
+    }
+
+    #[test]
+    fn test_parse_sitting_dedupes_procedural_notes() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        let welcome = sitting
+            .all_contributions()
+            .find(|c| c.content.starts_with("Thank you. Before the next Order"))
+            .expect("Should find the Speaker welcoming schools");
+        assert_eq!(welcome.procedural_notes, ["(Applause)"]);
+    }
+
+    #[test]
+    fn test_parse_contribution_at() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let at = |anchor| parse_contribution_at(&html, url, anchor).expect("Failed to parse");
+
+        let speech = at("chunk-710525").expect("Contributor line should mark a contribution");
+        assert_eq!(speech.speaker_name, "Hon. Peter Nabulindo (Matungu, ODM)");
+        assert!(speech.content.starts_with("I support this Bill."));
+        assert!(
+            speech
+                .content
+                .ends_with("Thank you very much, Hon. Temporary Speaker.")
+        );
+
+        let quorum = at("chunk-710231").expect("Speech should mark a contribution");
+        assert_eq!(quorum.speaker_name, "Hon. Speaker");
+        assert_eq!(
+            quorum.content,
+            "Hon. Members, we now have quorum to transact business."
+        );
+
+        assert_eq!(
+            at("chunk-710237"),
+            None,
+            "Section headings mark no contribution"
+        );
+        assert_eq!(at("chunk-1"), None);
+
+        let html =
+            fs::read_to_string("fixtures/current/national_assembly_hansard_sitting_new_format")
+                .expect("Failed to read new-format fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-19th-february-2026-afternoon-sitting-2440/";
+        let speech = parse_contribution_at(&html, url, "chunk-710967")
+            .expect("Failed to parse")
+            .expect("Chunk wrapper should mark a contribution");
+        assert_eq!(
+            speech.speaker_url.as_deref(),
+            Some(
+                "https://mzalendo.com/mps-performance/national-assembly/13th-parliament/samuel-kiprono-chepkonga/"
+            )
+        );
+        assert!(
+            speech
+                .content
+                .starts_with("The only sad thing she left me with")
+        );
+    }
+
+    #[test]
+    fn test_parse_sitting_interjections() {
+        let html =
+            fs::read_to_string("fixtures/current/national_assembly_hansard_sitting_interjection")
+                .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/tuesday-24th-june-2025-afternoon-sitting-2310/";
+
+        let folded = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+        let contributions = &folded.sections[0].subsections[0].contributions;
+        assert_eq!(contributions.len(), 3);
+        assert!(
+            contributions[0]
+                .content
+                .contains("(Hon. Junet Mohamed): Point of order!")
+        );
+
+        let sitting = parse_hansard_sitting_with(
+            &html,
+            url,
+            ParseConfig {
+                split_interjections: true,
+                ..ParseConfig::default()
+            },
+        )
+        .expect("Failed to parse sitting");
+        let contributions = &sitting.sections[0].subsections[0].contributions;
+        let speakers: Vec<&str> = contributions
+            .iter()
+            .map(|c| c.speaker_name.as_str())
+            .collect();
+        assert_eq!(
+            speakers,
+            [
+                "Hon. Kuria Kimani",
+                "Hon. Junet Mohamed",
+                "Hon. Kuria Kimani",
+                "Hon. Speaker",
+                "Hon. Junet Mohamed",
+            ]
+        );
+
+        let mover = &contributions[0];
+        assert!(mover.content.starts_with("Hon. Speaker, I beg to move"));
+        assert!(mover.content.ends_with("the Excise Duty Act."));
+        assert_eq!(mover.procedural_notes, ["(Loud consultations)"]);
+
+        let interjection = &contributions[1];
+        assert_eq!(interjection.content, "Point of order!");
+        assert_eq!(interjection.speaker_raw, "(Hon. Junet Mohamed)");
+        assert_eq!(interjection.speaker_url, None);
+        assert!(interjection.procedural_notes.is_empty());
+
+        let resumed = &contributions[2];
+        assert!(
+            resumed
+                .content
+                .starts_with("I will take the point of order")
+        );
+        assert_eq!(resumed.speaker_url, mover.speaker_url);
+        assert_eq!(resumed.contribution_kind, mover.contribution_kind);
+    }
+
+    #[test]
+    fn test_parse_sitting_parliament_and_session() {
+        let url = "https://mzalendo.com/democracy-tools/hansard/tuesday-24th-june-2025-afternoon-sitting-2310/";
+        let html = r#"
+            <span class="house">National Assembly</span>
+            <header class="hansard-header">
+                <h1 class="parliament-title">THE PARLIAMENT OF KENYA</h1>
+                <h1 class="document-title">THE HANSARD</h1>
+            </header>
+            <article class="hansard-document">
+                <h2 class="major-section-header">BILLS</h2>
+                <div class="contributor-name"><a href="/mps-performance/national-assembly/12th-parliament/kuria-kimani/">Hon. Kuria Kimani</a></div>
+                <div class="speech-content"><p>I beg to move.</p></div>
+            </article>
+        "#;
+        let sitting = parse_hansard_sitting(html, url).expect("Failed to parse sitting");
+        assert_eq!(
+            sitting.parliament_number.as_deref(),
+            Some("12th-parliament")
+        );
+        assert_eq!(sitting.session_number, None);
+
+        let html = r#"
+            <span class="house">Senate</span>
+            <header class="hansard-header">
+                <h1 class="parliament-title">THE PARLIAMENT OF KENYA</h1>
+                <h2>13th Parliament - Fourth Session</h2>
+            </header>
+            <article class="hansard-document"></article>
+        "#;
+        let sitting = parse_hansard_sitting(html, url).expect("Failed to parse sitting");
+        assert_eq!(
+            sitting.parliament_number.as_deref(),
+            Some("13th-parliament")
+        );
+        assert_eq!(sitting.session_number.as_deref(), Some("Fourth Session"));
+
+        let html =
+            r#"<span class="house">Senate</span><article class="hansard-document"></article>"#;
+        let sitting = parse_hansard_sitting(html, url).expect("Failed to parse sitting");
+        assert_eq!(sitting.parliament_number, None);
+    }
+
+    #[test]
+    fn test_parse_sitting_speaker_roles() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+        let contributions: Vec<&Contribution> = sitting.all_contributions().collect();
+
+        let chair = contributions
+            .iter()
+            .find(|c| c.speaker_raw == "The Temporary Speaker (Hon. Omboko Milemba)")
+            .expect("Should find the Temporary Speaker");
+        assert_eq!(chair.speaker_name, "Hon. Omboko Milemba");
+        assert_eq!(chair.speaker_role.as_deref(), Some("The Temporary Speaker"));
+        assert_eq!(chair.contribution_kind, None);
+
+        let nyamai = contributions
+            .iter()
+            .find(|c| c.speaker_raw == "The Temporary Speaker (Hon (Dr.) Rachael Nyamai)")
+            .expect("Should find the Temporary Speaker without a dotted honorific");
+        assert_eq!(nyamai.speaker_name, "Hon (Dr.) Rachael Nyamai");
+
+        let member = contributions
+            .iter()
+            .find(|c| c.speaker_role.is_none() && c.speaker_name.ends_with(", UDA)"))
+            .expect("Should find a Member with a constituency and party");
+        assert_eq!(member.speaker_name, member.speaker_raw);
+
+        assert_eq!(
+            split_speaker_role("Hon. Tom Kajwang’ (The Deputy Speaker)".to_string()),
+            (
+                "Hon. Tom Kajwang’".to_string(),
+                Some("The Deputy Speaker".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_absolute_url() {
+        assert_eq!(
+            absolute_url("/mps-performance/senate/13th-parliament/murungi-kathuri/"),
+            "https://mzalendo.com/mps-performance/senate/13th-parliament/murungi-kathuri/"
+        );
+        assert_eq!(
+            absolute_url("https://mzalendo.com/democracy-tools/hansard/"),
+            "https://mzalendo.com/democracy-tools/hansard/"
+        );
+        assert_eq!(
+            absolute_url("http://info.mzalendo.com/hansard/"),
+            "http://info.mzalendo.com/hansard/"
+        );
+        assert_eq!(
+            absolute_url("//cdn.mzalendo.com/a.jpg"),
+            "https://cdn.mzalendo.com/a.jpg"
+        );
+        assert_eq!(absolute_url(""), "");
     }
 
     #[test]
@@ -1378,13 +2702,345 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_sitting_bill_referred_to_committee() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        let bills_section = sitting
+            .sections
+            .iter()
+            .find(|s| s.section_type == "BILLS" || s.section_type == "BILL")
+            .expect("Should have a BILLS section");
+        let health = bills_section
+            .subsections
+            .iter()
+            .find(|s| s.title.contains("HEALTH"))
+            .expect("Should have the Health Amendment Bill subsection");
+        assert_eq!(
+            health.referred_to_committee.as_deref(),
+            Some("Committee of the whole House")
+        );
+
+        let motions = sitting
+            .sections
+            .iter()
+            .find(|s| s.section_type == "NOTICES OF MOTIONS")
+            .expect("Should have a NOTICES OF MOTIONS section");
+        assert!(
+            motions
+                .subsections
+                .iter()
+                .all(|s| s.referred_to_committee.is_none()),
+            "Non-bill subsections should not carry a referral"
+        );
+    }
+
+    #[test]
+    fn test_parse_sitting_house_business_statement() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        let subsections: Vec<&HansardSubsection> = sitting
+            .sections
+            .iter()
+            .flat_map(|s| s.subsections.iter())
+            .collect();
+        let business = subsections
+            .iter()
+            .find(|s| s.title.starts_with("BUSINESS FOR THE WEEK"))
+            .expect("Should have the business statement");
+        assert_eq!(business.statement_kind, Some(StatementKind::HouseBusiness));
+        assert!(
+            business.contributions[0]
+                .speaker_name
+                .contains("Kimani Ichung’wah")
+        );
+
+        let requests = subsections
+            .iter()
+            .find(|s| s.title == "REQUESTS FOR STATEMENTS")
+            .expect("Should have requests for statements");
+        assert_eq!(requests.statement_kind, Some(StatementKind::Other));
+
+        assert!(
+            subsections
+                .iter()
+                .filter(|s| s.title.contains("BILL"))
+                .all(|s| s.statement_kind.is_none()),
+            "Subsections outside statement sections should not be classified"
+        );
+    }
+
+    #[test]
+    fn test_classify_statement() {
+        let subsection = |title: &str, speaker_name: &str, content: &str| HansardSubsection {
+            title: title.to_string(),
+            contributions: vec![Contribution {
+                speaker_name: speaker_name.to_string(),
+                speaker_raw: speaker_name.to_string(),
+                speaker_role: None,
+                speaker_url: None,
+                content: content.to_string(),
+                procedural_notes: vec![],
+                contribution_kind: None,
+            }],
+            referred_to_committee: None,
+            statement_kind: None,
+        };
+
+        assert_eq!(
+            classify_statement(
+                "STATEMENTS",
+                &subsection(
+                    "BUSINESS OF THE SENATE",
+                    "The Senate Majority Leader (Sen. Cheruiyot)",
+                    "Mr. Speaker, Sir, I rise to give the business of the week."
+                )
+            ),
+            Some(StatementKind::HouseBusiness)
+        );
+        assert_eq!(
+            classify_statement(
+                "STATEMENTS",
+                &subsection(
+                    "BUSINESS FOR THE WEEK",
+                    "The Leader of the Minority Party (Hon. Junet Mohamed)",
+                    "Hon. Speaker, I rise to make a Statement."
+                )
+            ),
+            Some(StatementKind::HouseBusiness)
+        );
+        assert_eq!(
+            classify_statement(
+                "PERSONAL STATEMENT",
+                &subsection(
+                    "ALLEGED DEFAMATION",
+                    "Hon. Peter Kaluma (Homa Bay Town, ODM)",
+                    "Hon. Speaker, I rise to make a personal statement."
+                )
+            ),
+            Some(StatementKind::PersonalStatement)
+        );
+        assert_eq!(
+            classify_statement(
+                "STATEMENTS",
+                &subsection(
+                    "MINISTERIAL STATEMENT ON DROUGHT MITIGATION",
+                    "Hon. Cabinet Secretary",
+                    "Hon. Speaker, the Ministry has..."
+                )
+            ),
+            Some(StatementKind::MinisterialStatement)
+        );
+        assert_eq!(
+            classify_statement(
+                "BILLS",
+                &subsection(
+                    "THE FINANCE BILL",
+                    "Hon. Kuria Kimani (Molo, UDA)",
+                    "Hon. Speaker, I beg to move."
+                )
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_sitting_questions_status() {
+        let html =
+            fs::read_to_string("fixtures/current/national_assembly_hansard_sitting_questions")
+                .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/wednesday-25th-june-2025-morning-sitting-2311/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        assert_eq!(
+            sitting.questions_status,
+            vec![
+                QuestionStatusEntry {
+                    number: 91,
+                    subject: Some("UPGRADING OF KAPSABET-NANDI HILLS ROAD".to_string()),
+                    reached: true,
+                },
+                QuestionStatusEntry {
+                    number: 92,
+                    subject: Some("DELAYED DISBURSEMENT OF NG-CDF FUNDS".to_string()),
+                    reached: true,
+                },
+                QuestionStatusEntry {
+                    number: 93,
+                    subject: None,
+                    reached: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_sitting_contribution_kinds() {
+        let html =
+            fs::read_to_string("fixtures/current/national_assembly_hansard_sitting_questions")
+                .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/wednesday-25th-june-2025-morning-sitting-2311/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        let kinds: Vec<(&str, Option<ContributionKind>)> = sitting
+            .sections
+            .iter()
+            .find(|s| s.section_type == "QUESTIONS AND STATEMENTS")
+            .expect("Should have a QUESTIONS AND STATEMENTS section")
+            .all_contributions()
+            .map(|c| (c.speaker_name.as_str(), c.contribution_kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (
+                    "Hon. Bernard Kitur (Nandi Hills, UDA)",
+                    Some(ContributionKind::Question)
+                ),
+                ("Hon. Speaker", None),
+                (
+                    "Hon. Rose Museo (Makueni, WDM)",
+                    Some(ContributionKind::Question)
+                ),
+                ("Hon. Speaker", None),
+            ]
+        );
+
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        let bills = sitting
+            .sections
+            .iter()
+            .find(|s| s.section_type == "BILLS" || s.section_type == "BILL")
+            .expect("Should have a BILLS section");
+        assert!(
+            bills
+                .all_contributions()
+                .filter(|c| !c.speaker_name.is_empty()
+                    && !speaker_matches(c, &RE_PRESIDING_OFFICER))
+                .all(|c| c.contribution_kind == Some(ContributionKind::Debate))
+        );
+    }
+
+    #[test]
+    fn test_contribution_kind_answer() {
+        let contribution = |speaker_name: &str| Contribution {
+            speaker_name: speaker_name.to_string(),
+            speaker_raw: speaker_name.to_string(),
+            speaker_role: None,
+            speaker_url: None,
+            content: String::new(),
+            procedural_notes: vec![],
+            contribution_kind: None,
+        };
+
+        let cs =
+            contribution("The Cabinet Secretary for Roads and Transport (Hon. Davis Chirchir)");
+        assert_eq!(
+            contribution_kind(&cs, None, true),
+            Some(ContributionKind::Answer)
+        );
+        assert_eq!(
+            contribution_kind(
+                &contribution("Hon. Temporary Speaker (Hon. Martha Wangari)"),
+                None,
+                true
+            ),
+            None
+        );
+        assert_eq!(
+            contribution_kind(&cs, Some(ContributionKind::Statement), false),
+            Some(ContributionKind::Statement)
+        );
+
+        let (name, role) = split_speaker_role(cs.speaker_name.clone());
+        let split = Contribution {
+            speaker_name: name,
+            speaker_role: role,
+            ..cs
+        };
+        assert_eq!(split.speaker_name, "Hon. Davis Chirchir");
+        assert_eq!(
+            split.to_string(),
+            "Hon. Davis Chirchir (The Cabinet Secretary for Roads and Transport)"
+        );
+        assert_eq!(
+            contribution_kind(&split, None, true),
+            Some(ContributionKind::Answer)
+        );
+    }
+
+    #[test]
+    fn test_parse_sitting_without_numbered_questions() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = parse_hansard_sitting(&html, url).expect("Failed to parse sitting");
+
+        assert!(sitting.questions_status.is_empty());
+    }
+
+    #[test]
+    fn test_parse_referred_committee() {
+        let subsection = |text: &str| HansardSubsection {
+            title: "THE PUBLIC HEALTH (AMENDMENT) BILL".to_string(),
+            contributions: vec![Contribution {
+                speaker_name: "Hon. Speaker".to_string(),
+                speaker_raw: "Hon. Speaker".to_string(),
+                speaker_role: None,
+                speaker_url: None,
+                content: "Next Order.".to_string(),
+                procedural_notes: vec![text.to_string()],
+                contribution_kind: None,
+            }],
+            referred_to_committee: None,
+            statement_kind: None,
+        };
+
+        assert_eq!(
+            parse_referred_committee(&subsection(
+                "(The Bill was read a First Time and referred to the Departmental Committee on Health)"
+            ))
+            .as_deref(),
+            Some("Departmental Committee on Health")
+        );
+        assert_eq!(
+            parse_referred_committee(&subsection(
+                "(The Bill was read a First Time and referred to the Committee on Delegated Legislation for scrutiny)"
+            ))
+            .as_deref(),
+            Some("Committee on Delegated Legislation")
+        );
+        assert_eq!(
+            parse_referred_committee(&subsection(
+                "(The Bills were read a First Time and referred to relevant Committees)"
+            )),
+            None
+        );
+    }
+
     #[test]
     fn test_parse_member_list() {
         let html =
             fs::read_to_string("fixtures/current/national_assembly_13th_parliament_paginated")
                 .expect("Failed to read fixture");
 
-        let members =
+        let (members, _) =
             parse_member_list(&html, House::NationalAssembly).expect("Failed to parse members");
 
         assert!(!members.is_empty(), "Should parse at least one member");
@@ -1401,15 +3057,91 @@ mod tests {
             .expect("Should find the Speaker");
         assert!(speaker.role.is_some(), "Speaker should have a role");
 
+        let ichungwah = members
+            .iter()
+            .find(|m| m.name == "Anthony Kimani Ichung'wah")
+            .expect("Should find the Majority Leader");
+        assert_eq!(
+            ichungwah.role.as_deref(),
+            Some("Majority Leader / MNA for Kikuyu constituency")
+        );
+        assert_eq!(ichungwah.constituency.as_deref(), Some("Kikuyu"));
+        assert_eq!(ichungwah.county, None);
+        assert!(
+            members.iter().all(|m| m
+                .photo_url
+                .as_deref()
+                .is_some_and(|u| u.starts_with("https://"))),
+            "Every member should have an absolute photo URL"
+        );
+
+        let woman_rep = members
+            .iter()
+            .find(|m| m.name == "Boss Gladys Jepkosgei")
+            .expect("Should find the Deputy Speaker");
+        assert_eq!(woman_rep.constituency, None);
+        assert_eq!(woman_rep.county.as_deref(), Some("Uasin Gishu"));
+
+        let nominated = members
+            .iter()
+            .filter(|m| m.constituency.is_none() && m.county.is_none())
+            .count();
+        assert_eq!(nominated, 3, "Speaker and two nominated members");
+
         println!("Parsed {} members", members.len());
     }
 
+    #[test]
+    fn test_parse_member_list_constituency_and_county() {
+        let html = r#"
+            <a href="/mps-performance/national-assembly/13th-parliament/jane-doe/" class="members-list--item">
+              <div class="members-list--info">
+                <div class="members-list--name">Jane Doe</div>
+                <div class="members-list--representation">
+                  MNA for <strong>Kikuyu</strong> constituency, <strong>Kiambu</strong> county
+                </div>
+              </div>
+            </a>"#;
+
+        let (members, _) = parse_member_list(html, House::NationalAssembly).unwrap();
+
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].constituency.as_deref(), Some("Kikuyu"));
+        assert_eq!(members[0].county.as_deref(), Some("Kiambu"));
+        assert_eq!(members[0].photo_url, None);
+    }
+
+    #[test]
+    fn test_parse_member_list_lazy_photo() {
+        let html = r#"
+            <a href="/mps-performance/senate/13th-parliament/jane-doe/" class="senators-list--item">
+              <img class="senators-list--image" src="data:image/gif;base64,R0lGODlhAQABAAAAACw="
+                   data-src="/media/images-rendered/Jane_Doe.min-200x240.jpg" />
+              <div class="senators-list--name">Jane Doe</div>
+            </a>
+            <a href="/mps-performance/senate/13th-parliament/john-doe/" class="senators-list--item">
+              <img class="senators-list--image" src="//cdn.mzalendo.com/John_Doe.jpg" />
+              <div class="senators-list--name">John Doe</div>
+            </a>"#;
+
+        let (members, _) = parse_member_list(html, House::Senate).unwrap();
+
+        assert_eq!(
+            members[0].photo_url.as_deref(),
+            Some("https://mzalendo.com/media/images-rendered/Jane_Doe.min-200x240.jpg")
+        );
+        assert_eq!(
+            members[1].photo_url.as_deref(),
+            Some("https://cdn.mzalendo.com/John_Doe.jpg")
+        );
+    }
+
     #[test]
     fn test_parse_senate_member_list() {
         let html = fs::read_to_string("fixtures/current/senate_13th_parliament_paginated")
             .expect("Failed to read fixture");
 
-        let members =
+        let (members, _) =
             parse_member_list(&html, House::Senate).expect("Failed to parse senate members");
 
         assert!(!members.is_empty(), "Should parse at least one senator");
@@ -1424,9 +3156,58 @@ mod tests {
             .expect("Should find the Speaker");
         assert!(speaker.role.is_some(), "Speaker should have a role");
 
+        let kathuri = members
+            .iter()
+            .find(|m| m.name == "Murungi Kathuri")
+            .expect("Should find the Deputy Speaker");
+        assert_eq!(kathuri.role.as_deref(), Some("Deputy Speaker"));
+        assert_eq!(
+            kathuri.url,
+            "https://mzalendo.com/mps-performance/senate/13th-parliament/murungi-kathuri/"
+        );
+        assert_eq!(kathuri.county.as_deref(), Some("Meru"));
+        assert_eq!(kathuri.constituency, None);
+        assert_eq!(
+            kathuri.photo_url.as_deref(),
+            Some(
+                "https://mzalendo.com/media/images-rendered/Murungi_Kathuri_L6RS1ZN.min-200x240.jpg"
+            )
+        );
+
+        let okenyuri = members
+            .iter()
+            .find(|m| m.name == "Esther Anyieni Okenyuri")
+            .expect("Should find a nominated senator");
+        assert_eq!(okenyuri.county, None, "Nominated senators have no county");
+
         println!("Parsed {} senators", members.len());
     }
 
+    #[test]
+    fn test_parse_profile_path() {
+        assert_eq!(
+            parse_profile_path(
+                "https://mzalendo.com/mps-performance/senate/12th-parliament/murungi-kathuri/"
+            ),
+            Some((House::Senate, "12th-parliament".to_string()))
+        );
+        assert_eq!(
+            parse_profile_path(
+                "/mps-performance/national-assembly/13th-parliament/boss-gladys-jepkosgei?contributions_page=2"
+            ),
+            Some((House::NationalAssembly, "13th-parliament".to_string()))
+        );
+        assert_eq!(
+            parse_profile_path("/mps-performance/county-assembly/13th-parliament/jane-doe/"),
+            None
+        );
+        assert_eq!(
+            parse_profile_path("/mps-performance/senate/latest/jane-doe/"),
+            None
+        );
+        assert_eq!(parse_profile_path("boss-gladys-jepkosgei"), None);
+    }
+
     #[test]
     fn test_parse_member_profile() {
         let html = fs::read_to_string(
@@ -1439,10 +3220,26 @@ mod tests {
 
         assert_eq!(profile.name, "Boss Gladys Jepkosgei");
         assert_eq!(profile.slug, "boss-gladys-jepkosgei");
+        assert_eq!(profile.house, Some(House::NationalAssembly));
+        assert_eq!(profile.parliament.as_deref(), Some("13th-parliament"));
         assert!(profile.biography.is_some(), "Should have biography");
+        assert_eq!(profile.first_elected, Some(2017));
+        assert_eq!(profile.education, None);
         assert!(!profile.positions.is_empty(), "Should have positions");
         assert!(profile.party.is_some(), "Should have party");
         assert!(!profile.committees.is_empty(), "Should have committees");
+        assert_eq!(
+            profile.committees[0],
+            Committee {
+                name: "LIAISON".to_string(),
+                role: Some("Chair".to_string()),
+            }
+        );
+        assert_eq!(profile.committees[0].to_string(), "LIAISON (Chair)");
+        assert!(profile.committees.contains(&Committee {
+            name: "JUSTICE AND LEGAL AFFAIRS".to_string(),
+            role: Some("Member".to_string()),
+        }));
         assert_eq!(profile.speeches_last_year, Some(514));
         assert_eq!(profile.speeches_total, Some(675));
         assert_eq!(profile.bills_total, Some(8));
@@ -1482,6 +3279,11 @@ mod tests {
         assert!(!items.is_empty(), "Should parse at least one activity item");
         for item in &items {
             assert!(!item.date.is_empty(), "Date should not be empty");
+            assert!(
+                item.date_parsed.is_some(),
+                "Date should parse: {}",
+                item.date
+            );
             assert!(!item.topic.is_empty(), "Topic should not be empty");
             assert!(!item.url.is_empty(), "URL should not be empty");
             assert!(
@@ -1493,10 +3295,73 @@ mod tests {
                 "sitting_url should have no fragment"
             );
         }
+        assert!(
+            items
+                .iter()
+                .any(|i| i.date_parsed == NaiveDate::from_ymd_opt(2025, 10, 1))
+        );
+        assert_eq!(parse_activity_date("Yesterday"), None);
+        assert_eq!(parse_activity_date("31st February 2025"), None);
         println!("Parsed {} activity items", items.len());
         println!("First: {:#?}", items[0]);
     }
 
+    #[test]
+    fn test_strict_mode_rejects_unknown_heading() {
+        let html = r#"
+            <span class="house">National Assembly</span>
+            <article class="hansard-document">
+            <h2 class="major-section-header">PAPERS</h2>
+            <h3 class="agenda-item-header">THE ANNUAL REPORT OF THE AUDITOR-GENERAL</h3>
+            <div class="contributor-name">Hon. Speaker</div>
+            <div class="speech-content"><p>Next Order.</p></div>
+            </article>
+        "#;
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = parse_hansard_sitting(html, url).expect("Lenient mode should succeed");
+        assert_eq!(sitting.sections.len(), 1);
+        assert_eq!(sitting.sections[0].contributions.len(), 1);
+
+        let err = parse_hansard_sitting_with(
+            html,
+            url,
+            ParseConfig {
+                strict: true,
+                ..ParseConfig::default()
+            },
+        )
+        .expect_err("Strict mode should reject the unknown heading");
+        let ParseError::UnexpectedStructure(message) = err else {
+            panic!("Expected UnexpectedStructure, got {err:?}");
+        };
+        assert!(message.contains("agenda-item-header"));
+    }
+
+    #[test]
+    fn test_strict_mode_accepts_fixtures() {
+        for fixture in [
+            "national_assembly_hansard_sitting",
+            "national_assembly_hansard_sitting_new_format",
+            "national_assembly_hansard_sitting_division",
+            "senate_hansard_sitting",
+        ] {
+            let html = fs::read_to_string(format!("fixtures/current/{fixture}"))
+                .expect("Failed to read fixture");
+            let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+            parse_hansard_sitting_with(
+                &html,
+                url,
+                ParseConfig {
+                    strict: true,
+                    ..ParseConfig::default()
+                },
+            )
+            .unwrap_or_else(|e| panic!("{fixture} should parse strictly: {e}"));
+        }
+    }
+
     #[test]
     fn test_parse_member_profile_activity() {
         let html = fs::read_to_string(
@@ -1509,6 +3374,124 @@ mod tests {
 
         assert!(!profile.activity.is_empty(), "Should have activity items");
         assert_eq!(profile.activity_pages, 11);
+        assert_eq!(profile.assumed_office, NaiveDate::from_ymd_opt(2022, 9, 8));
+        assert_eq!(profile.left_office, None);
+    }
+
+    #[test]
+    fn test_parse_member_profile_by_election_term() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_by_election_member")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/mps-performance/national-assembly/13th-parliament/harrison-garama-kombe/";
+
+        let profile = parse_member_profile(&html, url).expect("Failed to parse member profile");
+
+        assert_eq!(profile.name, "Harrison Garama Kombe");
+        assert_eq!(profile.assumed_office, NaiveDate::from_ymd_opt(2023, 3, 10));
+        assert_eq!(profile.left_office, None);
+    }
+
+    #[test]
+    fn test_parse_member_profile_contact() {
+        let html = fs::read_to_string(
+            "fixtures/current/Boss_Gladys_Jepkosgei_with_paginated_contributions",
+        )
+        .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/mps-performance/national-assembly/13th-parliament/boss-gladys-jepkosgei/";
+
+        let profile = parse_member_profile(&html, url).expect("Failed to parse member profile");
+        assert_eq!(
+            (profile.email, profile.telephone),
+            (None, None),
+            "The footer's contact links are Mzalendo's, not the member's"
+        );
+
+        let html = html.replacen(
+            r#"<section class="member-biography">"#,
+            r#"<div class="member-contact">
+                <a href="mailto:gladys.boss@parliament.go.ke?subject=Hello">Email</a>
+                <a href="tel:+254700000000">Call</a>
+            </div>
+            <section class="member-biography">"#,
+            1,
+        );
+        let profile = parse_member_profile(&html, url).expect("Failed to parse member profile");
+        assert_eq!(
+            profile.email.as_deref(),
+            Some("gladys.boss@parliament.go.ke")
+        );
+        assert_eq!(profile.telephone.as_deref(), Some("+254700000000"));
+    }
+
+    #[test]
+    fn test_parse_biography_parts() {
+        let bio = "Hon. Musau was born in 1970. He holds a Bachelor of Commerce degree from the \
+            University of Nairobi. He has been the MP since 2013 and studied at Alliance High School.";
+
+        assert_eq!(
+            parse_education(bio).as_deref(),
+            Some(
+                "He holds a Bachelor of Commerce degree from the University of Nairobi. \
+                He has been the MP since 2013 and studied at Alliance High School."
+            )
+        );
+        assert_eq!(parse_first_elected(bio), Some(2013));
+        assert_eq!(
+            parse_first_elected(
+                "She was first elected to Parliament in 2017 and re-elected in 2022."
+            ),
+            Some(2017)
+        );
+        assert_eq!(parse_first_elected("Born in 1970 in Machakos."), None);
+        assert_eq!(parse_education("Born in 1970 in Machakos."), None);
+    }
+
+    #[test]
+    fn test_parse_committee_plain_text() {
+        let committee = |name: &str, role: Option<&str>| {
+            Some(Committee {
+                name: name.to_string(),
+                role: role.map(str::to_string),
+            })
+        };
+
+        assert_eq!(
+            parse_committee(
+                "A member of the Budget and Appropriations committee.",
+                None,
+                None
+            ),
+            committee("Budget and Appropriations", Some("Member"))
+        );
+        assert_eq!(
+            parse_committee("Health (Chairperson)", None, None),
+            committee("Health", Some("Chairperson"))
+        );
+        assert_eq!(
+            parse_committee("Public Accounts – Vice Chairperson", None, None),
+            committee("Public Accounts", Some("Vice Chairperson"))
+        );
+        assert_eq!(
+            parse_committee("House Business", None, None),
+            committee("House Business", None)
+        );
+        assert_eq!(parse_committee(" ", None, None), None);
+        assert_eq!(
+            committee("House Business", None).unwrap().to_string(),
+            "House Business"
+        );
+    }
+
+    #[test]
+    fn test_parse_term_with_exit_date() {
+        assert_eq!(
+            parse_term("A member of the 12th Parliament from Sept. 1, 2017 to Jan. 5, 2021"),
+            Some((
+                NaiveDate::from_ymd_opt(2017, 9, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2021, 1, 5)
+            ))
+        );
+        assert_eq!(parse_term("Elected to be Deputy Speaker"), None);
     }
 
     #[test]
@@ -1557,11 +3540,35 @@ mod tests {
 
         assert!(!votes.is_empty(), "Should parse at least one vote record");
         for vote in &votes {
-            assert!(!vote.date.is_empty(), "Date should not be empty");
+            assert!(vote.date.is_some(), "Date should parse");
             assert!(!vote.title.is_empty(), "Title should not be empty");
-            assert!(!vote.decision.is_empty(), "Decision should not be empty");
+            assert!(
+                !vote.raw_decision.is_empty(),
+                "Decision should not be empty"
+            );
+            assert_ne!(vote.decision, VoteDecision::Unknown);
             assert!(vote.url.is_some(), "Should have a URL");
         }
+        let finance = votes
+            .iter()
+            .find(|v| v.title == "Finance Bill 2024 (2nd Reading)")
+            .expect("Should find the Finance Bill vote");
+        assert_eq!(finance.date, NaiveDate::from_ymd_opt(2024, 6, 20));
+        assert_eq!(finance.decision, VoteDecision::Yes);
+        assert_eq!(
+            votes
+                .iter()
+                .filter(|v| v.decision == VoteDecision::Absent)
+                .count(),
+            2
+        );
+
+        assert_eq!(parse_vote_decision("", "Nay"), VoteDecision::No);
+        assert_eq!(parse_vote_decision("", "Abstained"), VoteDecision::Abstain);
+        assert_eq!(
+            parse_vote_decision("pending", "Pending"),
+            VoteDecision::Unknown
+        );
         println!("Parsed {} vote records", votes.len());
         println!("First vote: {:#?}", votes[0]);
     }
@@ -1598,6 +3605,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_sentiment() {
+        assert_eq!(parse_sentiment("Mixed"), Sentiment::Mixed);
+        assert_eq!(parse_sentiment("Largely Positive"), Sentiment::Positive);
+        assert_eq!(parse_sentiment("negative"), Sentiment::Negative);
+        assert_eq!(parse_sentiment("Neutral"), Sentiment::Neutral);
+        assert_eq!(parse_sentiment("Positive and negative"), Sentiment::Mixed);
+        assert_eq!(
+            parse_sentiment("Tense"),
+            Sentiment::Other {
+                raw: "Tense".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_parse_time_12h() {
         assert_eq!(
@@ -1,17 +1,34 @@
 use super::parser::{
-    ParseError, parse_activity_page_info, parse_bills, parse_bills_page_info, parse_hansard_list,
-    parse_hansard_sitting, parse_member_list, parse_member_profile, parse_page_info,
-    parse_parliamentary_activity,
+    ParseConfig, ParseError, is_not_found_page, parse_activity_page_info, parse_bills,
+    parse_bills_page_info, parse_contribution_at, parse_hansard_list, parse_hansard_sitting_with,
+    parse_member_list, parse_member_profile, parse_page_info, parse_parliamentary_activity,
 };
+#[cfg(feature = "pdf")]
+use super::types::HansardSection;
 use super::types::{
-    Bill, HansardListing, HansardSitting, House, Member, MemberProfile, ParliamentaryActivity,
+    ActivityFilter, Bill, Contribution, HansardListing, HansardSitting, House, Member,
+    MemberProfile, Page, ParliamentaryActivity,
 };
+use crate::types::{FetchProgress, Parliament, ProgressHook, SkipHook};
+use crate::util::get_with_retry;
 
-use futures::stream::FuturesUnordered;
-use futures::{StreamExt, future};
-use reqwest::Client;
+use bytes::Bytes;
+use chrono::NaiveDate;
+use futures::stream::{self, FuturesUnordered};
+use futures::{Stream, StreamExt, TryStreamExt, future};
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use reqwest::{Client, StatusCode};
+use std::collections::HashSet;
+use std::pin::pin;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::Duration;
 
+/// Maximum number of listing pages fetched concurrently by the paged streams.
+const PAGE_CONCURRENCY: usize = 16;
+
+/// How long to wait before refetching a sitting that parsed to no sections.
+const EMPTY_SITTING_RETRY_DELAY: Duration = Duration::from_secs(2);
+
 #[derive(Debug, thiserror::Error)]
 pub enum ScraperError {
     #[error("HTTP request failed: {0}")]
@@ -20,12 +37,28 @@ pub enum ScraperError {
     ParseError(#[from] ParseError),
     #[error("Page {requested} is out of range (last page is {last})")]
     PageOutOfRange { requested: u32, last: u32 },
+    #[error("Invalid page range {from}..={to}: pages start at 1 and from must not exceed to")]
+    InvalidPageRange { from: u32, to: u32 },
+    #[error("Nothing found at {0}")]
+    NotFound(String),
+    #[error("Sitting has no PDF to download")]
+    MissingPdfUrl,
+    #[error("Expected a PDF from {url}, got content type '{content_type}'")]
+    NotPdf { url: String, content_type: String },
+    #[error("Empty PDF response from {0}")]
+    EmptyPdf(String),
+    #[cfg(feature = "pdf")]
+    #[error("PDF text extraction failed: {0}")]
+    PdfExtract(#[from] pdf_extract::OutputError),
 }
 
 #[derive(Debug, Clone)]
 pub struct WebScraper {
     client: Client,
     base_url: String,
+    progress: ProgressHook,
+    skips: SkipHook,
+    retry_on_empty: bool,
 }
 
 impl WebScraper {
@@ -39,10 +72,56 @@ impl WebScraper {
             ))
             .build()?;
 
-        Ok(Self {
+        Ok(Self::from_client(client))
+    }
+
+    /// Wrap an already configured client instead of building one, e.g. to share its
+    /// connection pool and proxy settings. The client's timeout and user agent are used as-is.
+    pub fn from_client(client: Client) -> Self {
+        Self {
             client,
             base_url: super::BASE_URL.to_string(),
-        })
+            progress: ProgressHook::default(),
+            skips: SkipHook::default(),
+            retry_on_empty: false,
+        }
+    }
+
+    /// Point the scraper at another host serving the site's pages, e.g. a local mirror or a
+    /// mock server in tests. `new()` uses the production site.
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The host pages are fetched from, without a trailing slash.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Report page progress to `callback` as each page of a `fetch_all_*` crawl or a member
+    /// profile's page fan-out resolves.
+    pub fn with_progress(
+        mut self,
+        callback: impl Fn(FetchProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = ProgressHook::new(callback);
+        self
+    }
+
+    /// Report the rows of sitting and member list pages left out for being malformed to
+    /// `skips`.
+    pub fn with_skip_hook(mut self, skips: SkipHook) -> Self {
+        self.skips = skips;
+        self
+    }
+
+    /// Refetch a sitting once, after a short delay, when its transcript parses to no sections.
+    /// The site occasionally serves a partially rendered page under load; if the retry is
+    /// empty too, the empty sitting is returned as before.
+    pub fn with_retry_on_empty(mut self, retry: bool) -> Self {
+        self.retry_on_empty = retry;
+        self
     }
 
     pub async fn fetch_hansard_list(
@@ -50,48 +129,198 @@ impl WebScraper {
         page: u32,
         house: Option<House>,
     ) -> Result<Vec<HansardListing>, ScraperError> {
+        Ok(self.fetch_hansard_list_paged(page, house).await?.items)
+    }
+
+    /// Like [`fetch_hansard_list`](Self::fetch_hansard_list), also returning the page count.
+    pub async fn fetch_hansard_list_paged(
+        &self,
+        page: u32,
+        house: Option<House>,
+    ) -> Result<Page<HansardListing>, ScraperError> {
         let url = format!("{}/democracy-tools/hansard/?page={}", self.base_url, page);
         log::debug!("Fetching hansard list page {}...", page);
         let html = self.get_html(&url).await?;
-        self.check_page(page, &html)?;
-        Ok(parse_hansard_list(&html, house)?)
+        let total_pages = self.checked_total_pages(page, &html)?;
+        Ok(Page {
+            items: self.parse_listings(&html, house)?,
+            current_page: page,
+            total_pages,
+        })
+    }
+
+    /// Stream sitting listings page by page, in site order (newest first).
+    ///
+    /// Only a failure on the first page is yielded as an error; later pages that fail are
+    /// logged and skipped, matching [`WebScraper::fetch_all_sittings`].
+    pub fn sittings_stream(
+        &self,
+        house: Option<House>,
+    ) -> impl Stream<Item = Result<HansardListing, ScraperError>> + '_ {
+        let first_page = async move {
+            let url = format!("{}/democracy-tools/hansard/?page=1", self.base_url);
+            let html = self.get_html(&url).await?;
+            let total_pages = parse_page_info(&html)?.map(|(_, total)| total).unwrap_or(1);
+            Ok::<_, ScraperError>((self.parse_listings(&html, house)?, total_pages))
+        };
+        paged_stream(
+            first_page,
+            move |page| self.fetch_hansard_list(page, house),
+            "hansard list",
+            None,
+            self.progress.clone(),
+        )
+    }
+
+    /// Fetch listing pages `from..=to` (inclusive) concurrently, concatenated in page order.
+    ///
+    /// The first page of the range is fetched on its own to learn the page count, so a `to`
+    /// past the last page fails with [`ScraperError::PageOutOfRange`] before the rest are
+    /// requested. Unlike [`WebScraper::fetch_all_sittings`], any failed page fails the range.
+    pub async fn fetch_sittings_page_range(
+        &self,
+        house: Option<House>,
+        from: u32,
+        to: u32,
+    ) -> Result<Vec<HansardListing>, ScraperError> {
+        if from == 0 || from > to {
+            return Err(ScraperError::InvalidPageRange { from, to });
+        }
+
+        let url = format!("{}/democracy-tools/hansard/?page={}", self.base_url, from);
+        log::debug!("Fetching hansard list page {}...", from);
+        let html = self.get_html(&url).await?;
+        self.check_page(from, &html)?;
+        let last = parse_page_info(&html)?.map(|(_, total)| total).unwrap_or(1);
+        if to > last {
+            return Err(ScraperError::PageOutOfRange {
+                requested: to,
+                last,
+            });
+        }
+
+        let mut listings = self.parse_listings(&html, house)?;
+        if to > from {
+            log::info!("Fetching hansard list pages {} to {}...", from + 1, to);
+        }
+        let rest: Vec<Vec<HansardListing>> = stream::iter(from + 1..=to)
+            .map(|page| self.fetch_hansard_list(page, house))
+            .buffered(PAGE_CONCURRENCY)
+            .try_collect()
+            .await?;
+        listings.extend(rest.into_iter().flatten());
+
+        Ok(dedup_by_url(listings, |l| &l.url, "sitting"))
     }
 
     pub async fn fetch_all_sittings(
         &self,
         house: Option<House>,
     ) -> Result<Vec<HansardListing>, ScraperError> {
-        let first_url = format!("{}/democracy-tools/hansard/?page=1", self.base_url);
-        let first_html = self.get_html(&first_url).await?;
-        let total_pages = parse_page_info(&first_html)?
-            .map(|(_, total)| total)
-            .unwrap_or(1);
-        let mut listings = parse_hansard_list(&first_html, house)?;
-
-        if total_pages > 1 {
-            log::info!(
-                "Fetching {} remaining hansard list page(s)...",
-                total_pages - 1
+        let listings: Vec<HansardListing> = self.sittings_stream(house).try_collect().await?;
+        let mut listings = dedup_by_url(listings, |l| &l.url, "sitting");
+        listings.sort_by_key(|l| std::cmp::Reverse(l.date));
+        Ok(listings)
+    }
+
+    /// Fetch sittings dated within `[start, end]` (inclusive).
+    ///
+    /// Listing pages are newest-first, so pages are walked sequentially and paging stops at
+    /// the first page that reaches back before `start` instead of fetching every page.
+    pub async fn fetch_sittings_in_range(
+        &self,
+        house: Option<House>,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<HansardListing>, ScraperError> {
+        let mut listings = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let url = format!("{}/democracy-tools/hansard/?page={}", self.base_url, page);
+            log::debug!(
+                "Fetching hansard list page {} ({} to {})...",
+                page,
+                start,
+                end
             );
-            let mut futs: FuturesUnordered<_> = (2..=total_pages)
-                .map(|page| self.fetch_hansard_list(page, house))
-                .collect();
-            while let Some(result) = futs.next().await {
-                match result {
-                    Ok(page_listings) => listings.extend(page_listings),
-                    Err(e) => log::warn!("Failed to fetch hansard list page: {}", e),
-                }
+            let html = self.get_html(&url).await?;
+            self.check_page(page, &html)?;
+            let total_pages = parse_page_info(&html)?.map(|(_, total)| total).unwrap_or(1);
+
+            // XXX: parse without the house filter so a page holding only the other house's
+            // sittings still tells us how far back the listing has reached.
+            let page_listings = self.parse_listings(&html, None)?;
+            let reached_start = collect_in_range(page_listings, house, start, end, &mut listings);
+            if reached_start || page >= total_pages {
+                break;
             }
+            page += 1;
         }
 
+        log::info!(
+            "Found {} sitting(s) between {} and {} in {} page(s)",
+            listings.len(),
+            start,
+            end,
+            page
+        );
         listings.sort_by_key(|l| std::cmp::Reverse(l.date));
         Ok(listings)
     }
 
+    /// Fetch sittings dated after `after`, e.g. whatever is new since the last crawl. As with
+    /// [`fetch_sittings_in_range`](Self::fetch_sittings_in_range), paging stops at the first
+    /// page that reaches back to `after`.
+    pub async fn fetch_sittings_since(
+        &self,
+        house: Option<House>,
+        after: NaiveDate,
+    ) -> Result<Vec<HansardListing>, ScraperError> {
+        let Some(start) = after.succ_opt() else {
+            return Ok(Vec::new());
+        };
+        self.fetch_sittings_in_range(house, start, NaiveDate::MAX)
+            .await
+    }
+
     pub async fn fetch_hansard_sitting(
         &self,
         url_or_slug: &str,
+        config: ParseConfig,
+    ) -> Result<HansardSitting, ScraperError> {
+        let (sitting, _) = self.fetch_sitting_with_html(url_or_slug, config).await?;
+        Ok(sitting)
+    }
+
+    /// Fetch a sitting's page for its metadata, then replace the transcript with the text of
+    /// its official PDF. This is a best-effort fallback for sittings whose HTML transcript
+    /// comes back without sections: the PDF text is kept as-is in a single "PDF TRANSCRIPT"
+    /// section, so speakers, subsections, and procedural notes are not recovered from it.
+    #[cfg(feature = "pdf")]
+    pub async fn fetch_sitting_from_pdf(
+        &self,
+        url_or_slug: &str,
     ) -> Result<HansardSitting, ScraperError> {
+        let mut sitting = self
+            .fetch_hansard_sitting(url_or_slug, ParseConfig::default())
+            .await?;
+        let pdf = self
+            .download_sitting_pdf(sitting.pdf_url.as_deref())
+            .await?;
+        // XXX: extraction is synchronous and CPU-bound; a Hansard PDF takes well under a second
+        let text = pdf_extract::extract_text_from_mem(&pdf)?;
+        sitting.sections = vec![pdf_section(&text)];
+        Ok(sitting)
+    }
+
+    /// Fetch and parse a sitting, returning the raw page alongside it. Handy for telling a site
+    /// change from a wrong slug when a parse comes back empty, and for capturing new fixtures.
+    pub async fn fetch_sitting_with_html(
+        &self,
+        url_or_slug: &str,
+        config: ParseConfig,
+    ) -> Result<(HansardSitting, String), ScraperError> {
         let url = if url_or_slug.starts_with("http") {
             url_or_slug.to_string()
         } else {
@@ -99,74 +328,122 @@ impl WebScraper {
         };
         log::info!("Fetching hansard sitting: {}", url);
         let html = self.get_html(&url).await?;
-        Ok(parse_hansard_sitting(&html, &url)?)
+        let sitting = parse_hansard_sitting_with(&html, &url, config)?;
+        if !self.retry_on_empty || !sitting.sections.is_empty() {
+            return Ok((sitting, html));
+        }
+
+        log::warn!(
+            "Sitting {url} has no sections, retrying in {}s",
+            EMPTY_SITTING_RETRY_DELAY.as_secs()
+        );
+        tokio::time::sleep(EMPTY_SITTING_RETRY_DELAY).await;
+        let html = self.get_html(&url).await?;
+        let sitting = parse_hansard_sitting_with(&html, &url, config)?;
+        Ok((sitting, html))
     }
 
     pub async fn fetch_members(
         &self,
         house: House,
-        parliament: &str,
+        parliament: &Parliament,
         page: u32,
     ) -> Result<Vec<Member>, ScraperError> {
-        let url = format!(
-            "{}/mps-performance/{}/{}/?q=&page={}",
-            self.base_url,
-            house.slug(),
-            parliament,
-            page
-        );
+        Ok(self
+            .fetch_members_paged(house, parliament, page)
+            .await?
+            .items)
+    }
+
+    /// Like [`fetch_members`](Self::fetch_members), also returning the page count.
+    pub async fn fetch_members_paged(
+        &self,
+        house: House,
+        parliament: &Parliament,
+        page: u32,
+    ) -> Result<Page<Member>, ScraperError> {
+        let url = self.members_url(house, parliament, page);
         log::info!(
             "Fetching {} members ({}, page {})...",
             house.slug(),
             parliament,
             page
         );
-        let html = self.get_html(&url).await?;
-        self.check_page(page, &html)?;
-        Ok(parse_member_list(&html, house)?)
+        let html = self.get_members_html(&url).await?;
+        let total_pages = self.checked_total_pages(page, &html)?;
+        Ok(Page {
+            items: members_or_not_found(&html, house, &url, &self.skips)?,
+            current_page: page,
+            total_pages,
+        })
+    }
+
+    /// Stream members page by page as each page is parsed.
+    ///
+    /// Only a failure on the first page is yielded as an error; later pages that fail are
+    /// logged and skipped, matching [`WebScraper::fetch_all_members`].
+    pub fn members_stream<'a>(
+        &'a self,
+        house: House,
+        parliament: &'a Parliament,
+    ) -> impl Stream<Item = Result<Member, ScraperError>> + 'a {
+        let first_page = async move {
+            let url = self.members_url(house, parliament, 1);
+            let html = self.get_members_html(&url).await?;
+            let total_pages = parse_page_info(&html)?.map(|(_, total)| total).unwrap_or(1);
+            Ok::<_, ScraperError>((
+                members_or_not_found(&html, house, &url, &self.skips)?,
+                total_pages,
+            ))
+        };
+        paged_stream(
+            first_page,
+            move |page| self.fetch_members(house, parliament, page),
+            "members",
+            None,
+            self.progress.clone(),
+        )
     }
 
     pub async fn fetch_all_members(
         &self,
         house: House,
-        parliament: &str,
+        parliament: &Parliament,
     ) -> Result<Vec<Member>, ScraperError> {
-        let first_url = format!(
-            "{}/mps-performance/{}/{}/?q=&page=1",
-            self.base_url,
-            house.slug(),
-            parliament
-        );
-        let first_html = self.get_html(&first_url).await?;
-        let total_pages = parse_page_info(&first_html)?
-            .map(|(_, total)| total)
-            .unwrap_or(1);
-        let mut members = parse_member_list(&first_html, house)?;
-
-        if total_pages > 1 {
-            log::info!(
-                "Fetching {} remaining {} member page(s)...",
-                total_pages - 1,
-                house.slug()
-            );
-            let mut futs: FuturesUnordered<_> = (2..=total_pages)
-                .map(|page| self.fetch_members(house, parliament, page))
-                .collect();
-            while let Some(result) = futs.next().await {
-                match result {
-                    Ok(page_members) => members.extend(page_members),
-                    Err(e) => log::warn!("Failed to fetch members page: {}", e),
-                }
+        let members: Vec<Member> = self.members_stream(house, parliament).try_collect().await?;
+        Ok(dedup_by_url(members, |m| &m.url, "member"))
+    }
+
+    /// Find the member for `constituency` (matched case-insensitively, ignoring surrounding
+    /// whitespace), e.g. "Mwala". Member pages are crawled in order and the crawl stops at the
+    /// first match. Senators and woman representatives have a county rather than a
+    /// constituency, so they are never found this way.
+    pub async fn find_member_by_constituency(
+        &self,
+        house: House,
+        parliament: &Parliament,
+        constituency: &str,
+    ) -> Result<Option<Member>, ScraperError> {
+        let constituency = constituency.trim();
+        let mut members = pin!(self.members_stream(house, parliament));
+        while let Some(member) = members.try_next().await? {
+            if member
+                .constituency
+                .as_deref()
+                .is_some_and(|c| c.trim().eq_ignore_ascii_case(constituency))
+            {
+                return Ok(Some(member));
             }
         }
-
-        Ok(members)
+        Ok(None)
     }
 
+    /// Fetch every member of both houses in parallel. A house whose crawl fails is logged and
+    /// reported alongside the members of the other, rather than failing the whole fetch.
     pub async fn fetch_all_members_all_houses(
         &self,
-        parliament: &str,
-    ) -> Result<Vec<Member>, ScraperError> {
+        parliament: &Parliament,
+    ) -> (Vec<Member>, Vec<(House, ScraperError)>) {
         let (na_result, senate_result) = future::join(
             self.fetch_all_members(House::NationalAssembly, parliament),
             self.fetch_all_members(House::Senate, parliament),
@@ -174,16 +451,21 @@ impl WebScraper {
         .await;
 
         let mut members = Vec::new();
-        match na_result {
-            Ok(m) => members.extend(m),
-            Err(e) => log::warn!("Failed to fetch National Assembly members: {}", e),
-        }
-        match senate_result {
-            Ok(m) => members.extend(m),
-            Err(e) => log::warn!("Failed to fetch Senate members: {}", e),
+        let mut errors = Vec::new();
+        for (house, result) in [
+            (House::NationalAssembly, na_result),
+            (House::Senate, senate_result),
+        ] {
+            match result {
+                Ok(m) => members.extend(m),
+                Err(e) => {
+                    log::warn!("Failed to fetch {} members: {}", house, e);
+                    errors.push((house, e));
+                }
+            }
         }
 
-        Ok(members)
+        (members, errors)
     }
 
     pub async fn fetch_member_profile(
@@ -201,6 +483,20 @@ impl WebScraper {
         let html = self.get_html(&url).await?;
         let mut profile = parse_member_profile(&html, &url)?;
 
+        // XXX: activity and bills pages resolve interleaved, so both share one counter with
+        // the profile page itself counted as the first completed page.
+        let extra_pages =
+            |wanted: bool, pages: u32| if wanted { pages.saturating_sub(1) } else { 0 };
+        let total = 1
+            + extra_pages(fetch_all_activity, profile.activity_pages)
+            + extra_pages(fetch_all_bills, profile.bills_pages);
+        let completed = AtomicU32::new(1);
+        self.progress.report(1, total);
+        let page_done = || {
+            let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+            self.progress.report(done, total);
+        };
+
         let (extra_activity, extra_bills) = future::join(
             async {
                 if fetch_all_activity && profile.activity_pages > 1 {
@@ -213,6 +509,7 @@ impl WebScraper {
                         .collect();
                     let mut all = Vec::new();
                     while let Some(result) = futs.next().await {
+                        page_done();
                         match result {
                             Ok(items) => all.extend(items),
                             Err(e) => log::warn!("Failed to fetch activity page: {}", e),
@@ -234,6 +531,7 @@ impl WebScraper {
                         .collect();
                     let mut all = Vec::new();
                     while let Some(result) = futs.next().await {
+                        page_done();
                         match result {
                             Ok(items) => all.extend(items),
                             Err(e) => log::warn!("Failed to fetch bills page: {}", e),
@@ -258,11 +556,43 @@ impl WebScraper {
         url_or_slug: &str,
         contributions_page: u32,
     ) -> Result<Vec<ParliamentaryActivity>, ScraperError> {
-        let base = if url_or_slug.starts_with("http") {
-            url_or_slug.trim_end_matches('/').to_string()
+        let (activity, _) = self
+            .fetch_activity_page(url_or_slug, contributions_page)
+            .await?;
+        Ok(activity)
+    }
+
+    /// Fetch the sitting an activity item links to and return the contribution its
+    /// `#chunk-N` anchor marks, i.e. the full speech behind the item's `text_preview`.
+    pub async fn fetch_contribution(
+        &self,
+        activity: &ParliamentaryActivity,
+    ) -> Result<Contribution, ScraperError> {
+        let Some((_, anchor)) = activity.url.split_once('#').filter(|(_, a)| !a.is_empty()) else {
+            return Err(ParseError::UrlParse(format!(
+                "Activity URL has no #chunk anchor: {}",
+                activity.url
+            ))
+            .into());
+        };
+        let url = if activity.sitting_url.starts_with("http") {
+            activity.sitting_url.clone()
         } else {
-            format!("{}{}", self.base_url, url_or_slug.trim_end_matches('/'))
+            format!("{}{}", self.base_url, activity.sitting_url)
         };
+        log::info!("Fetching contribution #{anchor} of {url}");
+        let html = self.get_html(&url).await?;
+        parse_contribution_at(&html, &url, anchor)?
+            .ok_or_else(|| ScraperError::NotFound(activity.url.clone()))
+    }
+
+    /// Fetch a page of activity along with the last page number.
+    async fn fetch_activity_page(
+        &self,
+        url_or_slug: &str,
+        contributions_page: u32,
+    ) -> Result<(Vec<ParliamentaryActivity>, u32), ScraperError> {
+        let base = self.member_base_url(url_or_slug);
         let url = format!("{}/?contributions_page={}", base, contributions_page);
         log::debug!(
             "Fetching member activity page {}: {}",
@@ -270,7 +600,8 @@ impl WebScraper {
             url
         );
         let html = self.get_html(&url).await?;
-        if let Some((current, last)) = parse_activity_page_info(&html)?
+        let page_info = parse_activity_page_info(&html)?;
+        if let Some((current, last)) = page_info
             && current != contributions_page
         {
             return Err(ScraperError::PageOutOfRange {
@@ -278,7 +609,76 @@ impl WebScraper {
                 last,
             });
         }
-        Ok(parse_parliamentary_activity(&html)?)
+        let last = page_info.map(|(_, last)| last).unwrap_or(1);
+        Ok((parse_parliamentary_activity(&html)?, last))
+    }
+
+    /// Fetch every page of a member's parliamentary activity without parsing the rest of
+    /// the profile.
+    pub async fn fetch_all_member_activity(
+        &self,
+        url_or_slug: &str,
+    ) -> Result<Vec<ParliamentaryActivity>, ScraperError> {
+        paged_stream(
+            self.fetch_activity_page(url_or_slug, 1),
+            |page| self.fetch_member_activity(url_or_slug, page),
+            "activity",
+            None,
+            self.progress.clone(),
+        )
+        .try_collect()
+        .await
+    }
+
+    /// Fetch at most `max_pages` pages of a member's activity, newest first, returning the
+    /// items with the number of pages available. Pages after the first that fail are logged
+    /// and skipped.
+    pub async fn fetch_member_activity_pages(
+        &self,
+        url_or_slug: &str,
+        max_pages: u32,
+    ) -> Result<(Vec<ParliamentaryActivity>, u32), ScraperError> {
+        let (first, total_pages) = self.fetch_activity_page(url_or_slug, 1).await?;
+        let activity = paged_stream(
+            future::ready(Ok((first, total_pages))),
+            |page| self.fetch_member_activity(url_or_slug, page),
+            "activity",
+            Some(max_pages),
+            self.progress.clone(),
+        )
+        .try_collect()
+        .await?;
+
+        Ok((activity, total_pages))
+    }
+
+    /// Fetch a member's activity matching `filter`.
+    ///
+    /// Activity is listed newest first, so with `filter.since` set paging stops at the first
+    /// page that reaches back before it instead of fetching every page.
+    pub async fn fetch_member_activity_filtered(
+        &self,
+        url_or_slug: &str,
+        filter: &ActivityFilter,
+    ) -> Result<Vec<ParliamentaryActivity>, ScraperError> {
+        let (first, total_pages) = self.fetch_activity_page(url_or_slug, 1).await?;
+        let mut activity = Vec::new();
+        let mut reached_since = collect_matching_activity(first, filter, &mut activity);
+        let mut page = 1;
+
+        while !reached_since && page < total_pages {
+            page += 1;
+            self.progress.report(page, total_pages);
+            let items = self.fetch_member_activity(url_or_slug, page).await?;
+            reached_since = collect_matching_activity(items, filter, &mut activity);
+        }
+
+        log::info!(
+            "Found {} matching activity item(s) in {} page(s)",
+            activity.len(),
+            page
+        );
+        Ok(activity)
     }
 
     pub async fn fetch_member_bills(
@@ -286,11 +686,7 @@ impl WebScraper {
         url_or_slug: &str,
         bills_page: u32,
     ) -> Result<Vec<Bill>, ScraperError> {
-        let base = if url_or_slug.starts_with("http") {
-            url_or_slug.trim_end_matches('/').to_string()
-        } else {
-            format!("{}{}", self.base_url, url_or_slug.trim_end_matches('/'))
-        };
+        let base = self.member_base_url(url_or_slug);
         let url = format!("{}/?bills_page={}", base, bills_page);
         log::debug!("Fetching member bills page {}: {}", bills_page, url);
         let html = self.get_html(&url).await?;
@@ -305,27 +701,744 @@ impl WebScraper {
         Ok(parse_bills(&html)?)
     }
 
+    pub fn member_base_url(&self, url_or_slug: &str) -> String {
+        if url_or_slug.starts_with("http") {
+            url_or_slug.trim_end_matches('/').to_string()
+        } else {
+            format!("{}{}", self.base_url, url_or_slug.trim_end_matches('/'))
+        }
+    }
+
+    fn members_url(&self, house: House, parliament: &Parliament, page: u32) -> String {
+        format!(
+            "{}/mps-performance/{}/{}/?q=&page={}",
+            self.base_url,
+            house.slug(),
+            parliament,
+            page
+        )
+    }
+
+    /// Like `get_html`, reporting a members page the site 404s on (e.g. an unknown parliament)
+    /// as [`ScraperError::NotFound`].
+    async fn get_members_html(&self, url: &str) -> Result<String, ScraperError> {
+        match self.get_html(url).await {
+            Err(ScraperError::HttpError(e)) if e.status() == Some(StatusCode::NOT_FOUND) => {
+                Err(ScraperError::NotFound(url.to_string()))
+            }
+            result => result,
+        }
+    }
+
+    fn parse_listings(
+        &self,
+        html: &str,
+        house: Option<House>,
+    ) -> Result<Vec<HansardListing>, ParseError> {
+        let (listings, report) = parse_hansard_list(html, house)?;
+        self.skips.report(report);
+        Ok(listings)
+    }
+
+    /// Pages without a pagination widget are single-page results and always pass.
     fn check_page(&self, requested: u32, html: &str) -> Result<(), ScraperError> {
-        if let Some((current, last)) = parse_page_info(html)?
-            && current != requested
-        {
-            return Err(ScraperError::PageOutOfRange { requested, last });
+        self.checked_total_pages(requested, html).map(|_| ())
+    }
+
+    /// The listing's page count, or `PageOutOfRange` when the site served a page other than
+    /// `requested`. A listing without pagination is a single page.
+    fn checked_total_pages(&self, requested: u32, html: &str) -> Result<u32, ScraperError> {
+        match parse_page_info(html)? {
+            Some((current, last)) if current != requested => {
+                Err(ScraperError::PageOutOfRange { requested, last })
+            }
+            Some((_, last)) => Ok(last),
+            None => Ok(requested),
         }
-        Ok(())
     }
 
-    async fn get_html(&self, url: &str) -> Result<String, ScraperError> {
-        let html = self
+    /// Download the official Hansard PDF linked from a sitting page, given the sitting's
+    /// `pdf_url`.
+    pub async fn download_sitting_pdf(&self, pdf_url: Option<&str>) -> Result<Bytes, ScraperError> {
+        let url = pdf_url.ok_or(ScraperError::MissingPdfUrl)?;
+        self.download_pdf(url).await
+    }
+
+    /// Download a PDF, rejecting responses that are empty or not served as `application/pdf`.
+    pub async fn download_pdf(&self, url: &str) -> Result<Bytes, ScraperError> {
+        log::info!("Downloading PDF: {}", url);
+        let response = self
             .client
             .get(url)
+            .header(ACCEPT, "application/pdf")
             .send()
             .await
             .inspect_err(|e| log::error!("HTTP error: {e:?}"))?
-            .error_for_status()?
+            .error_for_status()?;
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = response.bytes().await?;
+
+        check_pdf(url, content_type.as_deref(), body)
+    }
+
+    /// Fetch the raw page at `url` without parsing it, with the same client and headers the
+    /// parsing fetches use.
+    pub async fn fetch_page(&self, url: &str) -> Result<String, ScraperError> {
+        log::info!("Fetching page: {}", url);
+        self.get_html(url).await
+    }
+
+    async fn get_html(&self, url: &str) -> Result<String, ScraperError> {
+        let html = get_with_retry(&self.client, url)
+            .await
+            .inspect_err(|e| log::error!("HTTP error: {e:?}"))?
             .text()
             .await
             .inspect_err(|e| log::error!("Decode error: {e:?}"))?;
 
+        if is_not_found_page(&html) {
+            return Err(ScraperError::NotFound(url.to_string()));
+        }
         Ok(html)
     }
 }
+
+/// A synthetic section holding extracted PDF text, with trailing whitespace and runs of blank
+/// lines left by the page layout collapsed.
+#[cfg(feature = "pdf")]
+fn pdf_section(text: &str) -> HansardSection {
+    let mut content = String::new();
+    let mut blank = false;
+    for line in text.lines().map(str::trim_end) {
+        if line.trim().is_empty() {
+            blank = !content.is_empty();
+            continue;
+        }
+        if !content.is_empty() {
+            content.push_str(if blank { "\n\n" } else { "\n" });
+        }
+        content.push_str(line);
+        blank = false;
+    }
+
+    HansardSection {
+        section_type: "PDF TRANSCRIPT".to_string(),
+        subsections: Vec::new(),
+        contributions: vec![Contribution {
+            speaker_name: String::new(),
+            speaker_raw: String::new(),
+            speaker_role: None,
+            speaker_url: None,
+            content,
+            procedural_notes: Vec::new(),
+            contribution_kind: None,
+        }],
+    }
+}
+
+// XXX: parliaments the site has no members for still render the page chrome, just without
+// list items or pagination, so an empty list means there is nothing at `url`.
+fn members_or_not_found(
+    html: &str,
+    house: House,
+    url: &str,
+    skips: &SkipHook,
+) -> Result<Vec<Member>, ScraperError> {
+    let (members, report) = parse_member_list(html, house)?;
+    skips.report(report);
+    if members.is_empty() {
+        return Err(ScraperError::NotFound(url.to_string()));
+    }
+    Ok(members)
+}
+
+fn check_pdf(url: &str, content_type: Option<&str>, body: Bytes) -> Result<Bytes, ScraperError> {
+    let mime = content_type
+        .and_then(|ct| ct.split(';').next())
+        .map(str::trim)
+        .unwrap_or_default();
+    if !mime.eq_ignore_ascii_case("application/pdf") {
+        return Err(ScraperError::NotPdf {
+            url: url.to_string(),
+            content_type: mime.to_string(),
+        });
+    }
+    if body.is_empty() {
+        return Err(ScraperError::EmptyPdf(url.to_string()));
+    }
+    Ok(body)
+}
+
+// XXX: the first page is fetched on its own to learn the page count; the remaining pages are
+// fetched with bounded concurrency but yielded in page order so callers can bail early.
+// Progress is reported as pages are yielded, so it counts pages in order too. Pages past
+// `max_pages`, when given, are never fetched.
+fn paged_stream<'a, T, Fut>(
+    first_page: impl Future<Output = Result<(Vec<T>, u32), ScraperError>> + 'a,
+    fetch_page: impl Fn(u32) -> Fut + 'a,
+    label: &'static str,
+    max_pages: Option<u32>,
+    progress: ProgressHook,
+) -> impl Stream<Item = Result<T, ScraperError>> + 'a
+where
+    T: 'a,
+    Fut: Future<Output = Result<Vec<T>, ScraperError>> + 'a,
+{
+    stream::once(async move {
+        match first_page.await {
+            Ok((items, total_pages)) => {
+                let last = max_pages.map_or(total_pages, |max| total_pages.min(max.max(1)));
+                progress.report(1, last);
+                if last > 1 {
+                    log::info!("Fetching {} remaining {} page(s)...", last - 1, label);
+                }
+                let rest = stream::iter(2..=last)
+                    .map(fetch_page)
+                    .buffered(PAGE_CONCURRENCY)
+                    .zip(stream::iter(2..=last))
+                    .flat_map(move |(result, page)| {
+                        progress.report(page, last);
+                        stream::iter(result.unwrap_or_else(|e| {
+                            log::warn!("Failed to fetch {} page: {}", label, e);
+                            Vec::new()
+                        }))
+                    });
+                stream::iter(items).chain(rest).map(Ok).left_stream()
+            }
+            Err(e) => stream::once(future::ready(Err(e))).right_stream(),
+        }
+    })
+    .flatten()
+}
+
+// XXX: the site can repeat an entry across page boundaries when new data is inserted
+// mid-crawl, so keep the first occurrence of each URL.
+fn dedup_by_url<T>(items: Vec<T>, url: impl Fn(&T) -> &str, label: &str) -> Vec<T> {
+    let before = items.len();
+    let mut seen = HashSet::new();
+    let items: Vec<T> = items
+        .into_iter()
+        .filter(|item| seen.insert(url(item).to_string()))
+        .collect();
+    if items.len() < before {
+        log::debug!(
+            "Dropped {} duplicate {} entr(ies) across pages",
+            before - items.len(),
+            label
+        );
+    }
+    items
+}
+
+/// Move listings within `[start, end]` (and matching `house`, if given) into `out`.
+/// Returns `true` once the page holds a listing older than `start` or is empty, meaning
+/// no later page can contain sittings in range.
+fn collect_in_range(
+    page: Vec<HansardListing>,
+    house: Option<House>,
+    start: NaiveDate,
+    end: NaiveDate,
+    out: &mut Vec<HansardListing>,
+) -> bool {
+    let reached_start = page.is_empty() || page.iter().any(|l| l.date < start);
+    out.extend(
+        page.into_iter()
+            .filter(|l| l.date >= start && l.date <= end && house.is_none_or(|h| l.house == h)),
+    );
+    reached_start
+}
+
+/// Move the activity matching `filter` into `out`. Returns `true` once the page holds an item
+/// older than `filter.since` or is empty, meaning no later page can match.
+fn collect_matching_activity(
+    page: Vec<ParliamentaryActivity>,
+    filter: &ActivityFilter,
+    out: &mut Vec<ParliamentaryActivity>,
+) -> bool {
+    let reached_since = page.is_empty()
+        || filter.since.is_some_and(|since| {
+            page.iter()
+                .any(|a| a.parsed_date().is_some_and(|date| date < since))
+        });
+    out.extend(page.into_iter().filter(|a| filter.matches(a)));
+    reached_since
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::sync::Arc;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_paged_stream_yields_pages_in_order() {
+        let first_page = async { Ok((vec![1, 2], 4)) };
+        let fetch_page = |page: u32| async move {
+            match page {
+                3 => Err(ScraperError::PageOutOfRange {
+                    requested: 3,
+                    last: 4,
+                }),
+                _ => Ok(vec![page * 10, page * 10 + 1]),
+            }
+        };
+
+        let items: Vec<u32> = futures::executor::block_on(
+            paged_stream(
+                first_page,
+                fetch_page,
+                "test",
+                None,
+                ProgressHook::default(),
+            )
+            .try_collect(),
+        )
+        .expect("Only the first page should fail the stream");
+        assert_eq!(items, vec![1, 2, 20, 21, 40, 41]);
+    }
+
+    #[test]
+    fn test_paged_stream_reports_progress() {
+        let first_page = async { Ok((vec![1], 3)) };
+        let fetch_page = |page: u32| async move { Ok(vec![page]) };
+        let reports = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let progress = ProgressHook::new({
+            let reports = Arc::clone(&reports);
+            move |p: FetchProgress| reports.lock().unwrap().push((p.completed, p.total))
+        });
+
+        let items: Vec<u32> = futures::executor::block_on(
+            paged_stream(first_page, fetch_page, "test", None, progress).try_collect(),
+        )
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(*reports.lock().unwrap(), vec![(1, 3), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn test_paged_stream_bails_early() {
+        let first_page = async { Ok((vec![1, 2], 120)) };
+        let fetch_page = |page: u32| async move { Ok(vec![page]) };
+
+        let items: Vec<u32> = futures::executor::block_on(
+            paged_stream(
+                first_page,
+                fetch_page,
+                "test",
+                None,
+                ProgressHook::default(),
+            )
+            .try_take_while(|n| future::ready(Ok(*n < 4)))
+            .try_collect(),
+        )
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn test_paged_stream_stops_at_max_pages() {
+        let first_page = async { Ok((vec![1], 10)) };
+        let fetch_page = |page: u32| async move {
+            assert!(page <= 3, "Page {page} is past the cap");
+            Ok(vec![page])
+        };
+
+        let items: Vec<u32> = futures::executor::block_on(
+            paged_stream(
+                first_page,
+                fetch_page,
+                "test",
+                Some(3),
+                ProgressHook::default(),
+            )
+            .try_collect(),
+        )
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_paged_stream_first_page_error() {
+        let first_page = async {
+            Err(ScraperError::PageOutOfRange {
+                requested: 1,
+                last: 0,
+            })
+        };
+        let fetch_page = |page: u32| async move { Ok(vec![page]) };
+
+        let items: Vec<Result<u32, ScraperError>> = futures::executor::block_on(
+            paged_stream(
+                first_page,
+                fetch_page,
+                "test",
+                None,
+                ProgressHook::default(),
+            )
+            .collect(),
+        );
+        assert_eq!(items.len(), 1);
+        assert!(matches!(
+            items[0],
+            Err(ScraperError::PageOutOfRange { requested: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_pdf() {
+        let url = "https://mzalendo.com/media/hansard/sitting.pdf";
+        let pdf = Bytes::from_static(b"%PDF-1.7");
+
+        assert_eq!(
+            check_pdf(url, Some("application/pdf; charset=binary"), pdf.clone()).unwrap(),
+            pdf
+        );
+        assert!(matches!(
+            check_pdf(url, Some("text/html; charset=utf-8"), pdf.clone()),
+            Err(ScraperError::NotPdf { content_type, .. }) if content_type == "text/html"
+        ));
+        assert!(matches!(
+            check_pdf(url, None, pdf),
+            Err(ScraperError::NotPdf { .. })
+        ));
+        assert!(matches!(
+            check_pdf(url, Some("application/pdf"), Bytes::new()),
+            Err(ScraperError::EmptyPdf(_))
+        ));
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_pdf_section() {
+        let text = "\n\nTHE HANSARD   \nThursday, 12th February 2026\n\n\n\nThe House met at 2.30 p.m.\n  \n";
+
+        let section = pdf_section(text);
+
+        assert_eq!(section.section_type, "PDF TRANSCRIPT");
+        assert!(section.subsections.is_empty());
+        assert_eq!(section.contributions.len(), 1);
+        assert_eq!(
+            section.contributions[0].content,
+            "THE HANSARD\nThursday, 12th February 2026\n\nThe House met at 2.30 p.m."
+        );
+    }
+
+    #[test]
+    fn test_members_or_not_found() {
+        let url =
+            "https://mzalendo.com/mps-performance/national-assembly/10th-parliament/?q=&page=1";
+        let html = fs::read_to_string("fixtures/current/national_assembly_10th_parliament_empty")
+            .expect("Failed to read fixture");
+
+        assert_eq!(parse_page_info(&html).unwrap(), None);
+        assert!(matches!(
+            members_or_not_found(&html, House::NationalAssembly, url, &SkipHook::default()),
+            Err(ScraperError::NotFound(u)) if u == url
+        ));
+
+        let html =
+            fs::read_to_string("fixtures/current/national_assembly_13th_parliament_paginated")
+                .expect("Failed to read fixture");
+        assert!(
+            !members_or_not_found(&html, House::NationalAssembly, url, &SkipHook::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_page_range_rejects_invalid_range() {
+        let scraper = WebScraper::new().unwrap();
+
+        for (from, to) in [(5, 3), (0, 2)] {
+            let result =
+                futures::executor::block_on(scraper.fetch_sittings_page_range(None, from, to));
+            assert!(
+                matches!(result, Err(ScraperError::InvalidPageRange { from: f, to: t }) if f == from && t == to),
+                "{from}..={to} should be rejected before any request"
+            );
+        }
+    }
+
+    #[test]
+    fn test_dedup_overlapping_pages() {
+        let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
+            .expect("Failed to read fixture");
+        let (listings, _) = parse_hansard_list(&html, None).expect("Failed to parse hansard list");
+        assert!(
+            listings.len() >= 5,
+            "Fixture should have at least five listings"
+        );
+
+        let first_page = listings[..3].to_vec();
+        let second_page = listings[2..5].to_vec();
+        let combined: Vec<HansardListing> = futures::executor::block_on(
+            paged_stream(
+                async move { Ok((first_page, 2)) },
+                |_| future::ready(Ok(second_page.clone())),
+                "test",
+                None,
+                ProgressHook::default(),
+            )
+            .try_collect(),
+        )
+        .unwrap();
+        assert_eq!(combined.len(), 6);
+
+        let deduped = dedup_by_url(combined, |l| &l.url, "sitting");
+        assert_eq!(deduped, listings[..5].to_vec());
+        assert_eq!(
+            deduped.iter().filter(|l| l.url == listings[2].url).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_collect_in_range_stops_past_start() {
+        let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
+            .expect("Failed to read fixture");
+        let (page, _) = parse_hansard_list(&html, None).expect("Failed to parse hansard list");
+
+        let mut listings = Vec::new();
+        let reached_start = collect_in_range(
+            page,
+            None,
+            date(2025, 12, 1),
+            date(2026, 2, 10),
+            &mut listings,
+        );
+
+        assert!(reached_start, "November sittings are older than the start");
+        assert!(!listings.is_empty());
+        assert!(
+            listings
+                .iter()
+                .all(|l| l.date >= date(2025, 12, 1) && l.date <= date(2026, 2, 10))
+        );
+        assert!(listings.iter().any(|l| l.date == date(2026, 2, 10)));
+        assert!(!listings.iter().any(|l| l.date == date(2026, 2, 11)));
+    }
+
+    #[test]
+    fn test_collect_in_range_continues_when_start_not_reached() {
+        let html = fs::read_to_string("fixtures/current/Hansard_list_paginated")
+            .expect("Failed to read fixture");
+        let (page, _) = parse_hansard_list(&html, None).expect("Failed to parse hansard list");
+        let page_len = page.len();
+
+        let mut listings = Vec::new();
+        let reached_start = collect_in_range(
+            page,
+            Some(House::Senate),
+            date(2025, 1, 1),
+            date(2026, 12, 31),
+            &mut listings,
+        );
+
+        assert!(
+            !reached_start,
+            "Older pages may still hold sittings in range"
+        );
+        assert!(!listings.is_empty() && listings.len() < page_len);
+        assert!(listings.iter().all(|l| l.house == House::Senate));
+    }
+
+    #[test]
+    fn test_collect_matching_activity() {
+        let html = fs::read_to_string(
+            "fixtures/current/Boss_Gladys_Jepkosgei_with_paginated_contributions",
+        )
+        .expect("Failed to read fixture");
+        let page = parse_parliamentary_activity(&html).expect("Failed to parse activity");
+
+        let mut activity = Vec::new();
+        let filter = ActivityFilter {
+            since: Some(date(2025, 11, 15)),
+            ..Default::default()
+        };
+        assert!(collect_matching_activity(
+            page.clone(),
+            &filter,
+            &mut activity
+        ));
+        assert_eq!(activity.len(), 7);
+        assert!(
+            activity
+                .iter()
+                .all(|a| a.date == "26th November 2025" || a.date == "18th November 2025")
+        );
+
+        let mut activity = Vec::new();
+        let filter = ActivityFilter {
+            topic: Some("statements".to_string()),
+            contribution_type: Some("question time".to_string()),
+            since: Some(date(2025, 1, 1)),
+        };
+        assert!(!collect_matching_activity(page, &filter, &mut activity));
+        assert_eq!(activity.len(), 6);
+        assert!(
+            activity
+                .iter()
+                .all(|a| a.topic == "Statements" && a.contribution_type == "Question Time")
+        );
+
+        assert!(collect_matching_activity(
+            Vec::new(),
+            &ActivityFilter::default(),
+            &mut activity
+        ));
+    }
+
+    async fn serve(server: &MockServer, page_path: &str, fixture: &str) {
+        let html = fs::read_to_string(fixture).expect("Failed to read fixture");
+        Mock::given(method("GET"))
+            .and(path(page_path))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(html))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_with_base_url_fetches_from_mirror() {
+        let server = MockServer::start().await;
+        serve(
+            &server,
+            "/democracy-tools/hansard/",
+            "fixtures/current/Hansard_list_single_page",
+        )
+        .await;
+        serve(
+            &server,
+            "/mps-performance/national-assembly/13th-parliament/",
+            "fixtures/current/national_assembly_13th_parliament_paginated",
+        )
+        .await;
+
+        let scraper = WebScraper::new()
+            .unwrap()
+            .with_base_url(format!("{}/", server.uri()));
+
+        let listings = scraper.fetch_hansard_list(1, None).await.unwrap();
+        assert!(!listings.is_empty());
+
+        let members = scraper
+            .fetch_members(House::NationalAssembly, &Parliament::current(), 1)
+            .await
+            .unwrap();
+        assert!(!members.is_empty());
+        assert!(members.iter().all(|m| m.house == House::NationalAssembly));
+
+        let missing = scraper
+            .fetch_members(House::Senate, &Parliament::current(), 1)
+            .await;
+        assert!(matches!(missing, Err(ScraperError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_from_client_uses_supplied_client() {
+        let server = MockServer::start().await;
+        let html = fs::read_to_string("fixtures/current/Hansard_list_single_page")
+            .expect("Failed to read fixture");
+        Mock::given(method("GET"))
+            .and(path("/democracy-tools/hansard/"))
+            .and(header("user-agent", "hansard-app/2.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(html))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .user_agent("hansard-app/2.0")
+            .build()
+            .unwrap();
+        let scraper = WebScraper::from_client(client).with_base_url(server.uri());
+
+        let listings = scraper.fetch_hansard_list(1, None).await.unwrap();
+        assert!(!listings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_soft_not_found_page() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/democracy-tools/hansard/no-such-sitting/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<html><head><title>Page Not Found | Mzalendo</title></head><body></body></html>",
+            ))
+            .mount(&server)
+            .await;
+
+        let scraper = WebScraper::new().unwrap().with_base_url(server.uri());
+        let url = format!("{}/democracy-tools/hansard/no-such-sitting/", server.uri());
+
+        let result = scraper
+            .fetch_hansard_sitting(&url, ParseConfig::default())
+            .await;
+        assert!(matches!(result, Err(ScraperError::NotFound(u)) if u == url));
+    }
+
+    const SITTING_PATH: &str =
+        "/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+    /// A sitting page whose transcript has not been published yet.
+    const EMPTY_SITTING: &str = r#"<span class="house">National Assembly</span><article class="hansard-document"></article>"#;
+
+    #[tokio::test]
+    async fn test_retry_on_empty_sitting() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(SITTING_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_string(EMPTY_SITTING))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(SITTING_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_string(html))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let url = format!("{}{SITTING_PATH}", server.uri());
+
+        let scraper = WebScraper::new().unwrap().with_retry_on_empty(true);
+        let sitting = scraper
+            .fetch_hansard_sitting(&url, ParseConfig::default())
+            .await
+            .unwrap();
+        assert!(!sitting.sections.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_empty_sitting_without_retry() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(SITTING_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_string(EMPTY_SITTING))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let url = format!("{}{SITTING_PATH}", server.uri());
+
+        let sitting = WebScraper::new()
+            .unwrap()
+            .fetch_hansard_sitting(&url, ParseConfig::default())
+            .await
+            .unwrap();
+        assert!(sitting.sections.is_empty());
+    }
+}
@@ -1,9 +1,11 @@
 use chrono::{NaiveDate, NaiveTime};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
 pub use crate::types::House;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardListing {
     pub house: House,
     pub date: NaiveDate,
@@ -13,6 +15,7 @@ pub struct HansardListing {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardSitting {
     pub house: House,
     pub date: NaiveDate,
@@ -21,41 +24,209 @@ pub struct HansardSitting {
     pub time: Option<NaiveTime>,
     pub summary: Option<String>,
     pub sentiment: Option<String>,
+    pub sentiment_label: Option<Sentiment>,
     pub pdf_url: Option<String>,
+    /// The parliament as in member list URLs, e.g. "13th-parliament".
+    #[serde(default)]
+    pub parliament_number: Option<String>,
+    /// e.g. "Fourth Session", when the page names it.
+    #[serde(default)]
+    pub session_number: Option<String>,
     pub sections: Vec<HansardSection>,
+    pub divisions: Vec<Division>,
+    pub questions_status: Vec<QuestionStatusEntry>,
+}
+
+impl HansardSitting {
+    /// Every contribution in document order: each section's own contributions, then those of
+    /// its subsections.
+    pub fn all_contributions(&self) -> impl Iterator<Item = &Contribution> {
+        self.sections
+            .iter()
+            .flat_map(HansardSection::all_contributions)
+    }
+
+    /// Like [`all_contributions`](Self::all_contributions), but mutable.
+    pub fn all_contributions_mut(&mut self) -> impl Iterator<Item = &mut Contribution> {
+        self.sections.iter_mut().flat_map(|s| {
+            s.contributions.iter_mut().chain(
+                s.subsections
+                    .iter_mut()
+                    .flat_map(|sub| &mut sub.contributions),
+            )
+        })
+    }
+}
+
+/// A numbered question (e.g. "Question No.091/2025") and whether the House reached it.
+/// Questions only mentioned in passing, or deferred or dropped, are not reached.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QuestionStatusEntry {
+    pub number: u32,
+    pub subject: Option<String>,
+    pub reached: bool,
+}
+
+/// Heuristic classification of the "Sentimental Analysis" text on a sitting page.
+/// Text that matches none of the known labels is kept verbatim in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Sentiment {
+    Positive,
+    Negative,
+    Neutral,
+    Mixed,
+    Other { raw: String },
+}
+
+/// A recorded vote. Member lists are `None` when the transcript only announces the tallies.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Division {
+    pub ayes: u32,
+    pub noes: u32,
+    pub abstentions: u32,
+    pub result: String,
+    pub ayes_members: Option<Vec<String>>,
+    pub noes_members: Option<Vec<String>>,
+    pub abstentions_members: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardSubsection {
     pub title: String,
     pub contributions: Vec<Contribution>,
+    pub referred_to_committee: Option<String>,
+    pub statement_kind: Option<StatementKind>,
+}
+
+/// Genre of a statement subsection, e.g. the weekly House Business statement by the Leader of
+/// the Majority or Minority Party. Subsections outside statement sections carry no kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum StatementKind {
+    HouseBusiness,
+    PersonalStatement,
+    MinisterialStatement,
+    Other,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardSection {
     pub section_type: String,
     pub subsections: Vec<HansardSubsection>,
     pub contributions: Vec<Contribution>,
 }
 
+impl HansardSection {
+    /// The section's own contributions, then those of its subsections.
+    pub fn all_contributions(&self) -> impl Iterator<Item = &Contribution> {
+        self.contributions
+            .iter()
+            .chain(self.subsections.iter().flat_map(|sub| &sub.contributions))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Contribution {
     pub speaker_name: String,
+    /// The contributor line as scraped, e.g. with the constituency and party the name omits.
+    pub speaker_raw: String,
+    /// The chair or office the speaker spoke from, e.g. "The Temporary Speaker", split off the
+    /// name.
+    pub speaker_role: Option<String>,
     pub speaker_url: Option<String>,
     pub content: String,
     pub procedural_notes: Vec<String>,
+    pub contribution_kind: Option<ContributionKind>,
+}
+
+impl Display for Contribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.speaker_role {
+            Some(role) => write!(f, "{} ({})", self.speaker_name, role),
+            None => write!(f, "{}", self.speaker_name),
+        }
+    }
+}
+
+/// Role of a contribution in its subsection, e.g. a Member's question and the Cabinet
+/// Secretary's answer during Question Time. Remarks from the Chair, and contributions whose
+/// role cannot be told from the surrounding structure, carry no kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ContributionKind {
+    Question,
+    Answer,
+    Statement,
+    Debate,
+}
+
+/// One page of a paginated listing, with its place in the pagination.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub current_page: u32,
+    /// Pages in the listing; equal to `current_page` on the last page.
+    pub total_pages: u32,
+}
+
+impl<T> Page<T> {
+    pub fn has_next(&self) -> bool {
+        self.current_page < self.total_pages
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            current_page: self.current_page,
+            total_pages: self.total_pages,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Member {
     pub name: String,
     pub url: String,
     pub house: House,
     pub role: Option<String>,
+    pub photo_url: Option<String>,
+    /// Constituency of an elected MNA, e.g. "Kikuyu".
     pub constituency: Option<String>,
+    /// County of a senator or woman representative, e.g. "Uasin Gishu".
+    pub county: Option<String>,
 }
 
+/// A committee seat, e.g. "LIAISON" with the role "Chair".
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Committee {
+    pub name: String,
+    /// The member's role on the committee ("Chair", "Member", ...), when the profile says.
+    pub role: Option<String>,
+}
+
+impl Display for Committee {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.role {
+            Some(role) => write!(f, "{} ({})", self.name, role),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Bill {
     pub name: String,
     pub year: String,
@@ -63,16 +234,36 @@ pub struct Bill {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VoteRecord {
-    pub date: String,
+    pub date: Option<NaiveDate>,
     pub title: String,
     pub url: Option<String>,
-    pub decision: String,
+    pub decision: VoteDecision,
+    /// The decision badge as shown on the site, kept for decisions classified as `Unknown`.
+    pub raw_decision: String,
+}
+
+/// How a member voted on a division, classified from the decision badge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum VoteDecision {
+    Yes,
+    No,
+    Abstain,
+    Absent,
+    Unknown,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ParliamentaryActivity {
+    /// The date as shown on the site, e.g. "19th November 2025".
     pub date: String,
+    /// `date` as a calendar date; `None` when it is not in the expected format.
+    #[serde(default)]
+    pub date_parsed: Option<NaiveDate>,
     pub topic: String,
     pub contribution_type: String,
     pub section_title: String,
@@ -81,17 +272,76 @@ pub struct ParliamentaryActivity {
     pub url: String,
 }
 
+impl ParliamentaryActivity {
+    /// `date` as a calendar date, e.g. "19th November 2025"; `None` if it cannot be read.
+    /// Falls back to parsing `date` for activity saved before `date_parsed` was recorded.
+    pub fn parsed_date(&self) -> Option<NaiveDate> {
+        self.date_parsed
+            .or_else(|| super::parser::parse_activity_date(&self.date))
+    }
+}
+
+/// Narrows a member's activity. Topic and contribution type match case-insensitively, the
+/// topic on any part of it; unset fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActivityFilter {
+    pub topic: Option<String>,
+    pub contribution_type: Option<String>,
+    /// Only activity on or after this date. Activity whose date cannot be read is kept.
+    pub since: Option<NaiveDate>,
+}
+
+impl ActivityFilter {
+    pub fn matches(&self, activity: &ParliamentaryActivity) -> bool {
+        let topic = self.topic.as_deref().is_none_or(|topic| {
+            activity
+                .topic
+                .to_lowercase()
+                .contains(&topic.trim().to_lowercase())
+        });
+        let contribution_type = self.contribution_type.as_deref().is_none_or(|ty| {
+            activity
+                .contribution_type
+                .trim()
+                .eq_ignore_ascii_case(ty.trim())
+        });
+        let since = self
+            .since
+            .is_none_or(|since| activity.parsed_date().is_none_or(|date| date >= since));
+        topic && contribution_type && since
+    }
+}
+
 // TODO: verify validity of counts to actual length of parsed data
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MemberProfile {
     pub name: String,
     pub slug: String,
+    /// House from the profile URL, e.g. `/mps-performance/senate/...`; `None` when the URL is
+    /// not a member profile path.
+    #[serde(default)]
+    pub house: Option<House>,
+    /// Parliament from the profile URL, e.g. "13th-parliament".
+    #[serde(default)]
+    pub parliament: Option<String>,
     pub photo_url: Option<String>,
     pub biography: Option<String>,
+    /// Sentences of the biography about the member's schooling.
+    pub education: Option<String>,
+    /// Year the member was first elected, from phrases like "first elected in 2013".
+    pub first_elected: Option<u16>,
     pub position_type: Option<String>,
     pub positions: Vec<String>,
+    /// Start of the member's current term, which differs from the general election date for
+    /// by-elections and nominations.
+    pub assumed_office: Option<NaiveDate>,
+    /// End of the term when the member left mid-term; `None` while still serving.
+    pub left_office: Option<NaiveDate>,
     pub party: Option<String>,
-    pub committees: Vec<String>,
+    pub email: Option<String>,
+    pub telephone: Option<String>,
+    pub committees: Vec<Committee>,
     pub speeches_last_year: Option<u32>,
     pub speeches_total: Option<u32>,
     pub bills: Vec<Bill>,
@@ -2,11 +2,63 @@ pub(crate) mod archive;
 pub(crate) mod current;
 pub mod types;
 pub mod unified;
+pub(crate) mod util;
 
-pub use types::House;
-pub use unified::scraper::{HansardScraper, ScraperError};
+pub use current::parser::ParseConfig;
+pub use types::{CURRENT_PARLIAMENT, FetchProgress, House, Parliament};
+pub use unified::MarkdownOptions;
+pub use unified::analysis::{
+    ListingStats, SittingStats, SpeakerStats, VoteSummary, WORDS_PER_MINUTE, common_speakers,
+    distinct_speaker_names, group_by_date,
+};
+pub use unified::scraper::{
+    AllHousesMembers, HansardScraper, ScraperError, ScraperErrorKind, fetch_sitting,
+};
+pub use unified::search::{
+    MemberMatch, best_member_match, match_score, normalize_search_text, rank_members,
+};
 pub use unified::types::{
-    Bill, Contribution, DataSource, HansardListing, HansardSection, HansardSitting,
-    HansardSubsection, Member, MemberProfile, ParliamentaryActivity, SittingListOptions,
-    VoteRecord,
+    ActivityFilter, Bill, Committee, Contribution, ContributionKind, DataSource, Division,
+    HansardListing, HansardSection, HansardSitting, HansardSubsection, ListingSort, Member,
+    MemberProfile, MemberSort, Page, ParliamentaryActivity, PersonDetails, QuestionStatusEntry,
+    Sentiment, SittingListOptions, SittingListOptionsBuilder, StatementKind, VoteDecision,
+    VoteRecord, Warning,
 };
+
+/// JSON Schemas for the scraper's output types, keyed by type name, e.g. to validate stored
+/// JSON or build an OpenAPI spec.
+#[cfg(feature = "schema")]
+pub fn schemas() -> std::collections::BTreeMap<&'static str, schemars::Schema> {
+    use schemars::schema_for;
+
+    std::collections::BTreeMap::from([
+        ("Bill", schema_for!(Bill)),
+        ("Committee", schema_for!(Committee)),
+        ("HansardListing", schema_for!(HansardListing)),
+        ("HansardSitting", schema_for!(HansardSitting)),
+        ("Member", schema_for!(Member)),
+        ("MemberProfile", schema_for!(MemberProfile)),
+        ("ParliamentaryActivity", schema_for!(ParliamentaryActivity)),
+        ("PersonDetails", schema_for!(PersonDetails)),
+        ("VoteRecord", schema_for!(VoteRecord)),
+        ("Warning", schema_for!(Warning)),
+    ])
+}
+
+#[cfg(all(test, feature = "schema"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schemas() {
+        let schemas = schemas();
+        let sitting = serde_json::to_value(&schemas["HansardSitting"]).unwrap();
+        assert_eq!(sitting["title"], "HansardSitting");
+        assert!(sitting["properties"]["sections"].is_object());
+        assert_eq!(
+            sitting["$defs"]["DataSource"]["enum"],
+            serde_json::json!(["https://info.mzalendo.com", "https://mzalendo.com"])
+        );
+        assert!(schemas["MemberProfile"].as_object().is_some());
+    }
+}
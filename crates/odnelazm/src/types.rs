@@ -1,13 +1,19 @@
+use std::sync::{Arc, LazyLock};
 use std::{fmt::Display, str::FromStr};
 
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, thiserror::Error)]
-#[error("Invalid house '{0}'. Accepted values: 'senate', 'national_assembly', 'na'")]
+#[error(
+    "Invalid house '{0}'. Accepted values: 'senate', 'national_assembly', 'national-assembly', 'national assembly', 'na'"
+)]
 pub struct HouseParseError(String);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+/// Serializes as its snake_case name (`national_assembly`); deserializes from any form
+/// [`House::from_str`] accepts, so the two round-trip identically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum House {
     Senate,
@@ -21,20 +27,54 @@ impl House {
             House::NationalAssembly => "national-assembly",
         }
     }
+
+    /// The [`slug`](Self::slug) as a JSON value, for emitting data in the hyphenated form other
+    /// mzalendo tooling expects rather than the default `snake_case`.
+    pub fn to_slug_value(&self) -> serde_json::Value {
+        serde_json::Value::from(self.slug())
+    }
+}
+
+/// Serde helper for `#[serde(with = "odnelazm::types::house_as_slug")]`, writing a [`House`]
+/// as its [`slug`](House::slug) (`national-assembly`). Reading accepts any form
+/// [`House::from_str`] does, so data in the default representation still loads.
+pub mod house_as_slug {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use super::House;
+
+    pub fn serialize<S: Serializer>(house: &House, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(house.slug())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<House, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
 }
 
 impl FromStr for House {
     type Err = HouseParseError;
 
+    /// Case-insensitive; accepts the serde name, [`slug`](Self::slug), and display forms.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
+        match s.trim().to_lowercase().as_str() {
             "senate" => Ok(House::Senate),
-            "national_assembly" | "na" => Ok(House::NationalAssembly),
+            "national_assembly" | "national-assembly" | "national assembly" | "na" => {
+                Ok(House::NationalAssembly)
+            }
             _ => Err(HouseParseError(s.to_string())),
         }
     }
 }
 
+impl<'de> Deserialize<'de> for House {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 impl Display for House {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -43,3 +83,280 @@ impl Display for House {
         }
     }
 }
+
+/// Slug of the sitting parliament, the default wherever a parliament is optional.
+pub const CURRENT_PARLIAMENT: &str = "13th-parliament";
+
+static RE_PARLIAMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(\d+)(st|nd|rd|th)-parliament$").expect("invalid regex: parliament")
+});
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "Invalid parliament '{0}'. Expected an ordinal followed by '-parliament', e.g. '13th-parliament', '12th-parliament', '1st-parliament'"
+)]
+pub struct ParliamentParseError(String);
+
+/// A parliament session as it appears in member list URLs, e.g. `13th-parliament`.
+///
+/// Parsing lowercases the input and checks the ordinal suffix against the number, so
+/// `13TH-Parliament` is accepted while `13thparliament` and `13st-parliament` are not.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Parliament(String);
+
+impl Parliament {
+    /// The sitting parliament, [`CURRENT_PARLIAMENT`].
+    pub fn current() -> Self {
+        Self(CURRENT_PARLIAMENT.to_string())
+    }
+
+    pub fn number(&self) -> u32 {
+        let digits = self.0.trim_end_matches(|c: char| !c.is_ascii_digit());
+        digits.parse().expect("validated on parse")
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for Parliament {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+impl FromStr for Parliament {
+    type Err = ParliamentParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let slug = s.trim().to_lowercase();
+        let number = RE_PARLIAMENT
+            .captures(&slug)
+            .and_then(|caps| {
+                let number: u32 = caps[1].parse().ok()?;
+                (number > 0 && &caps[2] == ordinal_suffix(number)).then_some(number)
+            })
+            .ok_or_else(|| ParliamentParseError(s.to_string()))?;
+        Ok(Self(format!(
+            "{number}{}-parliament",
+            ordinal_suffix(number)
+        )))
+    }
+}
+
+impl Display for Parliament {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn ordinal_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Page progress reported while a paged fetch fans out, once as each page resolves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FetchProgress {
+    pub completed: u32,
+    pub total: u32,
+}
+
+/// Optional progress callback shared by clones of a scraper. Reporting is a no-op when unset.
+#[derive(Clone, Default)]
+pub(crate) struct ProgressHook(Option<Arc<dyn Fn(FetchProgress) + Send + Sync>>);
+
+impl ProgressHook {
+    pub(crate) fn new(callback: impl Fn(FetchProgress) + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(callback)))
+    }
+
+    pub(crate) fn report(&self, completed: u32, total: u32) {
+        if let Some(callback) = &self.0 {
+            callback(FetchProgress { completed, total });
+        }
+    }
+}
+
+impl std::fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProgressHook")
+            .field(&self.0.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// Rows of a listing page that were parsed and those left out, so a crawl that drops rows
+/// does not go unnoticed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ParseReport {
+    pub(crate) parsed: usize,
+    /// Why each malformed row was left out, e.g. "listing 'Hansard Report' without a link".
+    pub(crate) skipped: Vec<String>,
+}
+
+impl ParseReport {
+    pub(crate) fn skip(&mut self, reason: String) {
+        self.skipped.push(reason);
+    }
+}
+
+type SkipCallback = dyn Fn(&str) + Send + Sync;
+
+/// Optional callback told of each listing row a parser left out, shared by clones of a
+/// scraper. Without one, only the number of rows left out is logged.
+#[derive(Clone, Default)]
+pub(crate) struct SkipHook(Option<Arc<SkipCallback>>);
+
+impl SkipHook {
+    pub(crate) fn new(callback: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(callback)))
+    }
+
+    pub(crate) fn report(&self, report: ParseReport) {
+        if report.skipped.is_empty() {
+            return;
+        }
+        log::warn!(
+            "Left out {} of {} rows on the page",
+            report.skipped.len(),
+            report.parsed + report.skipped.len()
+        );
+        if let Some(callback) = &self.0 {
+            report.skipped.iter().for_each(|reason| callback(reason));
+        }
+    }
+}
+
+impl std::fmt::Debug for SkipHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SkipHook")
+            .field(&self.0.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_house() {
+        for (spellings, house) in [
+            (
+                ["senate", "Senate", "SENATE", " senate "].as_slice(),
+                House::Senate,
+            ),
+            (
+                [
+                    "national_assembly",
+                    "national-assembly",
+                    "national assembly",
+                    "National Assembly",
+                    "NATIONAL_ASSEMBLY",
+                    "na",
+                    "NA",
+                ]
+                .as_slice(),
+                House::NationalAssembly,
+            ),
+        ] {
+            for spelling in spellings {
+                assert_eq!(spelling.parse::<House>().unwrap(), house, "{spelling}");
+            }
+        }
+
+        for house in [House::Senate, House::NationalAssembly] {
+            assert_eq!(House::from_str(house.slug()).unwrap(), house);
+            assert_eq!(House::from_str(&house.to_string()).unwrap(), house);
+            assert!(!format!("{house}").ends_with('\n'));
+
+            let json = serde_json::to_value(house).unwrap();
+            assert_eq!(House::from_str(json.as_str().unwrap()).unwrap(), house);
+            assert_eq!(serde_json::from_value::<House>(json).unwrap(), house);
+            assert_eq!(
+                serde_json::from_value::<House>(house.to_string().into()).unwrap(),
+                house
+            );
+        }
+        assert!(serde_json::from_str::<House>(r#""county""#).is_err());
+
+        assert!("national".parse::<House>().is_err());
+        assert!("".parse::<House>().is_err());
+    }
+
+    #[test]
+    fn test_house_as_slug() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Row {
+            #[serde(with = "house_as_slug")]
+            house: House,
+        }
+
+        let row = Row {
+            house: House::NationalAssembly,
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"house":"national-assembly"}"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+        assert_eq!(
+            serde_json::from_str::<Row>(r#"{"house":"national_assembly"}"#).unwrap(),
+            row
+        );
+        assert!(serde_json::from_str::<Row>(r#"{"house":"county"}"#).is_err());
+
+        assert_eq!(
+            serde_json::to_value(House::NationalAssembly).unwrap(),
+            "national_assembly",
+            "The default representation is unchanged"
+        );
+        assert_eq!(House::NationalAssembly.to_slug_value(), "national-assembly");
+    }
+
+    #[test]
+    fn test_parse_parliament() {
+        let parliament: Parliament = " 13TH-Parliament ".parse().unwrap();
+        assert_eq!(parliament.as_str(), "13th-parliament");
+        assert_eq!(parliament.number(), 13);
+        assert_eq!(parliament, Parliament::default());
+        assert_eq!(
+            CURRENT_PARLIAMENT.parse::<Parliament>().unwrap(),
+            Parliament::current()
+        );
+
+        for valid in [
+            "1st-parliament",
+            "2nd-parliament",
+            "3rd-parliament",
+            "11th-parliament",
+        ] {
+            assert_eq!(valid.parse::<Parliament>().unwrap().to_string(), valid);
+        }
+        assert_eq!(
+            "012th-parliament".parse::<Parliament>().unwrap().as_str(),
+            "12th-parliament"
+        );
+
+        for invalid in [
+            "13thparliament",
+            "13th",
+            "13st-parliament",
+            "11st-parliament",
+            "0th-parliament",
+            "thirteenth-parliament",
+            "",
+        ] {
+            let err = invalid.parse::<Parliament>().unwrap_err();
+            assert!(
+                err.to_string().contains("e.g. '13th-parliament'"),
+                "{invalid} should be rejected with the expected format"
+            );
+        }
+    }
+}
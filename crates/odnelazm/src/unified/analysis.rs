@@ -0,0 +1,679 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{self, Display};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::types::{
+    Contribution, HansardListing, HansardSitting, House, MemberProfile, VoteDecision,
+};
+
+/// Average speaking rate used to estimate speaking time from word counts.
+pub const WORDS_PER_MINUTE: f64 = 130.0;
+
+/// Words spoken by one speaker in a sitting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeakerStats {
+    /// The speaker's name as first given in the transcript.
+    pub speaker_name: String,
+    pub words: usize,
+    pub contributions: usize,
+    /// `words` at [`WORDS_PER_MINUTE`], rounded to one decimal place.
+    pub speaking_minutes: f64,
+}
+
+/// Per-speaker word counts for a sitting; see [`HansardSitting::stats`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SittingStats {
+    pub total_words: usize,
+    /// Most words first; ties keep the order speakers first spoke in.
+    pub speakers: Vec<SpeakerStats>,
+}
+
+impl Contribution {
+    /// Words in the spoken content, excluding procedural notes.
+    pub fn word_count(&self) -> usize {
+        self.content.split_whitespace().count()
+    }
+}
+
+impl HansardSitting {
+    /// Contributions across every section and subsection.
+    pub fn contribution_count(&self) -> usize {
+        self.all_contributions().count()
+    }
+
+    /// Speakers who made at least one contribution, matched on the same normalized name as
+    /// [`stats`](Self::stats): "Hon. Gladys Wanga" and "Sen. Gladys Wanga (Homa Bay County)"
+    /// count once.
+    pub fn distinct_speakers(&self) -> usize {
+        distinct_speaker_names(self.all_contributions()).len()
+    }
+
+    /// Word counts and estimated speaking time per speaker.
+    ///
+    /// Contributions are walked in document order. One without a speaker name continues the
+    /// previous speaker's turn, so its words go to that speaker without counting as a new
+    /// contribution; words before the first named speaker only count towards `total_words`.
+    /// Speakers are matched on the same normalized name as [`common_speakers`], so
+    /// "Hon. Gladys Wanga (Homa Bay County, ODM)" and "Hon. Gladys Wanga" are one speaker.
+    pub fn stats(&self) -> SittingStats {
+        let mut stats = SittingStats::default();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut current: Option<usize> = None;
+
+        for contribution in self.all_contributions() {
+            let words = contribution.word_count();
+            stats.total_words += words;
+
+            let name = normalize_speaker_name(&contribution.speaker_name);
+            if name.is_empty() {
+                if let Some(i) = current {
+                    stats.speakers[i].words += words;
+                }
+                continue;
+            }
+
+            let i = *index.entry(name).or_insert_with(|| {
+                stats.speakers.push(SpeakerStats {
+                    speaker_name: contribution.speaker_name.trim().to_string(),
+                    words: 0,
+                    contributions: 0,
+                    speaking_minutes: 0.0,
+                });
+                stats.speakers.len() - 1
+            });
+            stats.speakers[i].words += words;
+            stats.speakers[i].contributions += 1;
+            current = Some(i);
+        }
+
+        for speaker in &mut stats.speakers {
+            speaker.speaking_minutes =
+                (speaker.words as f64 / WORDS_PER_MINUTE * 10.0).round() / 10.0;
+        }
+        stats.speakers.sort_by_key(|s| std::cmp::Reverse(s.words));
+        stats
+    }
+
+    /// Every contribution grouped by speaker, in document order within each speaker.
+    ///
+    /// Keys are speaker names without honorifics or constituency annotations, e.g. "Gladys
+    /// Wanga" for "Hon. Gladys Wanga (Homa Bay County, ODM)", spelled as the speaker first
+    /// appears. Names differing only in case or honorifics share one key. Contributions
+    /// without a speaker name continue the previous speaker's turn and are grouped with it;
+    /// any before the first named speaker are left out.
+    pub fn contributions_by_speaker(&self) -> BTreeMap<String, Vec<&Contribution>> {
+        let mut keys: HashMap<String, String> = HashMap::new();
+        let mut grouped: BTreeMap<String, Vec<&Contribution>> = BTreeMap::new();
+        let mut current: Option<String> = None;
+
+        for contribution in self.all_contributions() {
+            let name = strip_speaker_name(&contribution.speaker_name);
+            if !name.is_empty() {
+                let key = keys.entry(name.to_lowercase()).or_insert(name).clone();
+                current = Some(key);
+            }
+            if let Some(key) = &current {
+                grouped.entry(key.clone()).or_default().push(contribution);
+            }
+        }
+        grouped
+    }
+}
+
+/// Each speaker of `contributions` once, spelled as they first appear, in order of first
+/// appearance. Names are matched as in [`HansardSitting::distinct_speakers`], so "Hon. Gladys
+/// Wanga" and "Sen. Gladys Wanga (Homa Bay County)" are one speaker.
+pub fn distinct_speaker_names<'a>(
+    contributions: impl IntoIterator<Item = &'a Contribution>,
+) -> Vec<String> {
+    let mut seen = HashSet::new();
+    contributions
+        .into_iter()
+        .filter(|c| {
+            let name = normalize_speaker_name(&c.speaker_name);
+            !name.is_empty() && seen.insert(name)
+        })
+        .map(|c| c.speaker_name.trim().to_string())
+        .collect()
+}
+
+/// Speakers that contributed to both sittings.
+///
+/// Each speaker is keyed by their profile URL path when the transcript links one (e.g.
+/// `/mps-performance/national-assembly/13th-parliament/gladys-wanga`), so absolute and relative
+/// links match. Otherwise the key is the normalized name: lowercased, without honorifics or a
+/// trailing "(Constituency, Party)" annotation.
+pub fn common_speakers(a: &HansardSitting, b: &HansardSitting) -> HashSet<String> {
+    let a = speaker_keys(a);
+    let b = speaker_keys(b);
+    a.intersection(&b).cloned().collect()
+}
+
+/// Listings grouped by sitting date, earliest first, e.g. to line up both houses' sittings on
+/// a calendar. Listings within a day keep their input order.
+pub fn group_by_date(listings: &[HansardListing]) -> BTreeMap<NaiveDate, Vec<&HansardListing>> {
+    let mut grouped: BTreeMap<NaiveDate, Vec<&HansardListing>> = BTreeMap::new();
+    for listing in listings {
+        grouped.entry(listing.date).or_default().push(listing);
+    }
+    grouped
+}
+
+/// Session type key for listings that carry none, which includes every archive listing.
+const UNSPECIFIED_SESSION: &str = "Unspecified";
+
+/// Sitting counts for a set of listings, per house and per session type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListingStats {
+    pub total: usize,
+    pub senate: usize,
+    pub national_assembly: usize,
+    /// Keyed by session type, e.g. "Afternoon Sitting"; listings without one are counted
+    /// under "Unspecified".
+    pub by_session_type: BTreeMap<String, usize>,
+}
+
+impl ListingStats {
+    pub fn from_hansard_listings(listings: &[HansardListing]) -> Self {
+        let mut stats = Self {
+            total: listings.len(),
+            ..Self::default()
+        };
+        for listing in listings {
+            match listing.house {
+                House::Senate => stats.senate += 1,
+                House::NationalAssembly => stats.national_assembly += 1,
+            }
+            let session_type = listing
+                .session_type
+                .as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .unwrap_or(UNSPECIFIED_SESSION);
+            *stats
+                .by_session_type
+                .entry(session_type.to_string())
+                .or_default() += 1;
+        }
+        stats
+    }
+}
+
+impl Display for ListingStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Sittings: {}", self.total)?;
+        writeln!(f, "  {}: {}", House::Senate, self.senate)?;
+        writeln!(
+            f,
+            "  {}: {}",
+            House::NationalAssembly,
+            self.national_assembly
+        )?;
+        write!(f, "By session type:")?;
+        for (session_type, count) in &self.by_session_type {
+            write!(f, "\n  {session_type}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// How a member voted across the divisions on their profile; see
+/// [`MemberProfile::vote_summary`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VoteSummary {
+    pub yes: usize,
+    pub no: usize,
+    pub abstain: usize,
+    pub absent: usize,
+    /// Votes whose decision could not be classified, left out of `participation_rate`.
+    pub unknown: usize,
+    /// Share of divisions the member voted in (yes, no or abstain) out of those with a known
+    /// decision, between 0 and 1; 0 when there are none.
+    pub participation_rate: f64,
+}
+
+impl MemberProfile {
+    /// Counts of each vote decision in `voting_patterns`, all zero when there are none.
+    pub fn vote_summary(&self) -> VoteSummary {
+        let mut summary = VoteSummary::default();
+        for vote in &self.voting_patterns {
+            match vote.decision {
+                VoteDecision::Yes => summary.yes += 1,
+                VoteDecision::No => summary.no += 1,
+                VoteDecision::Abstain => summary.abstain += 1,
+                VoteDecision::Absent => summary.absent += 1,
+                VoteDecision::Unknown => summary.unknown += 1,
+            }
+        }
+
+        let voted = summary.yes + summary.no + summary.abstain;
+        let known = voted + summary.absent;
+        if known > 0 {
+            summary.participation_rate = voted as f64 / known as f64;
+        }
+        summary
+    }
+}
+
+impl Display for VoteSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Yes: {}, No: {}, Abstain: {}, Absent: {}",
+            self.yes, self.no, self.abstain, self.absent
+        )?;
+        if self.unknown > 0 {
+            write!(f, ", Unknown: {}", self.unknown)?;
+        }
+        write!(
+            f,
+            " ({:.0}% participation)",
+            self.participation_rate * 100.0
+        )
+    }
+}
+
+impl Display for MemberProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(house) = self.house {
+            write!(f, "\n  House: {house}")?;
+        }
+        if let Some(party) = &self.party {
+            write!(f, "\n  Party: {party}")?;
+        }
+        if !self.positions.is_empty() {
+            write!(f, "\n  Positions: {}", self.positions.join(", "))?;
+        }
+        if !self.committees.is_empty() {
+            let committees: Vec<String> = self.committees.iter().map(|c| c.to_string()).collect();
+            write!(f, "\n  Committees: {}", committees.join(", "))?;
+        }
+        if let Some(total) = self.speeches_total {
+            write!(f, "\n  Speeches: {total}")?;
+        }
+        if let Some(total) = self.bills_total {
+            write!(f, "\n  Bills: {total}")?;
+        }
+        write!(f, "\n  Votes: {}", self.vote_summary())
+    }
+}
+
+fn speaker_keys(sitting: &HansardSitting) -> HashSet<String> {
+    sitting
+        .all_contributions()
+        .filter_map(speaker_key)
+        .collect()
+}
+
+fn speaker_key(contribution: &Contribution) -> Option<String> {
+    if let Some(url) = &contribution.speaker_url {
+        let path = url
+            .split_once("://")
+            .map_or(url.as_str(), |(_, rest)| {
+                rest.find('/').map_or("", |i| &rest[i..])
+            })
+            .trim_end_matches('/');
+        if !path.is_empty() {
+            return Some(path.to_string());
+        }
+    }
+
+    let name = normalize_speaker_name(&contribution.speaker_name);
+    (!name.is_empty()).then_some(name)
+}
+
+fn normalize_speaker_name(name: &str) -> String {
+    strip_speaker_name(name).to_lowercase()
+}
+
+/// The speaker's name without honorifics or a trailing "(Constituency, Party)" annotation.
+fn strip_speaker_name(name: &str) -> String {
+    let name = match name.trim_end().strip_suffix(')').and_then(|n| n.rfind('(')) {
+        Some(i) if i > 0 => &name[..i],
+        _ => name,
+    };
+    name.replace('’', "'")
+        .split_whitespace()
+        .filter(|word| {
+            !matches!(
+                word.to_lowercase().as_str(),
+                "hon." | "hon" | "sen." | "sen" | "dr." | "dr" | "(dr.)" | "prof." | "eng."
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::current::parser::parse_hansard_sitting;
+    use crate::unified::types::DataSource;
+    use std::fs;
+
+    fn sitting(fixture: &str, url: &str) -> HansardSitting {
+        let html = fs::read_to_string(fixture).expect("Failed to read fixture");
+        HansardSitting::from_current(
+            parse_hansard_sitting(&html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_common_speakers_across_fixtures() {
+        let a = sitting(
+            "fixtures/current/national_assembly_hansard_sitting",
+            "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/",
+        );
+        let b = sitting(
+            "fixtures/current/national_assembly_hansard_sitting_new_format",
+            "https://mzalendo.com/democracy-tools/hansard/tuesday-17th-february-2026-afternoon-sitting-2450/",
+        );
+
+        let common = common_speakers(&a, &b);
+
+        assert!(
+            common.contains(
+                "/mps-performance/national-assembly/13th-parliament/anthony-kimani-ichungwah"
+            ),
+            "Absolute and relative profile links should match, got: {:?}",
+            common
+        );
+        assert!(
+            common.contains(
+                "/mps-performance/national-assembly/13th-parliament/gideon-ochanda-ogolla"
+            )
+        );
+        assert!(
+            !common.contains(
+                "/mps-performance/national-assembly/13th-parliament/chumel-moroto-samuel"
+            ),
+            "Speakers from only one sitting should be excluded"
+        );
+        assert_eq!(common, common_speakers(&b, &a));
+        assert!(common.len() < speaker_keys(&a).len());
+    }
+
+    #[test]
+    fn test_normalize_speaker_name() {
+        assert_eq!(
+            normalize_speaker_name("Hon. Kimani Ichung’wah (Kikuyu, UDA)"),
+            "kimani ichung'wah"
+        );
+        assert_eq!(
+            normalize_speaker_name("Sen. (Dr.) Boni Khalwale"),
+            "boni khalwale"
+        );
+        assert_eq!(normalize_speaker_name("Hon. Speaker"), "speaker");
+    }
+
+    #[test]
+    fn test_stats_attributes_continuations() {
+        let mut a = sitting(
+            "fixtures/current/national_assembly_hansard_sitting",
+            "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/",
+        );
+        let before = a.stats();
+        assert!(!before.speakers.is_empty());
+        assert!(
+            before.speakers.windows(2).all(|w| w[0].words >= w[1].words),
+            "Speakers are ranked by words"
+        );
+
+        let (section, position) = a
+            .sections
+            .iter_mut()
+            .find_map(|s| {
+                let position = s
+                    .contributions
+                    .iter()
+                    .position(|c| !normalize_speaker_name(&c.speaker_name).is_empty())?;
+                Some((s, position))
+            })
+            .expect("Fixture should have a named speaker");
+        let mut continuation = section.contributions[position].clone();
+        let speaker = normalize_speaker_name(&continuation.speaker_name);
+        continuation.speaker_name = String::new();
+        continuation.content = "I thank you, Hon. Speaker.".to_string();
+        section
+            .contributions
+            .insert(position + 1, continuation.clone());
+
+        let after = a.stats();
+        let find = |stats: &SittingStats| {
+            stats
+                .speakers
+                .iter()
+                .find(|s| normalize_speaker_name(&s.speaker_name) == speaker)
+                .cloned()
+                .expect("Speaker should have stats")
+        };
+        assert_eq!(after.total_words, before.total_words + 5);
+        assert_eq!(find(&after).words, find(&before).words + 5);
+        assert_eq!(find(&after).contributions, find(&before).contributions);
+        assert_eq!(after.speakers.len(), before.speakers.len());
+        assert_eq!(continuation.word_count(), 5);
+    }
+
+    #[test]
+    fn test_speaking_minutes() {
+        let html = format!(
+            r#"
+            <span class="house">Senate</span>
+            <article class="hansard-document">
+            <h2 class="major-section-header">MOTIONS</h2>
+            <div class="contributor-name">Sen. Cherarkey</div>
+            <div class="speech-content"><p>{}</p></div>
+            </article>
+        "#,
+            vec!["word"; 195].join(" ")
+        );
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2434/";
+
+        let stats = HansardSitting::from_current(
+            parse_hansard_sitting(&html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        )
+        .stats();
+
+        assert_eq!(stats.total_words, 195);
+        assert_eq!(stats.speakers.len(), 1);
+        assert_eq!(stats.speakers[0].speaker_name, "Sen. Cherarkey");
+        assert_eq!(stats.speakers[0].contributions, 1);
+        assert_eq!(stats.speakers[0].speaking_minutes, 1.5);
+    }
+
+    #[test]
+    fn test_contribution_count_and_distinct_speakers() {
+        let mut a = sitting(
+            "fixtures/current/national_assembly_hansard_sitting",
+            "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/",
+        );
+        let count = a.contribution_count();
+        let speakers = a.distinct_speakers();
+        assert_eq!(count, a.all_contributions().count());
+        assert!(speakers > 0 && speakers <= count);
+        assert_eq!(speakers, a.stats().speakers.len());
+
+        let section = a
+            .sections
+            .iter_mut()
+            .find(|s| !s.contributions.is_empty())
+            .expect("Fixture should have contributions");
+        let mut first = section.contributions[0].clone();
+        first.speaker_name = "Hon. Gladys Wanga (Homa Bay County, ODM)".to_string();
+        let mut again = first.clone();
+        again.speaker_name = "Sen. Gladys Wanga".to_string();
+        let mut unnamed = first.clone();
+        unnamed.speaker_name = String::new();
+        section.contributions.extend([first, again, unnamed]);
+
+        assert_eq!(a.contribution_count(), count + 3);
+        assert_eq!(a.distinct_speakers(), speakers + 1);
+
+        let names = distinct_speaker_names(a.all_contributions());
+        assert_eq!(names.len(), speakers + 1);
+        assert!(
+            names
+                .iter()
+                .any(|n| n == "Hon. Gladys Wanga (Homa Bay County, ODM)")
+        );
+        assert!(!names.iter().any(|n| n == "Sen. Gladys Wanga"));
+    }
+
+    #[test]
+    fn test_contributions_by_speaker() {
+        let mut a = sitting(
+            "fixtures/current/national_assembly_hansard_sitting",
+            "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/",
+        );
+        let grouped = a.contributions_by_speaker();
+
+        assert!(
+            grouped
+                .keys()
+                .all(|k| !k.starts_with("Hon.") && !k.ends_with(')')),
+            "Keys should be stripped of honorifics and annotations, got: {:?}",
+            grouped.keys().collect::<Vec<_>>()
+        );
+        let named = a
+            .all_contributions()
+            .filter(|c| !c.speaker_name.trim().is_empty())
+            .count();
+        assert!(grouped.values().map(Vec::len).sum::<usize>() >= named);
+
+        // Appended as a last section, so no unnamed contribution of the fixture follows them
+        let mut section = a
+            .sections
+            .iter()
+            .find(|s| !s.contributions.is_empty())
+            .cloned()
+            .expect("Fixture should have contributions");
+        let mut first = section.contributions[0].clone();
+        first.speaker_name = "Hon. Gladys Wanga (Homa Bay County, ODM)".to_string();
+        let mut again = first.clone();
+        again.speaker_name = "HON. GLADYS WANGA".to_string();
+        let mut continuation = first.clone();
+        continuation.speaker_name = String::new();
+        continuation.content = "Thank you.".to_string();
+        section.contributions = vec![first, again, continuation];
+        section.subsections.clear();
+        a.sections.push(section);
+
+        let grouped = a.contributions_by_speaker();
+        let wanga = &grouped["Gladys Wanga"];
+        assert_eq!(wanga.len(), 3);
+        assert_eq!(wanga[2].content, "Thank you.");
+        assert!(!grouped.contains_key("GLADYS WANGA"));
+    }
+
+    #[test]
+    fn test_group_by_date() {
+        let listing = |house: House, date: &str, title: &str| HansardListing {
+            house,
+            date: date.parse().unwrap(),
+            url: String::new(),
+            title: title.to_string(),
+            session_type: None,
+            start_time: None,
+            end_time: None,
+            source: DataSource::Current,
+        };
+        let listings = vec![
+            listing(House::Senate, "2026-02-12", "Senate afternoon"),
+            listing(House::NationalAssembly, "2026-02-11", "NA morning"),
+            listing(House::NationalAssembly, "2026-02-12", "NA afternoon"),
+            listing(House::NationalAssembly, "2026-02-11", "NA afternoon"),
+        ];
+
+        let grouped = group_by_date(&listings);
+
+        let dates: Vec<String> = grouped.keys().map(|d| d.to_string()).collect();
+        assert_eq!(dates, ["2026-02-11", "2026-02-12"]);
+
+        let feb12: Vec<&str> = grouped[&listings[0].date]
+            .iter()
+            .map(|l| l.title.as_str())
+            .collect();
+        assert_eq!(feb12, ["Senate afternoon", "NA afternoon"]);
+        assert_eq!(grouped[&listings[1].date].len(), 2);
+
+        assert!(group_by_date(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_listing_stats() {
+        let listing = |house: House, session_type: Option<&str>| HansardListing {
+            house,
+            date: "2026-02-12".parse().unwrap(),
+            url: String::new(),
+            title: String::new(),
+            session_type: session_type.map(str::to_string),
+            start_time: None,
+            end_time: None,
+            source: DataSource::Current,
+        };
+        let listings = vec![
+            listing(House::Senate, Some("Afternoon Sitting")),
+            listing(House::NationalAssembly, Some("Morning Sitting")),
+            listing(House::NationalAssembly, Some("Afternoon Sitting")),
+            listing(House::NationalAssembly, None),
+        ];
+
+        let stats = ListingStats::from_hansard_listings(&listings);
+
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.senate, 1);
+        assert_eq!(stats.national_assembly, 3);
+        assert_eq!(stats.by_session_type["Afternoon Sitting"], 2);
+        assert_eq!(stats.by_session_type["Morning Sitting"], 1);
+        assert_eq!(stats.by_session_type[UNSPECIFIED_SESSION], 1);
+
+        let rendered = stats.to_string();
+        assert!(rendered.starts_with("Sittings: 4\n"));
+        assert!(rendered.ends_with(
+            "By session type:\n  Afternoon Sitting: 2\n  Morning Sitting: 1\n  Unspecified: 1"
+        ));
+    }
+
+    #[test]
+    fn test_vote_summary() {
+        let html = fs::read_to_string(
+            "fixtures/current/Boss_Gladys_Jepkosgei_with_paginated_contributions",
+        )
+        .expect("Failed to read fixture");
+        let mut profile = crate::current::parser::parse_member_profile(
+            &html,
+            "https://mzalendo.com/mps-performance/national-assembly/13th-parliament/boss-gladys-jepkosgei/",
+        )
+        .expect("Failed to parse profile");
+
+        let summary = profile.vote_summary();
+        assert_eq!(summary.yes, 4);
+        assert_eq!(summary.absent, 2);
+        assert_eq!(summary.no + summary.abstain + summary.unknown, 0);
+        assert!((summary.participation_rate - 4.0 / 6.0).abs() < f64::EPSILON);
+        assert_eq!(
+            summary.to_string(),
+            "Yes: 4, No: 0, Abstain: 0, Absent: 2 (67% participation)"
+        );
+
+        let rendered = profile.to_string();
+        assert!(rendered.starts_with(&profile.name));
+        assert!(
+            rendered
+                .ends_with("\n  Votes: Yes: 4, No: 0, Abstain: 0, Absent: 2 (67% participation)")
+        );
+
+        profile.voting_patterns.clear();
+        let summary = profile.vote_summary();
+        assert_eq!(summary, VoteSummary::default());
+        assert_eq!(
+            summary.to_string(),
+            "Yes: 0, No: 0, Abstain: 0, Absent: 0 (0% participation)"
+        );
+    }
+}
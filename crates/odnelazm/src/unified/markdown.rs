@@ -0,0 +1,265 @@
+use std::fmt::Write;
+
+use super::types::{Contribution, HansardSitting};
+
+/// Options for [`HansardSitting::to_markdown_with`]. The default renders everything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MarkdownOptions {
+    /// Render at most this many contributions per section and subsection, noting how many
+    /// were left out. `None` renders all of them.
+    pub max_contributions: Option<usize>,
+    /// Cut each contribution to this many characters, ending it with `…`. `None` keeps the
+    /// full text.
+    pub content_width: Option<usize>,
+}
+
+impl HansardSitting {
+    /// Render the sitting as a readable Markdown transcript.
+    ///
+    /// The house and date form the title, followed by the summary and sentiment when present.
+    /// Sections and subsections become `##` and `###` headings, speakers are set in bold and
+    /// procedural notes are blockquoted. Contributions without a speaker name continue the
+    /// previous speaker's block.
+    pub fn to_markdown(&self) -> String {
+        self.to_markdown_with(MarkdownOptions::default())
+    }
+
+    /// Like [`to_markdown`](Self::to_markdown), abridged according to `options`.
+    pub fn to_markdown_with(&self, options: MarkdownOptions) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            "# {} — {}\n",
+            self.house,
+            self.date.format("%A, %-d %B %Y")
+        );
+
+        let mut meta = Vec::new();
+        if !self.session_type.is_empty() {
+            meta.push(format!("*{}*", self.session_type));
+        }
+        match (self.start_time, self.end_time) {
+            (Some(start), Some(end)) => meta.push(format!(
+                "{} – {}",
+                start.format("%H:%M"),
+                end.format("%H:%M")
+            )),
+            (Some(start), None) => meta.push(start.format("%H:%M").to_string()),
+            _ => {}
+        }
+        if let Some(speaker) = &self.speaker_in_chair {
+            meta.push(format!("{speaker} in the Chair"));
+        }
+        if !meta.is_empty() {
+            let _ = writeln!(out, "{}\n", meta.join(" · "));
+        }
+
+        if let Some(summary) = &self.summary {
+            let _ = writeln!(out, "**Summary:** {summary}\n");
+        }
+        if let Some(sentiment) = &self.sentiment {
+            let _ = writeln!(out, "**Sentiment:** {sentiment}\n");
+        }
+
+        for section in &self.sections {
+            if !section.section_type.is_empty() {
+                let _ = writeln!(out, "## {}\n", section.section_type);
+            }
+            render_contributions(&mut out, &section.contributions, options);
+
+            for subsection in &section.subsections {
+                let _ = writeln!(out, "### {}\n", subsection.title);
+                render_contributions(&mut out, &subsection.contributions, options);
+            }
+        }
+
+        let trimmed = out.trim_end().len();
+        out.truncate(trimmed);
+        out.push('\n');
+        out
+    }
+}
+
+fn render_contributions(
+    out: &mut String,
+    contributions: &[Contribution],
+    options: MarkdownOptions,
+) {
+    let shown = options
+        .max_contributions
+        .map_or(contributions.len(), |max| max.min(contributions.len()));
+    let contents: Vec<String> = contributions[..shown]
+        .iter()
+        .map(|c| match options.content_width {
+            Some(width) => clip(&c.content, width),
+            None => c.content.clone(),
+        })
+        .collect();
+    let mut blocks: Vec<(Option<&Contribution>, Vec<&str>, Vec<&str>)> = Vec::new();
+
+    for (contribution, content) in contributions.iter().zip(&contents) {
+        let paragraphs = content
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+        let notes = contribution.procedural_notes.iter().map(String::as_str);
+
+        match blocks.last_mut() {
+            Some((_, body, block_notes)) if contribution.speaker_name.trim().is_empty() => {
+                body.extend(paragraphs);
+                block_notes.extend(notes);
+            }
+            _ => {
+                let speaker =
+                    (!contribution.speaker_name.trim().is_empty()).then_some(contribution);
+                blocks.push((speaker, paragraphs.collect(), notes.collect()));
+            }
+        }
+    }
+
+    for (speaker, body, notes) in blocks {
+        let mut paragraphs = body.into_iter();
+        if let Some(speaker) = speaker {
+            let mut author = format!("**{}**", speaker.speaker_name);
+            if let Some(role) = &speaker.speaker_role {
+                let _ = write!(author, " ({role})");
+            }
+            match paragraphs.next() {
+                Some(first) => {
+                    let _ = writeln!(out, "{author}: {first}\n");
+                }
+                None => {
+                    let _ = writeln!(out, "{author}\n");
+                }
+            }
+        }
+        for paragraph in paragraphs {
+            let _ = writeln!(out, "{paragraph}\n");
+        }
+        for note in notes {
+            let _ = writeln!(out, "> {note}\n");
+        }
+    }
+
+    let omitted = contributions.len() - shown;
+    if omitted > 0 {
+        let _ = writeln!(out, "*… {omitted} more contribution(s)*\n");
+    }
+}
+
+/// `text` cut to `width` characters, ending with `…` when anything was cut.
+fn clip(text: &str, width: usize) -> String {
+    match text.char_indices().nth(width) {
+        Some((cut, _)) => format!("{}…", text[..cut].trim_end()),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::current::parser::parse_hansard_sitting;
+    use std::fs;
+
+    #[test]
+    fn test_to_markdown_structure() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = HansardSitting::from_current(
+            parse_hansard_sitting(&html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        );
+        let markdown = sitting.to_markdown();
+
+        assert!(markdown.starts_with("# National Assembly — Thursday, 12 February 2026\n"));
+        if sitting.summary.is_some() {
+            assert!(markdown.contains("**Summary:** "));
+        }
+        for section in sitting
+            .sections
+            .iter()
+            .filter(|s| !s.section_type.is_empty())
+        {
+            assert!(markdown.contains(&format!("\n## {}\n", section.section_type)));
+            for subsection in &section.subsections {
+                assert!(markdown.contains(&format!("\n### {}\n", subsection.title)));
+            }
+        }
+        assert!(markdown.contains("**Hon. Speaker**"));
+        assert!(!markdown.contains("****"), "No empty speaker names");
+    }
+
+    #[test]
+    fn test_to_markdown_continuation_and_notes() {
+        let html = r#"
+            <span class="house">Senate</span>
+            <article class="hansard-document">
+            <h2 class="major-section-header">MOTIONS</h2>
+            <div class="contributor-name">Sen. Cherarkey</div>
+            <div class="speech-content">
+            <p>Mr. Speaker, Sir, I beg to move.</p>
+            <aside class="procedural-note">(Applause)</aside>
+            </div>
+            </article>
+        "#;
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2434/";
+
+        let mut sitting = HansardSitting::from_current(
+            parse_hansard_sitting(html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        );
+        let mut continuation = sitting.sections[0].contributions[0].clone();
+        continuation.speaker_name = String::new();
+        continuation.content = "Thank you.".to_string();
+        continuation.procedural_notes.clear();
+        sitting.sections[0].contributions.push(continuation);
+
+        let markdown = sitting.to_markdown();
+
+        assert!(markdown.contains(
+            "## MOTIONS\n\n**Sen. Cherarkey**: Mr. Speaker, Sir, I beg to move.\n\nThank you.\n\n> (Applause)\n"
+        ));
+        assert!(markdown.ends_with("> (Applause)\n"));
+    }
+
+    #[test]
+    fn test_to_markdown_with_options() {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+        let sitting = HansardSitting::from_current(
+            parse_hansard_sitting(&html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        );
+
+        assert_eq!(
+            sitting.to_markdown_with(MarkdownOptions::default()),
+            sitting.to_markdown()
+        );
+
+        let abridged = sitting.to_markdown_with(MarkdownOptions {
+            max_contributions: Some(1),
+            content_width: Some(40),
+        });
+        assert!(abridged.len() < sitting.to_markdown().len());
+        assert!(abridged.contains(" more contribution(s)*"));
+        assert!(abridged.contains('…'));
+        for section in sitting
+            .sections
+            .iter()
+            .filter(|s| !s.section_type.is_empty())
+        {
+            assert!(abridged.contains(&format!("\n## {}\n", section.section_type)));
+        }
+    }
+
+    #[test]
+    fn test_clip() {
+        assert_eq!(clip("I beg to move.", 20), "I beg to move.");
+        assert_eq!(clip("I beg to move.", 6), "I beg…");
+        assert_eq!(clip("Mheshimiwa Spika", 0), "…");
+    }
+}
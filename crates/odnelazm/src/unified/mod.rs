@@ -1,4 +1,8 @@
+pub mod analysis;
+mod markdown;
 pub mod scraper;
+pub mod search;
 pub mod types;
 
+pub use markdown::MarkdownOptions;
 pub use scraper::{HansardScraper, ScraperError};
@@ -1,42 +1,105 @@
+use std::sync::LazyLock;
+
+use bytes::Bytes;
 use chrono::NaiveDate;
-use futures::future;
+use futures::{Stream, StreamExt, future};
+use regex::Regex;
 
 use crate::{
-    archive::scraper::WebScraper as ArchiveScraper, current::scraper::WebScraper as CurrentScraper,
-    types::House,
+    archive::scraper::WebScraper as ArchiveScraper,
+    current::{parser::ParseConfig, scraper::WebScraper as CurrentScraper},
+    types::{FetchProgress, House, Parliament, SkipHook},
 };
 
 use super::types::{
-    Bill, DataSource, HansardListing, HansardSitting, Member, MemberProfile, ParliamentaryActivity,
-    SittingListOptions,
+    ActivityFilter, Bill, Contribution, DataSource, HansardListing, HansardSitting, Member,
+    MemberProfile, Page, ParliamentaryActivity, SittingListOptions,
 };
 
 fn current_cutoff() -> NaiveDate {
     NaiveDate::from_ymd_opt(2013, 3, 28).expect("valid date")
 }
 
+static RE_ARCHIVE_SLUG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^((senate|national_assembly)/)?\d{4}-\d{2}-\d{2}")
+        .expect("invalid regex: archive slug")
+});
+static RE_CURRENT_SLUG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(monday|tuesday|wednesday|thursday|friday|saturday|sunday)-\d{1,2}(st|nd|rd|th)-")
+        .expect("invalid regex: current slug")
+});
+
+const ARCHIVE_SITTING_PATH: &str = "/hansard/sitting/";
+const CURRENT_SITTING_PATH: &str = "/democracy-tools/hansard/";
+
 impl DataSource {
-    /// Detect the source from a URL or slug.
-    /// Archive URLs contain `info.mzalendo.com` or the `/hansard/sitting/` path prefix.
-    /// Everything else is treated as current.
-    fn from_url(url_or_slug: &str) -> Self {
-        if url_or_slug.contains("info.mzalendo.com") || url_or_slug.contains("/hansard/sitting/") {
-            DataSource::Archive
+    /// Detect the source of a sitting from its URL, site path, or bare slug.
+    ///
+    /// - Hosts: `info.mzalendo.com` is archive, any other `mzalendo.com` host is current.
+    /// - Paths: `/hansard/sitting/...` is archive, `/democracy-tools/hansard/...` is current.
+    /// - Bare slugs: a leading `YYYY-MM-DD` (optionally after the house, as in
+    ///   `senate/2025-07-17`) is archive; a weekday and ordinal day, as in
+    ///   `thursday-12th-february-2026-afternoon-sitting-2438`, is current.
+    ///
+    /// Returns `None` when the shape matches neither.
+    fn detect(url_or_slug: &str) -> Option<Self> {
+        let lower = url_or_slug.trim().to_lowercase();
+        if lower.contains("info.mzalendo.com") || lower.contains("/hansard/sitting/") {
+            Some(DataSource::Archive)
+        } else if lower.contains("mzalendo.com") || lower.contains("/democracy-tools/") {
+            Some(DataSource::Current)
+        } else if RE_ARCHIVE_SLUG.is_match(lower.trim_start_matches('/')) {
+            Some(DataSource::Archive)
+        } else if RE_CURRENT_SLUG.is_match(lower.trim_start_matches('/')) {
+            Some(DataSource::Current)
         } else {
-            DataSource::Current
+            None
         }
     }
 
-    /// Resolve a URL or bare slug to a fully qualified URL for this source.
-    fn normalize_url(&self, url_or_slug: &str) -> String {
+    /// Resolve a URL, site path, or bare slug to a fully qualified URL for this source, with
+    /// site paths and slugs resolved against `base_url`.
+    fn normalize_url(&self, url_or_slug: &str, base_url: &str) -> String {
+        let url_or_slug = url_or_slug.trim();
         if url_or_slug.starts_with("http") {
             return url_or_slug.to_string();
         }
-        match self {
-            DataSource::Archive => format!("https://info.mzalendo.com{}", url_or_slug),
-            DataSource::Current => {
-                format!("https://mzalendo.com{}", url_or_slug.trim_end_matches('/'))
+        match (self, url_or_slug.starts_with('/')) {
+            (DataSource::Archive, true) => format!("{base_url}{url_or_slug}"),
+            (DataSource::Archive, false) => {
+                format!("{base_url}{ARCHIVE_SITTING_PATH}{url_or_slug}")
+            }
+            (DataSource::Current, true) => {
+                format!("{base_url}{}", url_or_slug.trim_end_matches('/'))
+            }
+            (DataSource::Current, false) => {
+                format!(
+                    "{base_url}{CURRENT_SITTING_PATH}{}",
+                    url_or_slug.trim_end_matches('/')
+                )
+            }
+        }
+    }
+}
+
+/// Run `fetch` on each candidate in turn, returning the first success, or the error of the
+/// last candidate once all have failed.
+async fn first_candidate<T, Fut>(
+    candidates: Vec<(DataSource, String)>,
+    what: &str,
+    fetch: impl Fn(DataSource, String) -> Fut,
+) -> Result<T, ScraperError>
+where
+    Fut: Future<Output = Result<T, ScraperError>>,
+{
+    let mut candidates = candidates.into_iter().peekable();
+    loop {
+        let (source, url) = candidates.next().expect("at least one candidate");
+        match fetch(source, url.clone()).await {
+            Err(e) if candidates.peek().is_some() => {
+                log::debug!("No {source} {what} at {url} ({e}), trying the next candidate");
             }
+            result => return result,
         }
     }
 }
@@ -73,8 +136,13 @@ impl ListingRoute {
 }
 
 impl SittingListOptions {
-    /// Apply `offset` (skip) then `limit` (truncate) to `listings` in place.
-    fn apply_slice(&self, listings: &mut Vec<HansardListing>) {
+    /// Apply `sort` and `reverse`, then `offset` (skip) and `limit` (truncate), to `listings` in place.
+    fn apply(&self, listings: &mut Vec<HansardListing>) {
+        match self.sort {
+            Some(sort) => sort.sort(listings, self.reverse),
+            None if self.reverse => listings.reverse(),
+            None => {}
+        }
         if let Some(off) = self.offset {
             *listings = listings.drain(off..).collect();
         }
@@ -92,6 +160,72 @@ pub enum ScraperError {
     Current(#[from] crate::current::scraper::ScraperError),
 }
 
+/// Members of both houses from [`HansardScraper::list_all_members_all_houses`], with the error
+/// of each house whose crawl failed.
+#[derive(Debug)]
+pub struct AllHousesMembers {
+    pub members: Vec<Member>,
+    pub errors: Vec<(House, ScraperError)>,
+}
+
+impl AllHousesMembers {
+    /// The members, or the first error when no house returned any.
+    pub fn into_result(self) -> Result<Vec<Member>, ScraperError> {
+        match self.errors.into_iter().next() {
+            Some((_, e)) if self.members.is_empty() => Err(e),
+            _ => Ok(self.members),
+        }
+    }
+}
+
+/// Coarse cause of a [`ScraperError`], for callers that branch on the failure rather than
+/// just report it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScraperErrorKind {
+    /// The page was fetched but could not be parsed.
+    Parse,
+    /// The request failed or the response was unusable; often worth retrying.
+    Network,
+    /// The page or resource does not exist: an HTTP 404, a page past the last one, a
+    /// parliament without members, or a sitting without a PDF.
+    NotFound,
+    /// The request was rejected before anything was fetched.
+    InvalidInput,
+}
+
+impl ScraperError {
+    pub fn kind(&self) -> ScraperErrorKind {
+        use crate::archive::scraper::ScraperError as Archive;
+        use crate::current::scraper::ScraperError as Current;
+
+        match self {
+            ScraperError::Archive(Archive::HttpError(e))
+            | ScraperError::Current(Current::HttpError(e)) => http_error_kind(e),
+            ScraperError::Archive(Archive::ParseError(_))
+            | ScraperError::Current(Current::ParseError(_)) => ScraperErrorKind::Parse,
+            ScraperError::Current(
+                Current::PageOutOfRange { .. } | Current::NotFound(_) | Current::MissingPdfUrl,
+            ) => ScraperErrorKind::NotFound,
+            ScraperError::Current(Current::InvalidPageRange { .. }) => {
+                ScraperErrorKind::InvalidInput
+            }
+            ScraperError::Current(Current::NotPdf { .. } | Current::EmptyPdf(_)) => {
+                ScraperErrorKind::Network
+            }
+            #[cfg(feature = "pdf")]
+            ScraperError::Current(Current::PdfExtract(_)) => ScraperErrorKind::Parse,
+        }
+    }
+}
+
+fn http_error_kind(error: &reqwest::Error) -> ScraperErrorKind {
+    if error.status() == Some(reqwest::StatusCode::NOT_FOUND) {
+        ScraperErrorKind::NotFound
+    } else {
+        ScraperErrorKind::Network
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HansardScraper {
     archive: ArchiveScraper,
@@ -106,6 +240,58 @@ impl HansardScraper {
         })
     }
 
+    /// Use an already configured `reqwest::Client` for both sources instead of building one,
+    /// e.g. to share an application's connection pool and proxy settings. The client's timeout
+    /// and user agent are used as-is.
+    pub fn from_client(client: reqwest::Client) -> Self {
+        Self {
+            archive: ArchiveScraper::from_client(client.clone()),
+            current: CurrentScraper::from_client(client),
+        }
+    }
+
+    /// Serve archive requests from another host, e.g. a local mirror. Slugs and site paths,
+    /// including the index's links, resolve against it; full URLs are fetched as given.
+    pub fn with_archive_base_url(mut self, url: impl Into<String>) -> Self {
+        self.archive = self.archive.with_base_url(url);
+        self
+    }
+
+    /// Serve current-source requests from another host, e.g. a local mirror. As with
+    /// [`with_archive_base_url`](Self::with_archive_base_url), slugs and site paths resolve
+    /// against it.
+    pub fn with_current_base_url(mut self, url: impl Into<String>) -> Self {
+        self.current = self.current.with_base_url(url);
+        self
+    }
+
+    /// Report page progress to `callback` while current-source pages fan out: full sitting
+    /// and member crawls, and the extra activity and bills pages of a member profile.
+    pub fn with_progress(
+        mut self,
+        callback: impl Fn(FetchProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.current = self.current.with_progress(callback);
+        self
+    }
+
+    /// Tell `callback` why each malformed row of a sitting or member list page was left out,
+    /// from either source, e.g. to warn that a crawl came back short. Without a callback only
+    /// the number of rows left out is logged.
+    pub fn with_skip_report(mut self, callback: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        let skips = SkipHook::new(callback);
+        self.archive = self.archive.with_skip_hook(skips.clone());
+        self.current = self.current.with_skip_hook(skips);
+        self
+    }
+
+    /// Refetch a current-source sitting once, after a short delay, when its transcript parses
+    /// to no sections, as the site occasionally serves a partially rendered page under load.
+    pub fn with_retry_on_empty(mut self, retry: bool) -> Self {
+        self.current = self.current.with_retry_on_empty(retry);
+        self
+    }
+
     /// List parliamentary sittings with automatic source routing.
     ///
     /// | Date range                              | Source          |
@@ -116,8 +302,9 @@ impl HansardScraper {
     /// | Spans the cutoff (or one bound missing) | Both, merged    |
     ///
     /// When both sources are queried they are fetched in parallel. Results are
-    /// merged and sorted by date descending before `limit`/`offset` are applied.
-    /// `page`/`all` apply only when the route is Current-only.
+    /// merged and sorted by date descending, then reordered by `sort`/`reverse`,
+    /// before `limit`/`offset` are applied.
+    /// `page`/`to_page`/`all` apply only when the route is Current-only.
     pub async fn list_sittings(
         &self,
         opts: SittingListOptions,
@@ -127,14 +314,22 @@ impl HansardScraper {
                 let mut listings = self
                     .fetch_archive_listings(opts.start_date, opts.end_date, opts.house)
                     .await?;
-                opts.apply_slice(&mut listings);
+                opts.apply(&mut listings);
                 Ok(listings)
             }
 
             ListingRoute::Current => {
-                let has_date_filter = opts.start_date.is_some() || opts.end_date.is_some();
-                let raw = if opts.all || has_date_filter {
+                let raw = if let Some(start) = opts.start_date {
+                    let end = opts.end_date.unwrap_or(NaiveDate::MAX);
+                    self.current
+                        .fetch_sittings_in_range(opts.house, start, end)
+                        .await?
+                } else if opts.all || opts.end_date.is_some() {
                     self.current.fetch_all_sittings(opts.house).await?
+                } else if let Some(to) = opts.to_page {
+                    self.current
+                        .fetch_sittings_page_range(opts.house, opts.page.max(1), to)
+                        .await?
                 } else {
                     self.current
                         .fetch_hansard_list(opts.page.max(1), opts.house)
@@ -148,7 +343,7 @@ impl HansardScraper {
                 if let Some(end) = opts.end_date {
                     listings.retain(|l| l.date <= end);
                 }
-                opts.apply_slice(&mut listings);
+                opts.apply(&mut listings);
                 Ok(listings)
             }
 
@@ -188,56 +383,280 @@ impl HansardScraper {
                 }
 
                 listings.sort_by_key(|l| std::cmp::Reverse(l.date));
-                opts.apply_slice(&mut listings);
+                opts.apply(&mut listings);
                 Ok(listings)
             }
         }
     }
 
-    /// Fetch the full transcript of a sitting by URL or slug.
-    /// The data source is detected automatically from the URL shape.
+    /// Current-source sittings dated after `after`, newest first, fetching only the pages
+    /// that reach back to it. Meant for incremental crawls that already hold everything up to
+    /// `after`.
+    pub async fn list_sittings_since(
+        &self,
+        house: Option<House>,
+        after: NaiveDate,
+    ) -> Result<Vec<HansardListing>, ScraperError> {
+        Ok(self
+            .current
+            .fetch_sittings_since(house, after)
+            .await?
+            .into_iter()
+            .map(HansardListing::from)
+            .collect())
+    }
+
+    /// One page of current-source sittings with the page count, e.g. to tell whether there
+    /// are more. Unlike [`list_sittings`](Self::list_sittings) there is no date routing.
+    pub async fn list_sittings_paged(
+        &self,
+        page: u32,
+        house: Option<House>,
+    ) -> Result<Page<HansardListing>, ScraperError> {
+        Ok(self
+            .current
+            .fetch_hansard_list_paged(page, house)
+            .await?
+            .map(HansardListing::from))
+    }
+
+    /// Fetch the full transcript of a sitting by URL, site path, or bare slug.
+    /// The data source is detected from the shape of `url_or_slug`; shapes that match
+    /// neither source are tried against both, current first.
     pub async fn get_sitting(&self, url_or_slug: &str) -> Result<HansardSitting, ScraperError> {
-        let source = DataSource::from_url(url_or_slug);
-        let url = source.normalize_url(url_or_slug);
-        match source {
-            DataSource::Archive => {
-                let sitting = self.archive.fetch_hansard_sitting(&url, false).await?;
-                Ok(HansardSitting::from_archive(sitting, url))
-            }
-            DataSource::Current => {
-                let sitting = self.current.fetch_hansard_sitting(&url).await?;
-                Ok(HansardSitting::from_current(sitting, url))
+        self.get_sitting_with(url_or_slug, ParseConfig::default())
+            .await
+    }
+
+    /// Like [`get_sitting`](Self::get_sitting), parsing current-source transcripts with
+    /// `config`. Archive transcripts are always parsed leniently.
+    pub async fn get_sitting_with(
+        &self,
+        url_or_slug: &str,
+        config: ParseConfig,
+    ) -> Result<HansardSitting, ScraperError> {
+        first_candidate(
+            self.sitting_candidates(url_or_slug),
+            "sitting",
+            |source, url| async move {
+                match source {
+                    DataSource::Archive => self
+                        .archive
+                        .fetch_hansard_sitting(&url, false)
+                        .await
+                        .map(|sitting| HansardSitting::from_archive(sitting, url))
+                        .map_err(ScraperError::from),
+                    DataSource::Current => self
+                        .current
+                        .fetch_hansard_sitting(&url, config)
+                        .await
+                        .map(|sitting| HansardSitting::from_current(sitting, url))
+                        .map_err(ScraperError::from),
+                }
+            },
+        )
+        .await
+    }
+
+    /// Like [`get_sitting`](Self::get_sitting), also returning the raw HTML the sitting was
+    /// parsed from, e.g. to check whether an empty transcript is a site change or a wrong slug.
+    pub async fn get_sitting_with_html(
+        &self,
+        url_or_slug: &str,
+    ) -> Result<(HansardSitting, String), ScraperError> {
+        first_candidate(
+            self.sitting_candidates(url_or_slug),
+            "sitting",
+            |source, url| async move {
+                match source {
+                    DataSource::Archive => self
+                        .archive
+                        .fetch_sitting_with_html(&url, false)
+                        .await
+                        .map(|(sitting, html)| (HansardSitting::from_archive(sitting, url), html))
+                        .map_err(ScraperError::from),
+                    DataSource::Current => self
+                        .current
+                        .fetch_sitting_with_html(&url, ParseConfig::default())
+                        .await
+                        .map(|(sitting, html)| (HansardSitting::from_current(sitting, url), html))
+                        .map_err(ScraperError::from),
+                }
+            },
+        )
+        .await
+    }
+
+    /// The URLs [`get_sitting`](Self::get_sitting) tries for `url_or_slug`, in order. Most
+    /// shapes resolve to a single URL; a bare archive date yields one per house.
+    pub fn sitting_urls(&self, url_or_slug: &str) -> Vec<String> {
+        self.sitting_candidates(url_or_slug)
+            .into_iter()
+            .map(|(_, url)| url)
+            .collect()
+    }
+
+    /// The profile URL a member's URL or site path resolves to, without a trailing slash.
+    pub fn member_url(&self, url_or_slug: &str) -> String {
+        self.current.member_base_url(url_or_slug)
+    }
+
+    /// `url_or_slug` resolved as a page of `source`, against its configured base URL.
+    fn source_url(&self, source: DataSource, url_or_slug: &str) -> String {
+        let base_url = match source {
+            DataSource::Archive => self.archive.base_url(),
+            DataSource::Current => self.current.base_url(),
+        };
+        source.normalize_url(url_or_slug, base_url)
+    }
+
+    /// URLs to try, in order, when fetching the sitting `url_or_slug` names.
+    ///
+    /// A detected source yields its URL alone, except that a bare archive date without a house
+    /// segment is tried under both houses. An undetected shape is tried as current, then
+    /// archive.
+    fn sitting_candidates(&self, url_or_slug: &str) -> Vec<(DataSource, String)> {
+        match DataSource::detect(url_or_slug) {
+            Some(DataSource::Archive)
+                if !url_or_slug.starts_with("http") && !url_or_slug.contains('/') =>
+            {
+                ["national_assembly", "senate"]
+                    .into_iter()
+                    .map(|house| {
+                        let slug = format!("{house}/{}", url_or_slug.trim());
+                        (
+                            DataSource::Archive,
+                            self.source_url(DataSource::Archive, &slug),
+                        )
+                    })
+                    .collect()
             }
+            Some(source) => vec![(source, self.source_url(source, url_or_slug))],
+            None => [DataSource::Current, DataSource::Archive]
+                .into_iter()
+                .map(|source| (source, self.source_url(source, url_or_slug)))
+                .collect(),
         }
     }
 
+    /// Fetch the raw HTML of a page without parsing it, returning the resolved URL with it.
+    /// `source` picks the site a site path or bare slug is resolved against; without it the
+    /// source is detected as in [`get_sitting`](Self::get_sitting), trying each candidate in
+    /// turn.
+    pub async fn get_page_html(
+        &self,
+        url_or_slug: &str,
+        source: Option<DataSource>,
+    ) -> Result<(String, String), ScraperError> {
+        let candidates = match source {
+            Some(source) => vec![(source, self.source_url(source, url_or_slug))],
+            None => self.sitting_candidates(url_or_slug),
+        };
+        first_candidate(candidates, "page", |source, url| async move {
+            let html = match source {
+                DataSource::Archive => self.archive.fetch_page(&url).await?,
+                DataSource::Current => self.current.fetch_page(&url).await?,
+            };
+            Ok::<_, ScraperError>((url, html))
+        })
+        .await
+    }
+
+    /// Rebuild a current-source sitting from the text of its official PDF, as a fallback for
+    /// transcripts whose HTML comes back without sections. The text is kept in a single
+    /// "PDF TRANSCRIPT" section, so speakers and subsections are not recovered. Archive
+    /// sittings have no PDF.
+    #[cfg(feature = "pdf")]
+    pub async fn get_sitting_from_pdf(
+        &self,
+        url_or_slug: &str,
+    ) -> Result<HansardSitting, ScraperError> {
+        if DataSource::detect(url_or_slug) == Some(DataSource::Archive) {
+            return Err(crate::current::scraper::ScraperError::MissingPdfUrl.into());
+        }
+        let url = self.source_url(DataSource::Current, url_or_slug);
+        let sitting = self.current.fetch_sitting_from_pdf(&url).await?;
+        Ok(HansardSitting::from_current(sitting, url))
+    }
+
     pub async fn list_members(
         &self,
         house: House,
-        parliament: &str,
+        parliament: &Parliament,
         page: u32,
     ) -> Result<Vec<Member>, ScraperError> {
         Ok(self.current.fetch_members(house, parliament, page).await?)
     }
 
+    /// Like [`list_members`](Self::list_members), also returning the page count.
+    pub async fn list_members_paged(
+        &self,
+        house: House,
+        parliament: &Parliament,
+        page: u32,
+    ) -> Result<Page<Member>, ScraperError> {
+        Ok(self
+            .current
+            .fetch_members_paged(house, parliament, page)
+            .await?)
+    }
+
     pub async fn list_all_members(
         &self,
         house: House,
-        parliament: &str,
+        parliament: &Parliament,
     ) -> Result<Vec<Member>, ScraperError> {
         Ok(self.current.fetch_all_members(house, parliament).await?)
     }
 
-    pub async fn list_all_members_all_houses(
+    /// Find the elected member for `constituency`, e.g. "Mwala", matched case-insensitively.
+    /// Follow the member's `url` to [`get_member_profile`](Self::get_member_profile).
+    pub async fn find_member_by_constituency(
         &self,
-        parliament: &str,
-    ) -> Result<Vec<Member>, ScraperError> {
+        house: House,
+        parliament: &Parliament,
+        constituency: &str,
+    ) -> Result<Option<Member>, ScraperError> {
         Ok(self
             .current
-            .fetch_all_members_all_houses(parliament)
+            .find_member_by_constituency(house, parliament, constituency)
             .await?)
     }
 
+    /// Fetch every member of both houses in parallel. A house that fails does not abort the
+    /// other: its error is returned alongside whatever members were fetched.
+    pub async fn list_all_members_all_houses(&self, parliament: &Parliament) -> AllHousesMembers {
+        let (members, errors) = self.current.fetch_all_members_all_houses(parliament).await;
+        AllHousesMembers {
+            members,
+            errors: errors
+                .into_iter()
+                .map(|(house, e)| (house, ScraperError::from(e)))
+                .collect(),
+        }
+    }
+
+    /// Stream current-source sitting listings as each page is parsed, newest first.
+    pub fn sittings_stream(
+        &self,
+        house: Option<House>,
+    ) -> impl Stream<Item = Result<HansardListing, ScraperError>> + '_ {
+        self.current
+            .sittings_stream(house)
+            .map(|result| result.map(HansardListing::from).map_err(ScraperError::from))
+    }
+
+    /// Stream members as each page is parsed, so large crawls can stop early.
+    pub fn members_stream<'a>(
+        &'a self,
+        house: House,
+        parliament: &'a Parliament,
+    ) -> impl Stream<Item = Result<Member, ScraperError>> + 'a {
+        self.current
+            .members_stream(house, parliament)
+            .map(|result| result.map_err(ScraperError::from))
+    }
+
     pub async fn get_member_profile(
         &self,
         url_or_slug: &str,
@@ -261,6 +680,47 @@ impl HansardScraper {
             .await?)
     }
 
+    /// The full contribution behind an activity item, looked up in its sitting by the item's
+    /// `#chunk-N` anchor. An item URL without an anchor is a parse error; an anchor that marks
+    /// no contribution is not found.
+    pub async fn get_contribution(
+        &self,
+        activity: &ParliamentaryActivity,
+    ) -> Result<Contribution, ScraperError> {
+        Ok(self.current.fetch_contribution(activity).await?.into())
+    }
+
+    /// The first `max_pages` pages of a member's activity, with the number of pages available.
+    pub async fn get_member_activity_pages(
+        &self,
+        url_or_slug: &str,
+        max_pages: u32,
+    ) -> Result<(Vec<ParliamentaryActivity>, u32), ScraperError> {
+        Ok(self
+            .current
+            .fetch_member_activity_pages(url_or_slug, max_pages)
+            .await?)
+    }
+
+    /// A member's activity matching `filter`, paging only as far back as `filter.since`.
+    pub async fn get_member_activity_filtered(
+        &self,
+        url_or_slug: &str,
+        filter: &ActivityFilter,
+    ) -> Result<Vec<ParliamentaryActivity>, ScraperError> {
+        Ok(self
+            .current
+            .fetch_member_activity_filtered(url_or_slug, filter)
+            .await?)
+    }
+
+    pub async fn get_all_member_activity(
+        &self,
+        url_or_slug: &str,
+    ) -> Result<Vec<ParliamentaryActivity>, ScraperError> {
+        Ok(self.current.fetch_all_member_activity(url_or_slug).await?)
+    }
+
     pub async fn get_member_bills(
         &self,
         url_or_slug: &str,
@@ -269,6 +729,17 @@ impl HansardScraper {
         Ok(self.current.fetch_member_bills(url_or_slug, page).await?)
     }
 
+    /// Download the official Hansard PDF of a sitting. Only current-source sittings link one.
+    pub async fn download_sitting_pdf(
+        &self,
+        sitting: &HansardSitting,
+    ) -> Result<Bytes, ScraperError> {
+        Ok(self
+            .current
+            .download_sitting_pdf(sitting.pdf_url.as_deref())
+            .await?)
+    }
+
     /// Fetch archive listings and apply date-range and house filters client-side.
     async fn fetch_archive_listings(
         &self,
@@ -297,3 +768,144 @@ impl HansardScraper {
         Ok(listings)
     }
 }
+
+/// Fetch a sitting from whichever source `url_or_slug` belongs to, with a default
+/// [`HansardScraper`]. See [`HansardScraper::get_sitting`] for how the source is detected.
+pub async fn fetch_sitting(url_or_slug: &str) -> Result<HansardSitting, ScraperError> {
+    HansardScraper::new()?.get_sitting(url_or_slug).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_kind() {
+        use crate::current::scraper::ScraperError as Current;
+
+        let kind = |e: Current| ScraperError::from(e).kind();
+        assert_eq!(
+            kind(Current::PageOutOfRange {
+                requested: 9,
+                last: 8
+            }),
+            ScraperErrorKind::NotFound
+        );
+        assert_eq!(
+            kind(Current::InvalidPageRange { from: 5, to: 3 }),
+            ScraperErrorKind::InvalidInput
+        );
+        assert_eq!(
+            kind(Current::ParseError(
+                crate::current::parser::ParseError::MissingField("date".to_string())
+            )),
+            ScraperErrorKind::Parse
+        );
+        assert_eq!(
+            kind(Current::EmptyPdf(String::new())),
+            ScraperErrorKind::Network
+        );
+    }
+
+    #[test]
+    fn test_detect_source() {
+        for archive in [
+            "https://info.mzalendo.com/hansard/sitting/senate/2025-07-17",
+            "/hansard/sitting/national_assembly/2025-07-01-14-30-00",
+            "senate/2025-07-17",
+            "2020-12-29-14-30-00",
+        ] {
+            assert_eq!(
+                DataSource::detect(archive),
+                Some(DataSource::Archive),
+                "{archive}"
+            );
+        }
+        for current in [
+            "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/",
+            "/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438",
+            "thursday-12th-february-2026-afternoon-sitting-2438",
+            "Tuesday-3rd-March-2026-morning-sitting-2460",
+        ] {
+            assert_eq!(
+                DataSource::detect(current),
+                Some(DataSource::Current),
+                "{current}"
+            );
+        }
+        assert_eq!(DataSource::detect("special-sitting-2400"), None);
+    }
+
+    #[test]
+    fn test_sitting_candidates() {
+        let scraper = HansardScraper::new().unwrap();
+        assert_eq!(
+            scraper.sitting_candidates("thursday-12th-february-2026-afternoon-sitting-2438/"),
+            vec![(
+                DataSource::Current,
+                "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438".to_string()
+            )]
+        );
+        assert_eq!(
+            scraper.sitting_candidates("senate/2025-07-17"),
+            vec![(
+                DataSource::Archive,
+                "https://info.mzalendo.com/hansard/sitting/senate/2025-07-17".to_string()
+            )]
+        );
+        assert_eq!(
+            scraper.sitting_candidates("2025-07-17"),
+            vec![
+                (
+                    DataSource::Archive,
+                    "https://info.mzalendo.com/hansard/sitting/national_assembly/2025-07-17"
+                        .to_string()
+                ),
+                (
+                    DataSource::Archive,
+                    "https://info.mzalendo.com/hansard/sitting/senate/2025-07-17".to_string()
+                ),
+            ]
+        );
+
+        let sources: Vec<DataSource> = scraper
+            .sitting_candidates("special-sitting-2400")
+            .into_iter()
+            .map(|(source, _)| source)
+            .collect();
+        assert_eq!(sources, [DataSource::Current, DataSource::Archive]);
+    }
+
+    #[test]
+    fn test_member_url() {
+        let scraper = HansardScraper::new().unwrap();
+        let url = "https://mzalendo.com/mps-performance/national-assembly/13th-parliament/boss-gladys-jepkosgei";
+        assert_eq!(scraper.member_url(&format!("{url}/")), url);
+        assert_eq!(
+            scraper.member_url(
+                "/mps-performance/national-assembly/13th-parliament/boss-gladys-jepkosgei/"
+            ),
+            url
+        );
+    }
+
+    #[test]
+    fn test_sitting_candidates_use_base_urls() {
+        let scraper = HansardScraper::new()
+            .unwrap()
+            .with_archive_base_url("http://127.0.0.1:8080/")
+            .with_current_base_url("http://127.0.0.1:8081");
+        assert_eq!(
+            scraper.sitting_urls("senate/2025-07-17"),
+            ["http://127.0.0.1:8080/hansard/sitting/senate/2025-07-17"]
+        );
+        assert_eq!(
+            scraper.sitting_urls("/democracy-tools/hansard/special-sitting-2400/"),
+            ["http://127.0.0.1:8081/democracy-tools/hansard/special-sitting-2400"]
+        );
+        assert_eq!(
+            scraper.sitting_urls("https://info.mzalendo.com/hansard/sitting/senate/2025-07-17"),
+            ["https://info.mzalendo.com/hansard/sitting/senate/2025-07-17"]
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+use super::types::{House, Member};
+
+/// Matches scoring below this are dropped.
+const MIN_SCORE: f64 = 0.8;
+
+/// A match must score at least this to be taken as the member a query names.
+const STRONG_SCORE: f64 = 0.9;
+
+/// How far the best match must lead the runner-up to be taken over it.
+const MIN_LEAD: f64 = 0.05;
+
+/// A member ranked against a search query; see [`rank_members`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemberMatch {
+    /// Similarity of the closest field, from 0 to 1, rounded to two decimal places.
+    pub score: f64,
+    pub name: String,
+    pub constituency: Option<String>,
+    pub county: Option<String>,
+    pub house: House,
+    pub url: String,
+}
+
+/// Rank `members` by how closely their name, constituency, or county matches `query`, best
+/// first, keeping at most `limit`.
+pub fn rank_members(
+    query: &str,
+    members: impl IntoIterator<Item = Member>,
+    limit: usize,
+) -> Vec<MemberMatch> {
+    let query = normalize_search_text(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<MemberMatch> = members
+        .into_iter()
+        .filter_map(|member| {
+            let score = [
+                Some(member.name.as_str()),
+                member.constituency.as_deref(),
+                member.county.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .map(|field| similarity(&query, &normalize_search_text(field)))
+            .fold(0.0, f64::max);
+            (score >= MIN_SCORE).then(|| MemberMatch {
+                score: (score * 100.0).round() / 100.0,
+                name: member.name,
+                constituency: member.constituency,
+                county: member.county,
+                house: member.house,
+                url: member.url,
+            })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    matches.truncate(limit);
+    matches
+}
+
+/// The match a query clearly names, if any: the top of `matches` (as ranked by
+/// [`rank_members`]) when it scores at least 0.9 and leads the next match by 0.05.
+pub fn best_member_match(matches: &[MemberMatch]) -> Option<&MemberMatch> {
+    let (best, rest) = matches.split_first()?;
+    let clear_lead = rest
+        .first()
+        .is_none_or(|next| best.score - next.score >= MIN_LEAD);
+    (best.score >= STRONG_SCORE && clear_lead).then_some(best)
+}
+
+/// How closely `text` matches `query`, from 0 to 1, with the same fuzziness [`rank_members`]
+/// uses. `None` when it scores too low to count as a match.
+pub fn match_score(query: &str, text: &str) -> Option<f64> {
+    let query = normalize_search_text(query);
+    if query.is_empty() {
+        return None;
+    }
+    let score = similarity(&query, &normalize_search_text(text));
+    (score >= MIN_SCORE).then_some(score)
+}
+
+/// Lowercase `text` and collapse everything but letters and digits to single spaces, so
+/// "Bill No. 34" and "bill no 34" compare equal.
+pub fn normalize_search_text(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// XXX: whole-string Jaro-Winkler rewards shared prefixes, which misses queries that name a
+// member by surname only; every query token is also matched against its closest field token.
+fn similarity(query: &str, field: &str) -> f64 {
+    if field.is_empty() {
+        return 0.0;
+    }
+    if field == query {
+        return 1.0;
+    }
+    if field.contains(query) {
+        return 0.95;
+    }
+
+    let field_tokens: Vec<&str> = field.split(' ').collect();
+    let query_tokens: Vec<&str> = query.split(' ').collect();
+    let per_token = query_tokens
+        .iter()
+        .map(|q| {
+            field_tokens
+                .iter()
+                .map(|f| strsim::jaro_winkler(q, f))
+                .fold(0.0, f64::max)
+        })
+        .sum::<f64>()
+        / query_tokens.len() as f64;
+
+    strsim::jaro_winkler(query, field).max(per_token * 0.95)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, constituency: &str, house: House) -> Member {
+        Member {
+            name: name.to_string(),
+            url: format!(
+                "https://mzalendo.com/mps-performance/national-assembly/13th-parliament/{}/",
+                name.to_lowercase().replace(' ', "-")
+            ),
+            house,
+            role: None,
+            photo_url: None,
+            constituency: Some(constituency.to_string()),
+            county: None,
+        }
+    }
+
+    fn members() -> Vec<Member> {
+        vec![
+            member("Vincent Musau", "Mwala", House::NationalAssembly),
+            member("Gladys Wanga", "Homa Bay County", House::NationalAssembly),
+            member(
+                "Boss Gladys Jepkosgei",
+                "Uasin Gishu",
+                House::NationalAssembly,
+            ),
+            member("Aaron Cheruiyot", "Kericho", House::Senate),
+        ]
+    }
+
+    #[test]
+    fn test_rank_by_constituency() {
+        let matches = rank_members("mwala", members(), 10);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].name, "Vincent Musau");
+        assert_eq!(matches[0].score, 1.0);
+        assert!(matches[0].url.ends_with("/vincent-musau/"));
+    }
+
+    #[test]
+    fn test_rank_tolerates_misspelling_and_orders_by_score() {
+        let matches = rank_members("Gladis", members(), 10);
+
+        assert_eq!(matches.len(), 2);
+        assert!(matches.iter().all(|m| m.name.contains("Gladys")));
+        assert!(matches[0].score >= matches[1].score);
+
+        let matches = rank_members("cheruiyot", members(), 10);
+        assert_eq!(matches[0].name, "Aaron Cheruiyot");
+        assert_eq!(matches[0].house, House::Senate);
+    }
+
+    #[test]
+    fn test_best_member_match() {
+        let matches = rank_members("vincent musau", members(), 10);
+        let best = best_member_match(&matches).expect("Should be a single strong match");
+        assert_eq!(best.name, "Vincent Musau");
+
+        assert!(best_member_match(&rank_members("Gladys", members(), 10)).is_none());
+        assert!(best_member_match(&rank_members("Aaron Cheruiyut", members(), 10)).is_some());
+        assert!(best_member_match(&[]).is_none());
+    }
+
+    #[test]
+    fn test_rank_limit_and_no_match() {
+        assert_eq!(rank_members("Gladys", members(), 1).len(), 1);
+        assert!(rank_members("Odhiambo", members(), 10).is_empty());
+        assert!(rank_members("  ", members(), 10).is_empty());
+    }
+
+    #[test]
+    fn test_match_score() {
+        assert_eq!(
+            match_score(
+                "bill no. 34",
+                "The Sugar Bill (National Assembly Bill No. 34 of 2024)"
+            ),
+            Some(0.95)
+        );
+        assert_eq!(match_score("Mwala", "mwala"), Some(1.0));
+        assert_eq!(
+            match_score("sugar", "Drought mitigation in Machakos County"),
+            None
+        );
+        assert_eq!(match_score("", "Mwala"), None);
+    }
+}
@@ -1,17 +1,23 @@
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone};
+use chrono_tz::{Africa::Nairobi, Tz};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// Options for [`HansardScraper::list_sittings`].
 ///
 /// The data source is chosen automatically:
-/// - No dates → current source, paged via `page`/`all`.
+/// - No dates → current source, paged via `page`/`to_page`/`all`.
 /// - `end_date` before 2013-03-28 → archive only.
-/// - `start_date` on or after 2013-03-28 → current only, paged via `page`/`all`.
+/// - `start_date` on or after 2013-03-28 → current only, paged via `page`/`to_page`/`all`.
 /// - Range spans the cutoff (or one bound is absent while the other crosses it)
-///   → both sources fetched in parallel and merged; `page`/`all` are ignored.
+///   → both sources fetched in parallel and merged; `page`/`to_page`/`all` are ignored.
 ///
 /// `limit` and `offset` are applied client-side after any merging and sorting.
+///
+/// Prefer [`SittingListOptions::builder`] to a struct literal, e.g.
+/// `SittingListOptions::builder().house(House::Senate).limit(10).build()`.
 #[derive(Debug, Clone, Default)]
 pub struct SittingListOptions {
     pub start_date: Option<NaiveDate>,
@@ -21,13 +27,202 @@ pub struct SittingListOptions {
     pub page: u32,
     /// Fetch all pages at once from the current source. Ignored for cross-source queries.
     pub all: bool,
+    /// Last page of an inclusive `page..=to_page` range fetched concurrently from the current
+    /// source. Ignored for cross-source queries and when `all` is set.
+    pub to_page: Option<u32>,
     /// Maximum results to return (applied after merging and sorting).
     pub limit: Option<usize>,
     /// Results to skip (applied after merging and sorting).
     pub offset: Option<usize>,
+    /// Field to order results by. Unset keeps site order (newest first for merged queries).
+    pub sort: Option<ListingSort>,
+    /// Reverse the order, descending for `sort` or oldest-first for site order.
+    pub reverse: bool,
+}
+
+impl SittingListOptions {
+    pub fn builder() -> SittingListOptionsBuilder {
+        SittingListOptionsBuilder::default()
+    }
+}
+
+/// Builder for [`SittingListOptions`]; unset fields keep their defaults.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct SittingListOptionsBuilder {
+    options: SittingListOptions,
+}
+
+impl SittingListOptionsBuilder {
+    pub fn start_date(mut self, date: NaiveDate) -> Self {
+        self.options.start_date = Some(date);
+        self
+    }
+
+    pub fn end_date(mut self, date: NaiveDate) -> Self {
+        self.options.end_date = Some(date);
+        self
+    }
+
+    pub fn house(mut self, house: House) -> Self {
+        self.options.house = Some(house);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.options.page = page;
+        self
+    }
+
+    pub fn to_page(mut self, to_page: u32) -> Self {
+        self.options.to_page = Some(to_page);
+        self
+    }
+
+    pub fn all(mut self, all: bool) -> Self {
+        self.options.all = all;
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.options.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.options.offset = Some(offset);
+        self
+    }
+
+    pub fn sort(mut self, sort: ListingSort) -> Self {
+        self.options.sort = Some(sort);
+        self
+    }
+
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.options.reverse = reverse;
+        self
+    }
+
+    pub fn build(self) -> SittingListOptions {
+        self.options
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid sort field '{field}'. Accepted values: {accepted}")]
+pub struct SortParseError {
+    field: String,
+    accepted: &'static str,
+}
+
+/// Field to order sitting listings by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ListingSort {
+    /// Chronological, by date then start time. Sittings of both houses at the same time
+    /// are kept apart by house.
+    Date,
+    House,
+    Title,
+}
+
+impl ListingSort {
+    /// Stable sort of `listings` by this field, descending when `reverse` is set.
+    pub fn sort(self, listings: &mut [HansardListing], reverse: bool) {
+        listings.sort_by(|a, b| {
+            let ordering = match self {
+                ListingSort::Date => (a.date, a.start_time)
+                    .cmp(&(b.date, b.start_time))
+                    .then_with(|| a.house.slug().cmp(b.house.slug())),
+                ListingSort::House => a.house.slug().cmp(b.house.slug()),
+                ListingSort::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            };
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+}
+
+impl FromStr for ListingSort {
+    type Err = SortParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "date" => Ok(ListingSort::Date),
+            "house" => Ok(ListingSort::House),
+            "title" => Ok(ListingSort::Title),
+            _ => Err(SortParseError {
+                field: s.to_string(),
+                accepted: "'date', 'house', 'title'",
+            }),
+        }
+    }
+}
+
+/// Field to order member lists by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MemberSort {
+    Name,
+    /// By constituency, or county for senators and woman representatives. Members with
+    /// neither (e.g. nominated) sort last.
+    Constituency,
+}
+
+impl MemberSort {
+    /// Stable sort of `members` by this field, descending when `reverse` is set. Members
+    /// without a seat stay last either way.
+    pub fn sort(self, members: &mut [Member], reverse: bool) {
+        let directed = |ordering: Ordering| {
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        };
+        members.sort_by(|a, b| match self {
+            MemberSort::Name => directed(a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+            MemberSort::Constituency => {
+                fn seat(m: &Member) -> Option<&str> {
+                    m.constituency.as_deref().or(m.county.as_deref())
+                }
+                match (seat(a), seat(b)) {
+                    (Some(a), Some(b)) => directed(a.to_lowercase().cmp(&b.to_lowercase())),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            }
+        });
+    }
+}
+
+impl FromStr for MemberSort {
+    type Err = SortParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(MemberSort::Name),
+            "constituency" => Ok(MemberSort::Constituency),
+            _ => Err(SortParseError {
+                field: s.to_string(),
+                accepted: "'name', 'constituency'",
+            }),
+        }
+    }
 }
 
-pub use crate::current::types::{Bill, Member, MemberProfile, ParliamentaryActivity, VoteRecord};
+pub use crate::archive::types::PersonDetails;
+pub use crate::current::types::{
+    ActivityFilter, Bill, Committee, ContributionKind, Division, Member, MemberProfile, Page,
+    ParliamentaryActivity, QuestionStatusEntry, Sentiment, StatementKind, VoteDecision, VoteRecord,
+};
 pub use crate::types::House;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -45,12 +240,43 @@ impl Display for DataSource {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid source '{0}'. Accepted values: 'archive', 'current'")]
+pub struct DataSourceParseError(String);
+
+impl FromStr for DataSource {
+    type Err = DataSourceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "archive" => Ok(DataSource::Archive),
+            "current" => Ok(DataSource::Current),
+            _ => Err(DataSourceParseError(s.to_string())),
+        }
+    }
+}
+
 impl Serialize for DataSource {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.serialize_str(&self.to_string())
     }
 }
 
+// XXX: serialized as the source's base URL, so the schema is written out by hand
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for DataSource {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "DataSource".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "enum": [crate::archive::BASE_URL, crate::current::BASE_URL],
+        })
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for DataSource {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let s = String::deserialize(deserializer)?;
@@ -71,6 +297,7 @@ impl<'de> serde::Deserialize<'de> for DataSource {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardListing {
     pub house: House,
     pub date: NaiveDate,
@@ -112,7 +339,13 @@ impl From<crate::current::types::HansardListing> for HansardListing {
     }
 }
 
+/// A sitting from either source, normalized to one schema.
+///
+/// Fields carry the superset of both sources; those only one source provides are `Option`s
+/// (or empty collections) when the sitting came from the other, so sittings from both can be
+/// stored side by side without branching on `source`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardSitting {
     pub house: House,
     pub date: NaiveDate,
@@ -126,9 +359,23 @@ pub struct HansardSitting {
     pub parliament_number: Option<String>,
     pub session_number: Option<String>,
     pub speaker_in_chair: Option<String>,
+    /// Archive only: scene notes before the first speech.
+    #[serde(default)]
+    pub opening: Vec<String>,
+    /// Archive only: scene notes that mention the quorum.
+    #[serde(default)]
+    pub quorum_notes: Vec<String>,
     pub summary: Option<String>,
     pub sentiment: Option<String>,
+    pub sentiment_label: Option<Sentiment>,
     pub pdf_url: Option<String>,
+    #[serde(default)]
+    pub divisions: Vec<Division>,
+    #[serde(default)]
+    pub questions_status: Vec<QuestionStatusEntry>,
+    /// RFC 3339 start of the sitting in East Africa Time; see [`HansardSitting::start_datetime`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<DateTime<FixedOffset>>,
 }
 
 impl HansardSitting {
@@ -150,12 +397,18 @@ impl HansardSitting {
             day_of_week: None,
             start_time: sitting.start_time,
             end_time: sitting.end_time,
+            starts_at: nairobi_datetime(sitting.date, sitting.start_time),
             parliament_number: Some(sitting.parliament_number),
             session_number: Some(sitting.session_number),
             speaker_in_chair: Some(sitting.speaker_in_chair),
+            opening: sitting.opening,
+            quorum_notes: sitting.quorum_notes,
             summary: None,
             sentiment: None,
+            sentiment_label: None,
             pdf_url: None,
+            divisions: Vec::new(),
+            questions_status: Vec::new(),
         }
     }
 
@@ -175,25 +428,327 @@ impl HansardSitting {
                 .collect(),
             source: DataSource::Current,
             day_of_week: Some(sitting.day_of_week),
+            starts_at: nairobi_datetime(sitting.date, sitting.time),
             start_time: sitting.time,
             end_time: None,
-            parliament_number: None,
-            session_number: None,
+            parliament_number: sitting.parliament_number,
+            session_number: sitting.session_number,
             speaker_in_chair: None,
+            opening: Vec::new(),
+            quorum_notes: Vec::new(),
             summary: sitting.summary,
             sentiment: sitting.sentiment,
+            sentiment_label: sitting.sentiment_label,
             pdf_url: sitting.pdf_url,
+            divisions: sitting.divisions,
+            questions_status: sitting.questions_status,
         }
     }
 }
 
+impl HansardSitting {
+    /// Start of the sitting in East Africa Time, combining `date` and `start_time`.
+    /// Returns `None` when the start time is unknown.
+    pub fn start_datetime(&self) -> Option<DateTime<Tz>> {
+        let time = self.start_time?;
+        Nairobi
+            .from_local_datetime(&self.date.and_time(time))
+            .single()
+    }
+
+    /// Time from `start_time` to `end_time`. Returns `None` when either is unknown.
+    ///
+    /// An end time earlier than the start is taken to mean the sitting ran past midnight.
+    pub fn duration(&self) -> Option<Duration> {
+        let (start, end) = (self.start_time?, self.end_time?);
+        let duration = end - start;
+        if duration < Duration::zero() {
+            log::warn!(
+                "Sitting {} ends at {end}, before its {start} start; assuming it ran past midnight",
+                self.url
+            );
+            return Some(duration + Duration::days(1));
+        }
+        Some(duration)
+    }
+
+    /// Every contribution in document order: each section's own contributions, then those of
+    /// its subsections.
+    pub fn all_contributions(&self) -> impl Iterator<Item = &Contribution> {
+        self.sections
+            .iter()
+            .flat_map(HansardSection::all_contributions)
+    }
+
+    /// Like [`all_contributions`](Self::all_contributions), but mutable, e.g. to redact or
+    /// rewrite contributions in place.
+    pub fn all_contributions_mut(&mut self) -> impl Iterator<Item = &mut Contribution> {
+        self.sections.iter_mut().flat_map(|s| {
+            s.contributions.iter_mut().chain(
+                s.subsections
+                    .iter_mut()
+                    .flat_map(|sub| &mut sub.contributions),
+            )
+        })
+    }
+
+    /// Index of the first contribution after the opening prayers, counting contributions in
+    /// document order (each section's own contributions, then those of its subsections).
+    ///
+    /// The marker is either a `PRAYERS` section heading or a "(Prayers)" procedural note.
+    /// Returns `None` when there is no marker or nothing follows it.
+    pub fn business_start_index(&self) -> Option<usize> {
+        let mut index = 0;
+        let mut start = None;
+
+        for section in &self.sections {
+            for contribution in section.all_contributions() {
+                index += 1;
+                if start.is_none()
+                    && contribution
+                        .procedural_notes
+                        .iter()
+                        .any(|n| is_prayers_marker(n))
+                {
+                    start = Some(index);
+                }
+            }
+            if start.is_none() && is_prayers_marker(&section.section_type) {
+                start = Some(index);
+            }
+        }
+
+        start.filter(|&i| i < index)
+    }
+
+    /// Report data-quality issues in the parsed sitting without modifying it.
+    ///
+    /// Sections are flagged when neither they nor their subsections hold any contributions,
+    /// except the opening prayers which are legitimately empty. Division tallies are compared
+    /// against the member lists recorded for each side.
+    pub fn validate(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        for (s, section) in self.sections.iter().enumerate() {
+            if section.all_contributions().next().is_none()
+                && !is_prayers_marker(&section.section_type)
+            {
+                warnings.push(Warning::EmptySection {
+                    section: s,
+                    section_type: section.section_type.clone(),
+                });
+            }
+
+            for (c, contribution) in section.contributions.iter().enumerate() {
+                if contribution.speaker_name.trim().is_empty() {
+                    warnings.push(Warning::EmptySpeakerName {
+                        section: s,
+                        subsection: None,
+                        contribution: c,
+                    });
+                }
+            }
+
+            for (sub_idx, subsection) in section.subsections.iter().enumerate() {
+                if subsection.title.trim().is_empty() {
+                    warnings.push(Warning::EmptySubsectionTitle {
+                        section: s,
+                        subsection: sub_idx,
+                    });
+                }
+                for (c, contribution) in subsection.contributions.iter().enumerate() {
+                    if contribution.speaker_name.trim().is_empty() {
+                        warnings.push(Warning::EmptySpeakerName {
+                            section: s,
+                            subsection: Some(sub_idx),
+                            contribution: c,
+                        });
+                    }
+                }
+            }
+        }
+
+        for (d, division) in self.divisions.iter().enumerate() {
+            let sides = [
+                ("ayes", division.ayes, &division.ayes_members),
+                ("noes", division.noes, &division.noes_members),
+                (
+                    "abstentions",
+                    division.abstentions,
+                    &division.abstentions_members,
+                ),
+            ];
+            for (side, count, members) in sides {
+                if let Some(members) = members
+                    && members.len() != count as usize
+                {
+                    warnings.push(Warning::DivisionCountMismatch {
+                        division: d,
+                        side: side.to_string(),
+                        count,
+                        members: members.len(),
+                    });
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Merge adjacent contributions with the same `speaker_name` and `speaker_url` into one
+    /// turn, within each section and subsection. The parser can split a long speech where the
+    /// transcript interleaves paragraphs and lists; this is opt-in so the raw turns stay
+    /// available. Content is joined with a blank line and procedural notes are kept in order.
+    /// Returns the number of contributions merged away.
+    pub fn coalesce_contributions(&mut self) -> usize {
+        let mut merged = 0;
+        for section in &mut self.sections {
+            merged += coalesce(&mut section.contributions);
+            for subsection in &mut section.subsections {
+                merged += coalesce(&mut subsection.contributions);
+            }
+        }
+        merged
+    }
+
+    /// Hex-encoded SHA-256 of the sitting's JSON, for telling whether a sitting changed since it
+    /// was last fetched. Every field is covered; a sitting carries no fetch-time state. Archive
+    /// and current sittings both come through this type, so one hash serves either source.
+    #[cfg(feature = "cache")]
+    pub fn content_hash(&self) -> String {
+        use sha2::{Digest, Sha256};
+
+        // XXX: hashed straight from the struct, whose fields serialize in declaration order and
+        // which holds no maps, so equal sittings give equal bytes. Going through `Value` would
+        // tie the hash to serde_json's `preserve_order` feature, which other crates may enable.
+        let json = serde_json::to_vec(self).expect("HansardSitting serializes to JSON");
+        format!("{:x}", Sha256::digest(json))
+    }
+}
+
+fn coalesce(contributions: &mut Vec<Contribution>) -> usize {
+    let before = contributions.len();
+    let mut turns: Vec<Contribution> = Vec::with_capacity(before);
+    for contribution in contributions.drain(..) {
+        match turns.last_mut() {
+            Some(last)
+                if last.speaker_name == contribution.speaker_name
+                    && last.speaker_url == contribution.speaker_url =>
+            {
+                if !last.content.is_empty() && !contribution.content.is_empty() {
+                    last.content.push_str("\n\n");
+                }
+                last.content.push_str(&contribution.content);
+                last.procedural_notes.extend(contribution.procedural_notes);
+            }
+            _ => turns.push(contribution),
+        }
+    }
+    *contributions = turns;
+    before - contributions.len()
+}
+
+/// A data-quality issue reported by [`HansardSitting::validate`].
+///
+/// Indices are zero-based positions in `sections`, a section's `subsections` and
+/// `contributions`, and `divisions`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Warning {
+    EmptySection {
+        section: usize,
+        section_type: String,
+    },
+    EmptySpeakerName {
+        section: usize,
+        subsection: Option<usize>,
+        contribution: usize,
+    },
+    EmptySubsectionTitle {
+        section: usize,
+        subsection: usize,
+    },
+    DivisionCountMismatch {
+        division: usize,
+        side: String,
+        count: u32,
+        members: usize,
+    },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::EmptySection {
+                section,
+                section_type,
+            } => write!(f, "section {section} ({section_type}) has no contributions"),
+            Warning::EmptySpeakerName {
+                section,
+                subsection: Some(subsection),
+                contribution,
+            } => write!(
+                f,
+                "contribution {contribution} in section {section}, subsection {subsection} has an empty speaker name"
+            ),
+            Warning::EmptySpeakerName {
+                section,
+                subsection: None,
+                contribution,
+            } => write!(
+                f,
+                "contribution {contribution} in section {section} has an empty speaker name"
+            ),
+            Warning::EmptySubsectionTitle {
+                section,
+                subsection,
+            } => write!(
+                f,
+                "subsection {subsection} in section {section} has an empty title"
+            ),
+            Warning::DivisionCountMismatch {
+                division,
+                side,
+                count,
+                members,
+            } => write!(
+                f,
+                "division {division} reports {count} {side} but lists {members} members"
+            ),
+        }
+    }
+}
+
+fn nairobi_datetime(date: NaiveDate, time: Option<NaiveTime>) -> Option<DateTime<FixedOffset>> {
+    Nairobi
+        .from_local_datetime(&date.and_time(time?))
+        .single()
+        .map(|dt| dt.fixed_offset())
+}
+
+fn is_prayers_marker(text: &str) -> bool {
+    let word = text.trim_matches(|c: char| !c.is_alphanumeric());
+    word.eq_ignore_ascii_case("prayers") || word.eq_ignore_ascii_case("prayer")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardSection {
     pub section_type: String,
     pub subsections: Vec<HansardSubsection>,
     pub contributions: Vec<Contribution>,
 }
 
+impl HansardSection {
+    /// The section's own contributions, then those of its subsections.
+    pub fn all_contributions(&self) -> impl Iterator<Item = &Contribution> {
+        self.contributions
+            .iter()
+            .chain(self.subsections.iter().flat_map(|sub| &sub.contributions))
+    }
+}
+
 impl From<crate::archive::types::HansardSection> for HansardSection {
     fn from(s: crate::archive::types::HansardSection) -> Self {
         let section_type = match s.title {
@@ -231,9 +786,12 @@ impl From<crate::current::types::HansardSection> for HansardSection {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HansardSubsection {
     pub title: String,
     pub contributions: Vec<Contribution>,
+    pub referred_to_committee: Option<String>,
+    pub statement_kind: Option<StatementKind>,
 }
 
 impl From<crate::current::types::HansardSubsection> for HansardSubsection {
@@ -245,27 +803,49 @@ impl From<crate::current::types::HansardSubsection> for HansardSubsection {
                 .into_iter()
                 .map(Contribution::from)
                 .collect(),
+            referred_to_committee: s.referred_to_committee,
+            statement_kind: s.statement_kind,
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Contribution {
     pub speaker_name: String,
+    /// The speaker line as scraped, before any normalization; empty in data saved before it
+    /// was recorded.
+    #[serde(default)]
+    pub speaker_raw: String,
     pub speaker_role: Option<String>,
     pub speaker_url: Option<String>,
+    pub speaker_party: Option<String>,
+    /// Archive only: the speaker's profile, when fetched alongside the transcript.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker_details: Option<PersonDetails>,
     pub content: String,
     pub procedural_notes: Vec<String>,
+    /// Current only: whether this is a question, an answer, a statement or debate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contribution_kind: Option<ContributionKind>,
+    /// Archive only: the page of the official Hansard the speech falls on, for citation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_page: Option<u32>,
 }
 
 impl From<crate::archive::types::Contribution> for Contribution {
     fn from(c: crate::archive::types::Contribution) -> Self {
         Self {
             speaker_name: c.speaker_name,
+            speaker_raw: c.speaker_raw,
             speaker_role: c.speaker_role,
             speaker_url: c.speaker_url,
+            speaker_party: c.speaker_party,
+            speaker_details: c.speaker_details,
             content: c.content,
             procedural_notes: c.procedural_notes,
+            contribution_kind: None,
+            source_page: c.source_page,
         }
     }
 }
@@ -274,10 +854,541 @@ impl From<crate::current::types::Contribution> for Contribution {
     fn from(c: crate::current::types::Contribution) -> Self {
         Self {
             speaker_name: c.speaker_name,
-            speaker_role: None,
+            speaker_raw: c.speaker_raw,
+            speaker_role: c.speaker_role,
             speaker_url: c.speaker_url,
+            speaker_party: None,
+            speaker_details: None,
             content: c.content,
             procedural_notes: c.procedural_notes,
+            contribution_kind: c.contribution_kind,
+            source_page: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::current::parser::parse_hansard_sitting;
+    use std::fs;
+
+    fn contribution(speaker_name: &str) -> Contribution {
+        Contribution {
+            speaker_name: speaker_name.to_string(),
+            speaker_raw: speaker_name.to_string(),
+            speaker_role: None,
+            speaker_url: None,
+            speaker_party: None,
+            speaker_details: None,
+            content: "Hon. Speaker, I beg to move.".to_string(),
+            procedural_notes: vec![],
+            contribution_kind: None,
+            source_page: None,
+        }
+    }
+
+    /// The National Assembly sitting of 12th February 2026, from the current fixture.
+    fn sitting() -> HansardSitting {
+        let html = fs::read_to_string("fixtures/current/national_assembly_hansard_sitting")
+            .expect("Failed to read fixture");
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+        HansardSitting::from_current(
+            parse_hansard_sitting(&html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        )
+    }
+
+    /// A sitting with no start time, sections or divisions, for tests to fill in.
+    fn empty_sitting() -> HansardSitting {
+        let html = r#"
+            <span class="house">National Assembly</span>
+            <article class="hansard-document"></article>
+        "#;
+        let url = "https://mzalendo.com/democracy-tools/hansard/tuesday-24th-june-2025-afternoon-sitting-2310/";
+        HansardSitting::from_current(
+            parse_hansard_sitting(html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_validate_malformed_sitting() {
+        let mut sitting = empty_sitting();
+        assert_eq!(sitting.validate(), vec![]);
+
+        sitting.sections = vec![
+            HansardSection {
+                section_type: "PRAYERS".to_string(),
+                subsections: vec![],
+                contributions: vec![],
+            },
+            HansardSection {
+                section_type: "PAPERS".to_string(),
+                subsections: vec![],
+                contributions: vec![],
+            },
+            HansardSection {
+                section_type: "BILLS".to_string(),
+                subsections: vec![HansardSubsection {
+                    title: "  ".to_string(),
+                    contributions: vec![contribution("Hon. Kuria Kimani"), contribution("")],
+                    referred_to_committee: None,
+                    statement_kind: None,
+                }],
+                contributions: vec![contribution(" ")],
+            },
+        ];
+        sitting.divisions = vec![Division {
+            ayes: 2,
+            noes: 106,
+            abstentions: 0,
+            result: "The Noes have it".to_string(),
+            ayes_members: Some(vec![
+                "Hon. Kuria Kimani (Molo, UDA)".to_string(),
+                "Hon. Gladys Boss (Uasin Gishu County, UDA)".to_string(),
+            ]),
+            noes_members: Some(vec![
+                "Hon. Junet Mohamed (Suna East, ODM)".to_string(),
+                "Hon. Opiyo Wandayi (Ugunja, ODM)".to_string(),
+            ]),
+            abstentions_members: None,
+        }];
+
+        let warnings = sitting.validate();
+        assert_eq!(
+            warnings,
+            vec![
+                Warning::EmptySection {
+                    section: 1,
+                    section_type: "PAPERS".to_string(),
+                },
+                Warning::EmptySpeakerName {
+                    section: 2,
+                    subsection: None,
+                    contribution: 0,
+                },
+                Warning::EmptySubsectionTitle {
+                    section: 2,
+                    subsection: 0,
+                },
+                Warning::EmptySpeakerName {
+                    section: 2,
+                    subsection: Some(0),
+                    contribution: 1,
+                },
+                Warning::DivisionCountMismatch {
+                    division: 0,
+                    side: "noes".to_string(),
+                    count: 106,
+                    members: 2,
+                },
+            ]
+        );
+        assert_eq!(
+            warnings[4].to_string(),
+            "division 0 reports 106 noes but lists 2 members"
+        );
+
+        let json = serde_json::to_value(&warnings[0]).expect("Failed to serialize warning");
+        assert_eq!(json["kind"], "empty_section");
+        assert_eq!(json["section_type"], "PAPERS");
+    }
+
+    #[test]
+    fn test_coalesce_contributions() {
+        let mut sitting = empty_sitting();
+
+        let mut first = contribution("Hon. Kuria Kimani");
+        first.procedural_notes = vec!["(Applause)".to_string()];
+        let mut continued = contribution("Hon. Kuria Kimani");
+        continued.content = "I beg to move.".to_string();
+        let mut elsewhere = contribution("Hon. Kuria Kimani");
+        elsewhere.speaker_url = Some("/mps-performance/kuria-kimani/".to_string());
+        sitting.sections = vec![HansardSection {
+            section_type: "BILLS".to_string(),
+            subsections: vec![HansardSubsection {
+                title: "The Finance Bill".to_string(),
+                contributions: vec![
+                    first,
+                    continued,
+                    contribution("Hon. Ndindi Nyoro"),
+                    contribution("Hon. Kuria Kimani"),
+                    elsewhere,
+                ],
+                referred_to_committee: None,
+                statement_kind: None,
+            }],
+            contributions: vec![contribution("Hon. Speaker"), contribution("Hon. Speaker")],
+        }];
+        let raw = sitting.clone();
+
+        assert_eq!(sitting.coalesce_contributions(), 2);
+        assert_eq!(sitting.sections[0].contributions.len(), 1);
+        let turns = &sitting.sections[0].subsections[0].contributions;
+        let speakers: Vec<&str> = turns.iter().map(|c| c.speaker_name.as_str()).collect();
+        assert_eq!(
+            speakers,
+            [
+                "Hon. Kuria Kimani",
+                "Hon. Ndindi Nyoro",
+                "Hon. Kuria Kimani",
+                "Hon. Kuria Kimani"
+            ],
+            "Only adjacent turns with the same name and URL are merged"
+        );
+        assert_eq!(
+            turns[0].content,
+            "Hon. Speaker, I beg to move.\n\nI beg to move."
+        );
+        assert_eq!(turns[0].procedural_notes, ["(Applause)"]);
+
+        assert_ne!(sitting, raw, "The raw turns are only merged on request");
+        assert_eq!(sitting.clone().coalesce_contributions(), 0);
+    }
+
+    #[test]
+    fn test_business_start_index_after_prayers_section() {
+        let sitting = sitting();
+
+        let index = sitting
+            .business_start_index()
+            .expect("Should find the prayers marker");
+        let contributions = sitting.all_contributions().collect::<Vec<_>>();
+        assert_eq!(index, 0);
+        assert_eq!(contributions[index].speaker_name, "Hon. Speaker");
+        assert!(
+            contributions[index].content.contains("Quorum Bell"),
+            "Business should start with the quorum call"
+        );
+    }
+
+    #[test]
+    fn test_all_contributions_in_document_order() {
+        let mut sitting = sitting();
+
+        let expected: usize = sitting
+            .sections
+            .iter()
+            .map(|s| {
+                s.contributions.len()
+                    + s.subsections
+                        .iter()
+                        .map(|sub| sub.contributions.len())
+                        .sum::<usize>()
+            })
+            .sum();
+        assert!(expected > 0);
+        assert_eq!(sitting.all_contributions().count(), expected);
+
+        let bills = sitting
+            .sections
+            .iter()
+            .find(|s| !s.subsections.is_empty())
+            .expect("Should have a section with subsections");
+        let first_in_subsection = bills
+            .subsections
+            .iter()
+            .find_map(|sub| sub.contributions.first())
+            .cloned()
+            .expect("Should have a subsection contribution");
+        let position = sitting
+            .all_contributions()
+            .position(|c| *c == first_in_subsection)
+            .expect("Subsection contributions should be included");
+        assert!(position >= bills.contributions.len());
+
+        for contribution in sitting.all_contributions_mut() {
+            contribution.content.clear();
+        }
+        assert!(sitting.all_contributions().all(|c| c.content.is_empty()));
+    }
+
+    #[test]
+    fn test_start_datetime_is_east_africa_time() {
+        let sitting = sitting();
+
+        let start = sitting.start_datetime().expect("Should have a start time");
+        assert_eq!(start.to_rfc3339(), "2026-02-12T14:30:00+03:00");
+        assert_eq!(
+            start.with_timezone(&chrono::Utc).to_rfc3339(),
+            "2026-02-12T11:30:00+00:00"
+        );
+        assert_eq!(sitting.starts_at, Some(start.fixed_offset()));
+
+        let json = serde_json::to_value(&sitting).expect("Failed to serialize sitting");
+        assert_eq!(json["starts_at"], "2026-02-12T14:30:00+03:00");
+        assert_eq!(json["start_time"], "14:30:00");
+    }
+
+    #[test]
+    fn test_duration() {
+        let mut sitting = sitting();
+        assert_eq!(
+            sitting.duration(),
+            None,
+            "Current sittings have no end time"
+        );
+
+        sitting.end_time = NaiveTime::from_hms_opt(18, 45, 0);
+        assert_eq!(
+            sitting.duration(),
+            Some(Duration::hours(4) + Duration::minutes(15))
+        );
+
+        sitting.end_time = NaiveTime::from_hms_opt(0, 30, 0);
+        assert_eq!(sitting.duration(), Some(Duration::hours(10)));
+    }
+
+    #[test]
+    fn test_start_datetime_without_time() {
+        let sitting = empty_sitting();
+
+        assert_eq!(sitting.start_datetime(), None);
+        let json = serde_json::to_value(&sitting).expect("Failed to serialize sitting");
+        assert!(json.get("starts_at").is_none());
+    }
+
+    #[test]
+    fn test_business_start_index_after_prayers_note() {
+        let html = r#"
+            <span class="house">National Assembly</span>
+            <article class="hansard-document">
+            <h2 class="major-section-header">COMMUNICATION FROM THE CHAIR</h2>
+            <div class="contributor-name">Hon. Speaker</div>
+            <div class="speech-content">
+            <p>Order, Hon. Members. Let us pray.</p>
+            <aside class="procedural-note">(Prayers)</aside>
+            </div>
+            <div class="contributor-name">Hon. Speaker</div>
+            <div class="speech-content"><p>Hon. Members, I have a Communication to make.</p></div>
+            </article>
+        "#;
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2438/";
+
+        let sitting = HansardSitting::from_current(
+            parse_hansard_sitting(html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        );
+
+        assert_eq!(sitting.business_start_index(), Some(1));
+        let contributions = sitting.all_contributions().collect::<Vec<_>>();
+        assert!(contributions[1].content.contains("Communication"));
+    }
+
+    #[test]
+    fn test_business_start_index_without_marker() {
+        let html = r#"
+            <span class="house">Senate</span>
+            <article class="hansard-document">
+            <h2 class="major-section-header">STATEMENTS</h2>
+            <div class="contributor-name">Hon. Speaker</div>
+            <div class="speech-content"><p>Proceed.</p></div>
+            </article>
+        "#;
+        let url = "https://mzalendo.com/democracy-tools/hansard/thursday-12th-february-2026-afternoon-sitting-2434/";
+
+        let sitting = HansardSitting::from_current(
+            parse_hansard_sitting(html, url).expect("Failed to parse sitting"),
+            url.to_string(),
+        );
+
+        assert_eq!(sitting.business_start_index(), None);
+    }
+
+    fn listing(house: House, date: &str, start: Option<&str>, title: &str) -> HansardListing {
+        HansardListing {
+            house,
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            url: format!("https://mzalendo.com/democracy-tools/hansard/{title}/"),
+            title: title.to_string(),
+            session_type: None,
+            start_time: start.map(|t| NaiveTime::parse_from_str(t, "%H:%M").unwrap()),
+            end_time: None,
+            source: DataSource::Current,
+        }
+    }
+
+    #[test]
+    fn test_listing_sort() {
+        let mut listings = vec![
+            listing(House::Senate, "2026-02-12", Some("14:30"), "b"),
+            listing(House::NationalAssembly, "2025-12-02", None, "C"),
+            listing(House::NationalAssembly, "2026-02-12", Some("09:30"), "a"),
+        ];
+
+        ListingSort::Date.sort(&mut listings, false);
+        let titles: Vec<&str> = listings.iter().map(|l| l.title.as_str()).collect();
+        assert_eq!(titles, ["C", "a", "b"], "Chronological, not lexical");
+
+        ListingSort::Title.sort(&mut listings, true);
+        let titles: Vec<&str> = listings.iter().map(|l| l.title.as_str()).collect();
+        assert_eq!(titles, ["C", "b", "a"]);
+
+        ListingSort::House.sort(&mut listings, false);
+        let titles: Vec<&str> = listings.iter().map(|l| l.title.as_str()).collect();
+        assert_eq!(titles, ["C", "a", "b"], "Stable within a house");
+
+        let mut listings = vec![
+            listing(House::Senate, "2026-02-12", None, "senate"),
+            listing(House::NationalAssembly, "2026-02-11", None, "older"),
+            listing(House::NationalAssembly, "2026-02-12", None, "assembly"),
+        ];
+        ListingSort::Date.sort(&mut listings, true);
+        let titles: Vec<&str> = listings.iter().map(|l| l.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            ["senate", "assembly", "older"],
+            "Houses interleaved by date"
+        );
+
+        assert_eq!("date".parse::<ListingSort>().unwrap(), ListingSort::Date);
+        assert!("speaker".parse::<ListingSort>().is_err());
+    }
+
+    #[test]
+    fn test_sitting_list_options_builder() {
+        let start = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        let options = SittingListOptions::builder()
+            .house(House::Senate)
+            .start_date(start)
+            .limit(10)
+            .sort(ListingSort::Date)
+            .reverse(true)
+            .build();
+        assert_eq!(options.house, Some(House::Senate));
+        assert_eq!(options.start_date, Some(start));
+        assert_eq!(options.end_date, None);
+        assert_eq!(options.limit, Some(10));
+        assert_eq!(options.sort, Some(ListingSort::Date));
+        assert!(options.reverse);
+        assert!(!options.all);
+
+        let options = SittingListOptions::builder().page(3).to_page(5).build();
+        assert_eq!((options.page, options.to_page), (3, Some(5)));
+    }
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!(
+            "archive".parse::<DataSource>().unwrap(),
+            DataSource::Archive
+        );
+        assert_eq!(
+            " Current ".parse::<DataSource>().unwrap(),
+            DataSource::Current
+        );
+        assert!("mzalendo".parse::<DataSource>().is_err());
+    }
+
+    #[test]
+    fn test_member_sort() {
+        let member = |name: &str, constituency: Option<&str>| Member {
+            name: name.to_string(),
+            url: String::new(),
+            house: House::NationalAssembly,
+            role: None,
+            photo_url: None,
+            constituency: constituency.map(str::to_string),
+            county: None,
+        };
+        let mut members = vec![
+            member("Vincent Musau", Some("Mwala")),
+            member("Aden Duale", None),
+            member("boss Gladys Jepkosgei", Some("Uasin Gishu")),
+            member("Gladys Wanga", Some("Homa Bay County")),
+        ];
+
+        MemberSort::Name.sort(&mut members, false);
+        let names: Vec<&str> = members.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Aden Duale",
+                "boss Gladys Jepkosgei",
+                "Gladys Wanga",
+                "Vincent Musau"
+            ]
+        );
+
+        MemberSort::Constituency.sort(&mut members, false);
+        let names: Vec<&str> = members.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Gladys Wanga",
+                "Vincent Musau",
+                "boss Gladys Jepkosgei",
+                "Aden Duale"
+            ]
+        );
+
+        MemberSort::Name.sort(&mut members, true);
+        assert_eq!(members[0].name, "Vincent Musau");
+
+        MemberSort::Constituency.sort(&mut members, true);
+        let names: Vec<&str> = members.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "boss Gladys Jepkosgei",
+                "Vincent Musau",
+                "Gladys Wanga",
+                "Aden Duale"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_archive_contribution_keeps_speaker_details() {
+        let details = PersonDetails {
+            name: "Moses Wetang'ula".to_string(),
+            slug: "moses-wetangula".to_string(),
+            summary: None,
+            party: Some("FORD-K".to_string()),
+            party_url: None,
+            email: None,
+            telephone: None,
+            current_position: Some("Speaker".to_string()),
+            constituency: None,
+        };
+        let archived = crate::archive::types::Contribution {
+            speaker_name: "The Speaker".to_string(),
+            speaker_raw: "The Speaker".to_string(),
+            speaker_role: Some("Speaker".to_string()),
+            speaker_url: Some("/person/moses-wetangula/".to_string()),
+            speaker_party: Some("FORD-K".to_string()),
+            speaker_details: Some(details.clone()),
+            content: "Order, Members.".to_string(),
+            procedural_notes: vec![],
+            source_page: Some(3),
+        };
+
+        let unified = Contribution::from(archived);
+        assert_eq!(unified.speaker_details, Some(details));
+        assert_eq!(unified.source_page, Some(3));
+
+        let json = serde_json::to_value(contribution("Hon. Kuria Kimani"))
+            .expect("Failed to serialize contribution");
+        assert!(
+            json.get("speaker_details").is_none(),
+            "Absent details are not serialized"
+        );
+        let parsed: Contribution =
+            serde_json::from_value(json).expect("Details default to None when missing");
+        assert_eq!(parsed.speaker_details, None);
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_content_hash() {
+        let hash = sitting().content_hash();
+        assert_eq!(hash.len(), 64);
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(sitting().content_hash(), hash);
+
+        let mut changed = sitting();
+        changed.sections.pop();
+        assert_ne!(changed.content_hash(), hash);
+    }
+}
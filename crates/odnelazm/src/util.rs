@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use reqwest::{Client, Response, StatusCode};
+
+/// Attempts [`get_with_retry`] makes before giving up, including the first.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry; it doubles for each retry after that.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// GET `url`, retrying with exponential backoff when the request times out, cannot connect, or
+/// is answered with a 5xx or 429. Other errors, such as a 404, are returned straight away, as is
+/// the last error once the attempts run out.
+pub(crate) async fn get_with_retry(client: &Client, url: &str) -> Result<Response, reqwest::Error> {
+    let mut delay = INITIAL_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        let result = client
+            .get(url)
+            .send()
+            .await
+            .and_then(Response::error_for_status);
+        match result {
+            Err(e) if attempt < MAX_ATTEMPTS && is_transient(&e) => {
+                log::warn!(
+                    "Request to {url} failed ({e}), retrying in {}ms (attempt {attempt} of {MAX_ATTEMPTS})",
+                    delay.as_millis()
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn is_transient(error: &reqwest::Error) -> bool {
+    error.is_timeout()
+        || error.is_connect()
+        || error
+            .status()
+            .is_some_and(|s| s.is_server_error() || s == StatusCode::TOO_MANY_REQUESTS)
+}
+
+/// Trim procedural notes, drop empty ones, and collapse runs of the same note. A scene
+/// description can repeat back to back, e.g. "(Applause)" after each school the Speaker
+/// welcomes, and notes carry no position in the content, so the repeats are noise.
+pub(crate) fn clean_procedural_notes(notes: &mut Vec<String>) {
+    *notes = notes
+        .drain(..)
+        .map(|note| note.trim().to_string())
+        .filter(|note| !note.is_empty())
+        .collect();
+    notes.dedup();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_with_retry_recovers_from_server_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/hansard/"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/hansard/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<ul></ul>"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = get_with_retry(&Client::new(), &format!("{}/hansard/", server.uri()))
+            .await
+            .unwrap();
+        assert_eq!(response.text().await.unwrap(), "<ul></ul>");
+    }
+
+    #[tokio::test]
+    async fn test_get_with_retry_gives_up() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/hansard/"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(u64::from(MAX_ATTEMPTS))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/missing/"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new();
+        let err = get_with_retry(&client, &format!("{}/hansard/", server.uri()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+
+        let err = get_with_retry(&client, &format!("{}/missing/", server.uri()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn test_clean_procedural_notes() {
+        let mut notes = vec![
+            " (Applause) ".to_string(),
+            "(Applause)".to_string(),
+            String::new(),
+            "(Laughter)".to_string(),
+            "(Applause)".to_string(),
+        ];
+        clean_procedural_notes(&mut notes);
+        assert_eq!(notes, ["(Applause)", "(Laughter)", "(Applause)"]);
+    }
+}